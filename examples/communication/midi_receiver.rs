@@ -0,0 +1,117 @@
+use nannou::prelude::*;
+use nannou_midi as midi;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    receiver: Option<midi::Receiver>,
+    // The value of each control change controller, mapped to the radius of a circle.
+    controls: [u8; 8],
+    // The currently held notes.
+    notes: Vec<u8>,
+    pitch_bend: i16,
+    last_connect_attempt: f32,
+}
+
+// How often to look for a device while disconnected, in seconds.
+const CONNECT_INTERVAL: f32 = 1.0;
+
+fn model(app: &App) -> Model {
+    let _w_id = app
+        .new_window()
+        .title("MIDI Receiver")
+        .size(1024, 480)
+        .view(view)
+        .build()
+        .unwrap();
+
+    Model {
+        receiver: connect(),
+        controls: [0; 8],
+        notes: vec![],
+        pitch_bend: 0,
+        last_connect_attempt: 0.0,
+    }
+}
+
+// Connect to the first available MIDI input, if any.
+fn connect() -> Option<midi::Receiver> {
+    let inputs = midi::inputs().ok()?;
+    let input = inputs.first()?;
+    match midi::receiver(input) {
+        Ok(receiver) => Some(receiver),
+        Err(err) => {
+            eprintln!("failed to connect to {}: {}", input, err);
+            None
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    // If the device was unplugged, periodically look for another.
+    if app.time - model.last_connect_attempt > CONNECT_INTERVAL {
+        model.last_connect_attempt = app.time;
+        let connected = model.receiver.as_ref().map(|rx| rx.is_connected());
+        if connected != Some(true) {
+            model.receiver = connect();
+            model.notes.clear();
+        }
+    }
+
+    // Handle any pending MIDI messages.
+    let receiver = match model.receiver {
+        Some(ref receiver) => receiver,
+        None => return,
+    };
+    for msg in receiver.try_iter() {
+        match msg {
+            midi::Message::NoteOn { note, .. } => model.notes.push(note),
+            midi::Message::NoteOff { note, .. } => model.notes.retain(|&n| n != note),
+            midi::Message::ControlChange {
+                controller, value, ..
+            } => {
+                if let Some(control) = model.controls.get_mut(controller as usize % 8) {
+                    *control = value;
+                }
+            }
+            midi::Message::PitchBend { value, .. } => model.pitch_bend = value,
+            _ => (),
+        }
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+
+    let status = match model.receiver {
+        Some(ref receiver) => format!("Receiving from {}", receiver.input()),
+        None => "No MIDI inputs found. Waiting for a device...".to_string(),
+    };
+    draw.text(&status)
+        .x_y(0.0, win.top() - 20.0)
+        .w(win.w())
+        .color(WHITE);
+
+    // A circle for each controller.
+    let bend = model.pitch_bend as f32 / 8192.0 * 20.0;
+    for (i, &value) in model.controls.iter().enumerate() {
+        let x = map_range(i, 0, 7, win.left() + 80.0, win.right() - 80.0);
+        let radius = map_range(value, 0, 127, 4.0, 50.0);
+        draw.ellipse().x_y(x, bend).radius(radius).color(STEELBLUE);
+    }
+
+    // A bar for each held note.
+    for &note in &model.notes {
+        let x = map_range(note, 0, 127, win.left(), win.right());
+        draw.rect()
+            .x_y(x, win.bottom() + 40.0)
+            .w_h(6.0, 60.0)
+            .color(ORANGE);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
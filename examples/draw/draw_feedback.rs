@@ -0,0 +1,46 @@
+// A demonstration of a feedback (or "trail") effect using a `FeedbackBuffer`.
+//
+// Each update, the previous result is drawn into the feedback buffer slightly scaled up and faded
+// before a new circle is drawn on top at the position of the mouse. The result is then displayed
+// in the window.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    feedback: nannou::draw::FeedbackBuffer,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(512, 512).view(view).build().unwrap();
+    let feedback = app.feedback_buffer([512, 512], Frame::TEXTURE_FORMAT);
+    Model { feedback }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let draw = model.feedback.draw_prev();
+    let [w, h] = model.feedback.size();
+
+    // Draw the previous result, slightly enlarged, then fade it towards black.
+    draw.texture(&model.feedback.prev_texture())
+        .w_h(w as f32 * 1.01, h as f32 * 1.01);
+    draw.rect()
+        .w_h(w as f32, h as f32)
+        .rgba(0.0, 0.0, 0.0, 0.05);
+
+    // Draw a new circle on top.
+    let hue = (app.time * 0.1).fract();
+    draw.ellipse()
+        .x_y(app.mouse.x, app.mouse.y)
+        .radius(20.0)
+        .hsv(hue, 0.8, 1.0);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.texture(&model.feedback.prev_texture());
+    draw.to_frame(app, &frame).unwrap();
+}
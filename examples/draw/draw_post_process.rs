@@ -0,0 +1,50 @@
+use nannou::prelude::*;
+
+// Splits the red and blue channels towards the edges of the window and darkens the corners.
+const POST_PROCESS: &str = r#"
+@group(0) @binding(0)
+var tex: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+
+@fragment
+fn main(@location(0) tex_coords: vec2<f32>) -> @location(0) vec4<f32> {
+    let offset = (tex_coords - vec2<f32>(0.5, 0.5)) * 0.015;
+    let r = textureSample(tex, tex_sampler, tex_coords + offset).r;
+    let g = textureSample(tex, tex_sampler, tex_coords).g;
+    let b = textureSample(tex, tex_sampler, tex_coords - offset).b;
+    let vignette = 1.0 - dot(offset, offset) * 2500.0;
+    return vec4<f32>(vec3<f32>(r, g, b) * clamp(vignette, 0.0, 1.0), 1.0);
+}
+"#;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(720, 720)
+        .post_process(POST_PROCESS)
+        .view(view)
+        .build()
+        .unwrap();
+    Model
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let t = app.time;
+    for i in 0..12 {
+        let angle = i as f32 / 12.0 * TAU + t * 0.5;
+        let radius = 220.0 + (t * 2.0 + i as f32).sin() * 40.0;
+        draw.ellipse()
+            .x_y(angle.cos() * radius, angle.sin() * radius)
+            .radius(30.0)
+            .color(WHITE);
+    }
+    draw.to_frame(app, &frame).unwrap();
+}
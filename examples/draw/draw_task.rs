@@ -0,0 +1,81 @@
+// Generating a mesh on a background thread with `app.spawn_task`.
+//
+// Generating the mesh is slow enough to noticeably freeze the window if done within `update`.
+// Instead, the work is spawned as a task and the result is swapped in once it is ready. Press the
+// space bar to generate a new mesh while the current one keeps animating.
+
+use nannou::prelude::*;
+
+type Tris = Vec<geom::Tri<(Vec3, Srgba)>>;
+
+fn main() {
+    nannou::app(model).update(update).event(event).run();
+}
+
+struct Model {
+    tris: Tris,
+    task: Option<app::Task<Tris>>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 720).view(view).build().unwrap();
+    let task = Some(app.spawn_task(generate_mesh));
+    Model { tris: vec![], task }
+}
+
+fn event(app: &App, model: &mut Model, event: Event) {
+    if let Event::WindowEvent {
+        simple: Some(KeyPressed(Key::Space)),
+        ..
+    } = event
+    {
+        // Only spawn a new task if the previous one has finished.
+        if model.task.is_none() {
+            model.task = Some(app.spawn_task(generate_mesh));
+        }
+    }
+}
+
+fn update(_app: &App, model: &mut Model, _update: Update) {
+    // Check whether the mesh is ready without blocking.
+    if let Some(tris) = model.task.as_mut().and_then(|task| task.try_take()) {
+        model.tris = tris;
+        model.task = None;
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    draw.rotate(app.time * 0.1)
+        .mesh()
+        .tris_colored(model.tris.iter().cloned());
+    if model.task.is_some() {
+        let win = app.window_rect();
+        draw.text("generating...")
+            .xy(win.pad(20.0).bottom_left())
+            .left_justify()
+            .color(WHITE);
+    }
+    draw.to_frame(app, &frame).unwrap();
+}
+
+// Produce a ring of many thousands of randomly placed and colored triangles.
+fn generate_mesh() -> Tris {
+    let n_tris = 200_000;
+    let hue = random_f32();
+    (0..n_tris)
+        .map(|_| {
+            let angle = random_range(0.0, TAU);
+            let radius = random_range(100.0, 300.0);
+            let centre = vec2(angle.cos(), angle.sin()) * radius;
+            let size = random_range(1.0, 4.0);
+            let color: Srgba = hsva(hue + random_range(-0.1, 0.1), 0.8, 1.0, 0.5).into();
+            let vertex = |angle: f32| {
+                let p = centre + vec2(angle.cos(), angle.sin()) * size;
+                (p.extend(0.0), color)
+            };
+            geom::Tri([vertex(0.0), vertex(TAU / 3.0), vertex(TAU * 2.0 / 3.0)])
+        })
+        .collect()
+}
@@ -32,6 +32,8 @@ fn model(app: &App) -> Model {
         .dropped_file(dropped_file)
         .focused(window_focused)
         .unfocused(window_unfocused)
+        .occluded(window_occluded)
+        .unoccluded(window_unoccluded)
         .closed(window_closed)
         .build()
         .unwrap();
@@ -78,6 +80,8 @@ fn window_event(_app: &App, _model: &mut Model, event: WindowEvent) {
         HoveredFileCancelled => {}
         Focused => {}
         Unfocused => {}
+        Occluded => {}
+        Unoccluded => {}
         Closed => {}
     }
 }
@@ -112,6 +116,10 @@ fn window_focused(_app: &App, _model: &mut Model) {}
 
 fn window_unfocused(_app: &App, _model: &mut Model) {}
 
+fn window_occluded(_app: &App, _model: &mut Model) {}
+
+fn window_unoccluded(_app: &App, _model: &mut Model) {}
+
 fn window_closed(_app: &App, _model: &mut Model) {}
 
 fn hovered_file(_app: &App, _model: &mut Model, _path: std::path::PathBuf) {}
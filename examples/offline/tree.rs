@@ -121,6 +121,8 @@ fn window_event(_app: &App, _model: &mut Model, event: WindowEvent) {
         HoveredFileCancelled => {}
         Focused => {}
         Unfocused => {}
+        Occluded => {}
+        Unoccluded => {}
         Closed => {}
     }
 }
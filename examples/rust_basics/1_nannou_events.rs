@@ -53,6 +53,8 @@ fn event(_app: &App, _model: &mut Model, event: WindowEvent) {
         HoveredFileCancelled => {}
         Focused => {}
         Unfocused => {}
+        Occluded => {}
+        Unoccluded => {}
         Closed => {}
     }
 }
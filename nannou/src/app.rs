@@ -8,6 +8,7 @@
 //!   thread.
 //! - [**LoopMode**](./enum.LoopMode.html) - describes the behaviour of the application event loop.
 
+use crate::color::{IntoLinSrgba, LinSrgba};
 use crate::draw;
 use crate::event::{self, Event, Key, LoopEvent, Update};
 use crate::frame::{Frame, RawFrame};
@@ -24,7 +25,7 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::atomic::{self, AtomicBool};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::{self, future};
 use wgpu_upstream::InstanceDescriptor;
@@ -67,6 +68,7 @@ pub struct Builder<M = (), E = Event> {
     exit: Option<ExitFn<M>>,
     create_default_window: bool,
     default_window_size: Option<DefaultWindowSize>,
+    default_clear_color: Option<LinSrgba>,
     capture_frame_timeout: Option<Option<Duration>>,
     max_capture_frame_jobs: Option<u32>,
     backends: wgpu::Backends,
@@ -106,6 +108,7 @@ fn default_model(_: &App) -> () {
 pub struct App {
     config: RefCell<Config>,
     default_window_size: Option<DefaultWindowSize>,
+    default_clear_color: Option<LinSrgba>,
     max_capture_frame_jobs: u32,
     capture_frame_timeout: Option<Duration>,
     pub(crate) event_loop_window_target: Option<EventLoopWindowTarget>,
@@ -120,7 +123,11 @@ pub struct App {
     draw_state: DrawState,
     /// The window that is currently in focus.
     pub(crate) focused_window: RefCell<Option<window::Id>>,
+    /// The virtual clock read by `time`.
+    clock: RefCell<state::Clock>,
     /// The current state of the `Mouse`.
+    ///
+    /// Positions and deltas are described in logical points.
     pub mouse: state::Mouse,
     /// State of the keyboard keys.
     ///
@@ -141,6 +148,10 @@ pub struct App {
     pub duration: state::Time,
     /// The time in seconds since the `App` started running.
     ///
+    /// This is read from a virtual clock that follows real time by default, but that may be
+    /// paused, scaled or set via `app.pause_time()`, `app.set_time_scale(scale)` and
+    /// `app.set_time(secs)`. `app.duration` always tracks real time.
+    ///
     /// Primarily, this field is a convenience that removes the need to call
     /// `app.duration.since_start.secs()`. Normally we would try to avoid using such an ambiguous
     /// field name, however due to the sheer amount of use that this value has we feel it is
@@ -164,6 +175,24 @@ struct Config {
     loop_mode: LoopMode,
     exit_on_escape: bool,
     fullscreen_on_shortcut: bool,
+    panic_overlay: bool,
+    // The key that saves a screenshot of the focused window and the directory to save it within.
+    capture_key: Option<(Key, PathBuf)>,
+    // `None` if the user has not specified whether or not to smooth graphics.
+    smooth: Option<bool>,
+    draw_reset: DrawReset,
+}
+
+// State for displaying panics over each window. See `Builder::panic_overlay`.
+struct PanicOverlay {
+    // The panic most recently reported to the hook, including its location.
+    last_panic: Arc<Mutex<Option<String>>>,
+    // The message currently displayed over each window.
+    message: Option<String>,
+    // Whether or not a panic has been caught since the last call to `view`.
+    panicked_since_view: bool,
+    // Restores the panic hook that was in place before the overlay was installed.
+    restore_hook: Box<dyn FnOnce()>,
 }
 
 // Draw state managed by the **App**.
@@ -171,6 +200,8 @@ struct Config {
 struct DrawState {
     draw: RefCell<draw::Draw>,
     renderers: RefCell<HashMap<window::Id, RefCell<draw::Renderer>>>,
+    // Feedback buffers that are rendered and swapped at the end of each update.
+    feedback_buffers: RefCell<Vec<draw::feedback::WeakFeedbackBuffer>>,
 }
 
 /// A handle to the **App** that can be shared across threads. This may be used to "wake up" the
@@ -185,6 +216,15 @@ pub struct Proxy {
     wakeup_queued: Arc<AtomicBool>,
 }
 
+/// A handle to a computation running on a background thread, produced by `app.spawn_task`.
+///
+/// The result may be collected without blocking via `try_take`, e.g. once per `update`.
+#[derive(Debug)]
+pub struct Task<T> {
+    // `None` once the result has been taken.
+    handle: Option<tokio::task::JoinHandle<T>>,
+}
+
 // State related specifically to the application loop, shared between loop modes.
 struct LoopState {
     updates_since_event: u64,
@@ -193,6 +233,27 @@ struct LoopState {
     total_updates: u64,
 }
 
+/// When the **Draw** returned by `app.draw()` is reset.
+///
+/// See `Builder::draw_reset`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DrawReset {
+    /// The **Draw** is reset to its checkpoint (or cleared if there is none) on each call to
+    /// `app.draw()`.
+    ///
+    /// As `app.draw()` is typically called once at the beginning of each `view`, each frame starts
+    /// from an empty **Draw**. This is the default.
+    EachFrame,
+    /// The **Draw** is never reset by the **App**.
+    ///
+    /// Drawings are retained when rendered and accumulate across calls to `app.draw()` until the
+    /// user calls `draw.reset()` or `draw.reset_to_checkpoint()`. This includes any background,
+    /// which is applied to every window until reset. Note that all accumulated drawings are
+    /// tessellated and rendered each frame, so the cost of each frame and the memory used grow
+    /// until reset.
+    Manual,
+}
+
 /// The mode in which the **App** is currently running the event loop and emitting `Update` events.
 #[derive(Clone, Debug, PartialEq)]
 pub enum LoopMode {
@@ -268,6 +329,7 @@ where
             exit: None,
             create_default_window: false,
             default_window_size: None,
+            default_clear_color: None,
             max_capture_frame_jobs: None,
             capture_frame_timeout: None,
             backends: Self::DEFAULT_BACKENDS,
@@ -294,6 +356,7 @@ where
             exit,
             create_default_window,
             default_window_size,
+            default_clear_color,
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends,
@@ -308,6 +371,7 @@ where
             exit,
             create_default_window,
             default_window_size,
+            default_clear_color,
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends
@@ -394,12 +458,89 @@ where
         self
     }
 
+    /// Specify the default color with which window backgrounds are cleared.
+    ///
+    /// If a window is created and its `clear_color` is not specified, this color will be used. See
+    /// `window::Builder::clear_color` for details.
+    pub fn clear_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.default_clear_color = Some(color.into_lin_srgba());
+        self
+    }
+
     /// Specify the default initial loop mode for this app.
     pub fn loop_mode(mut self, mode: LoopMode) -> Self {
         self.config.loop_mode = mode;
         self
     }
 
+    /// Display panics within the window rather than closing it.
+    ///
+    /// When enabled, panics within the `update` and `view` functions (and the `event` function when
+    /// handling updates) are caught and the panic message and location are drawn over each window
+    /// until the next frame that completes without panicking. The message is still printed to
+    /// stderr as usual. Note that the model may be left in an inconsistent state by the interrupted
+    /// function.
+    ///
+    /// The overlay is only drawn over windows that render via a `Frame`. Windows using a
+    /// `raw_view` function or no view at all are skipped, as they have no up-to-date frame to draw
+    /// over.
+    ///
+    /// This is disabled by default and is primarily intended as an aid while learning.
+    pub fn panic_overlay(mut self, enabled: bool) -> Self {
+        self.config.panic_overlay = enabled;
+        self
+    }
+
+    /// Save a screenshot of the focused window to the given directory when the given key is
+    /// pressed.
+    ///
+    /// Screenshots are saved as PNG files named after the current UTC time, e.g.
+    /// `sketch-20240131-235959.png`, via the window's `capture_frame` method. The directory is
+    /// created if it does not already exist.
+    pub fn capture_key<P>(mut self, key: Key, dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.config.capture_key = Some((key, dir.into()));
+        self
+    }
+
+    /// Specify whether or not graphics should be smoothed.
+    ///
+    /// When `true`, windows use `Frame::DEFAULT_MSAA_SAMPLES` for multisample anti-aliasing and the
+    /// **Draw** returned by `app.draw()` tessellates curves and strokes with the finer
+    /// `App::SMOOTH_TOLERANCE`. When `false`, MSAA is disabled and lyon's default tolerance is
+    /// used. Windows that specify their own `msaa_samples` and drawings that specify their own
+    /// tolerance are unaffected.
+    ///
+    /// Smoothing increases both the number of vertices generated for curves and the fill rate
+    /// required by the GPU. Consider disabling it for sketches that draw many thousands of
+    /// primitives per frame.
+    ///
+    /// If unspecified, windows use `Frame::DEFAULT_MSAA_SAMPLES` and the default tolerance.
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.config.smooth = Some(smooth);
+        self
+    }
+
+    /// Specify when the **Draw** returned by `app.draw()` is reset.
+    ///
+    /// By default, `DrawReset::EachFrame` resets the **Draw** to its checkpoint on each call to
+    /// `app.draw()`, immediately before it is returned. The **App** does not reset the **Draw** at
+    /// any other time, e.g. before or after `view` is called.
+    ///
+    /// `DrawReset::Manual` may be used to accumulate drawings across frames, in which case the
+    /// user is responsible for calling `draw.reset()` to bound memory use. For simple trails,
+    /// consider drawing a translucent background each frame instead, or see
+    /// `App::feedback_buffer`.
+    pub fn draw_reset(mut self, draw_reset: DrawReset) -> Self {
+        self.config.draw_reset = draw_reset;
+        self
+    }
+
     /// The maximum number of simultaneous capture frame jobs that can be run per window before we
     /// block and wait for the existing jobs to complete.
     ///
@@ -497,6 +638,7 @@ where
             event_loop_proxy,
             event_loop_window_target,
             self.default_window_size,
+            self.default_clear_color,
             max_capture_frame_jobs,
             capture_frame_timeout,
             self.backends,
@@ -551,6 +693,52 @@ where
         self
     }
 
+    /// Display panics within the sketch window rather than closing it.
+    ///
+    /// This method delegates to `Builder::panic_overlay`.
+    pub fn panic_overlay(mut self, enabled: bool) -> Self {
+        self.builder = self.builder.panic_overlay(enabled);
+        self
+    }
+
+    /// Save a screenshot of the sketch window to the given directory when the given key is pressed.
+    ///
+    /// This method delegates to `Builder::capture_key`.
+    pub fn capture_key<P>(mut self, key: Key, dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.builder = self.builder.capture_key(key, dir);
+        self
+    }
+
+    /// Specify whether or not graphics should be smoothed.
+    ///
+    /// This method delegates to `Builder::smooth`.
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.builder = self.builder.smooth(smooth);
+        self
+    }
+
+    /// Specify when the **Draw** returned by `app.draw()` is reset.
+    ///
+    /// This method delegates to `Builder::draw_reset`.
+    pub fn draw_reset(mut self, draw_reset: DrawReset) -> Self {
+        self.builder = self.builder.draw_reset(draw_reset);
+        self
+    }
+
+    /// The color with which the sketch window background is cleared.
+    ///
+    /// This method delegates to `Builder::clear_color`.
+    pub fn clear_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.builder = self.builder.clear_color(color);
+        self
+    }
+
     /// Build and run a `Sketch` with the specified parameters.
     ///
     /// This calls `App::run` internally. See that method for details!
@@ -636,6 +824,10 @@ impl Default for Config {
             loop_mode,
             exit_on_escape,
             fullscreen_on_shortcut,
+            panic_overlay: false,
+            capture_key: None,
+            smooth: None,
+            draw_reset: DrawReset::EachFrame,
         }
     }
 }
@@ -644,6 +836,8 @@ impl App {
     pub const ASSETS_DIRECTORY_NAME: &'static str = "assets";
     pub const DEFAULT_EXIT_ON_ESCAPE: bool = true;
     pub const DEFAULT_FULLSCREEN_ON_SHORTCUT: bool = true;
+    /// The tessellation tolerance used by `app.draw()` when smoothing is enabled.
+    pub const SMOOTH_TOLERANCE: f32 = 0.02;
 
     // Create a new `App`.
     fn new(
@@ -651,6 +845,7 @@ impl App {
         event_loop_proxy: Proxy,
         event_loop_window_target: Option<EventLoopWindowTarget>,
         default_window_size: Option<DefaultWindowSize>,
+        default_clear_color: Option<LinSrgba>,
         max_capture_frame_jobs: u32,
         capture_frame_timeout: Option<Duration>,
         backends: wgpu::Backends,
@@ -664,19 +859,27 @@ impl App {
         let draw = RefCell::new(draw::Draw::default());
         let config = RefCell::new(config);
         let renderers = RefCell::new(Default::default());
-        let draw_state = DrawState { draw, renderers };
+        let feedback_buffers = RefCell::new(Vec::new());
+        let draw_state = DrawState {
+            draw,
+            renderers,
+            feedback_buffers,
+        };
         let focused_window = RefCell::new(None);
         let mouse = state::Mouse::new();
         let keys = state::Keys::default();
         let duration = state::Time::default();
+        let clock = RefCell::new(state::Clock::default());
         let time = duration.since_start.secs() as _;
         let app = App {
             event_loop_proxy,
             event_loop_window_target,
             default_window_size,
+            default_clear_color,
             max_capture_frame_jobs,
             capture_frame_timeout,
             focused_window,
+            clock,
             backends,
             instance,
             adapters,
@@ -756,6 +959,10 @@ impl App {
             Some(DefaultWindowSize::Logical(size)) => builder.size(size.width, size.height),
             None => builder,
         };
+        let builder = match self.default_clear_color {
+            Some(color) => builder.clear_color(color),
+            None => builder,
+        };
         builder
             .max_capture_frame_jobs(self.max_capture_frame_jobs)
             .capture_frame_timeout(self.capture_frame_timeout)
@@ -778,6 +985,10 @@ impl App {
 
     /// Return the **Id** of the currently focused window.
     ///
+    /// The focused window is the window that most recently received an event, initially the first
+    /// window created. If the focused window is closed, focus moves to one of the remaining
+    /// windows.
+    ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_id(&self) -> window::Id {
         self.focused_window
@@ -792,23 +1003,77 @@ impl App {
         windows.keys().cloned().collect()
     }
 
+    /// Whether or not any of the app's windows currently have input focus.
+    ///
+    /// Useful for pausing expensive work while the app is in the background, e.g. in combination
+    /// with `LoopMode::Wait`.
+    pub fn is_focused(&self) -> bool {
+        self.windows.borrow().values().any(|w| w.is_focused())
+    }
+
     /// Return the **Rect** for the currently focused window.
     ///
-    /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor).
+    /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor) and
+    /// are centred on the origin, the same as those returned by **Window::rect** and
+    /// **Frame::rect**. See `window_id` for which window is considered focused.
+    ///
+    /// With multiple windows, the focused window is not necessarily the one being drawn to or
+    /// updated. Use `frame.rect()` within a `view` function or `app.window(id).rect()` for the
+    /// rect of a specific window.
     ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_rect(&self) -> geom::Rect<f32> {
         self.main_window().rect()
     }
 
+    /// Return the **Rect** for the currently focused window in physical pixels.
+    ///
+    /// This is the same as `window_rect` scaled by `scale_factor`, and is useful for pixel-exact
+    /// work such as sizing textures and buffers to match the window's surface.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn window_rect_physical(&self) -> geom::Rect<f32> {
+        let (w, h) = self.main_window().inner_size_pixels();
+        geom::Rect::from_w_h(w as f32, h as f32)
+    }
+
+    /// Begin recording the frames of the currently focused window to a video file.
+    ///
+    /// See `Window::start_recording` for details.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn start_recording<P>(&self, path: P, fps: f64) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        self.main_window().start_recording(path, fps)
+    }
+
+    /// Stop recording the frames of the currently focused window.
+    ///
+    /// See `Window::stop_recording` for details.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn stop_recording(&self) -> std::io::Result<()> {
+        self.main_window().stop_recording()
+    }
+
+    /// The ratio of physical pixels to logical points for the currently focused window.
+    ///
+    /// All coordinates exposed by the **App** (e.g. `window_rect` and `mouse`) are described in
+    /// logical points unless stated otherwise. Multiply by this factor to convert to pixels.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn scale_factor(&self) -> f32 {
+        self.main_window().scale_factor()
+    }
+
     /// A reference to the window currently in focus.
     ///
     /// **Panics** if their are no windows open in the **App**.
     ///
-    /// Uses the **App::window** method internally.
-    ///
-    /// TODO: Currently this produces a reference to the *focused* window, but this behaviour
-    /// should be changed to track the "main" window (the first window created?).
+    /// Uses the **App::window** method internally. See `window_id` for which window is considered
+    /// focused.
     pub fn main_window(&self) -> std::cell::Ref<Window> {
         self.window(self.window_id())
             .expect("no window for focused id")
@@ -843,6 +1108,49 @@ impl App {
         &self.adapters
     }
 
+    /// The set of features supported by the adapter of the focused window.
+    ///
+    /// This may be used to check for optional features like push constants or multi-draw-indirect
+    /// before requesting them via a window's `device_descriptor`.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn gpu_features(&self) -> wgpu::Features {
+        self.main_window().adapter().features()
+    }
+
+    /// The best limits supported by the adapter of the focused window.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn gpu_limits(&self) -> wgpu::Limits {
+        self.main_window().adapter().limits()
+    }
+
+    /// Information about the adapter of the focused window, including its name, vendor, device
+    /// type and backend.
+    ///
+    /// This is useful to include in bug reports.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn adapter_info(&self) -> wgpu::AdapterInfo {
+        self.main_window().adapter().get_info()
+    }
+
+    /// Allocate a double-buffered storage buffer large enough for `count` instances of `T` on the
+    /// focused window's device.
+    ///
+    /// Both buffers have the `STORAGE`, `VERTEX`, `COPY_SRC` and `COPY_DST` usages enabled,
+    /// allowing them to be written by a compute shader and then drawn as instance data. See
+    /// `wgpu::PingPongBuffer` for details.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus.
+    pub fn new_particle_buffer<T>(&self, count: usize) -> wgpu::PingPongBuffer
+    where
+        T: wgpu::bytemuck::Pod,
+    {
+        let window = self.main_window();
+        wgpu::PingPongBuffer::new::<T>(window.device(), Some("nannou_particle_buffer"), count)
+    }
+
     /// Return whether or not the `App` is currently set to exit when the `Escape` key is pressed.
     pub fn exit_on_escape(&self) -> bool {
         self.config.borrow().exit_on_escape
@@ -898,15 +1206,92 @@ impl App {
         self.event_loop_proxy.clone()
     }
 
+    /// Run the given function on a background thread, returning a handle to its result.
+    ///
+    /// This is useful for long computations (e.g. generating a large mesh) that would otherwise
+    /// block the frame. The function runs on the app's pool of blocking threads and the app is
+    /// woken up once it completes so that the result may be collected via `Task::try_take` within
+    /// `update`, even in `LoopMode::Wait`.
+    ///
+    /// **Note:** The function must not attempt to access the **App**, which is not `Send`. Clone
+    /// any required data into the function instead.
+    pub fn spawn_task<F, T>(&self, task: F) -> Task<T>
+    where
+        F: 'static + FnOnce() -> T + Send,
+        T: 'static + Send,
+    {
+        let proxy = self.create_proxy();
+        let handle = tokio::task::spawn_blocking(move || {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(task));
+            // Wake the app even if the task panicked. An error only indicates that the app exited.
+            let _ = proxy.wakeup();
+            match result {
+                Ok(output) => output,
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        });
+        Task {
+            handle: Some(handle),
+        }
+    }
+
     /// Produce the **App**'s **Draw** API for drawing geometry and text with colors and textures.
     ///
     /// **Note:** You can also create your own **Draw** instances via `Draw::new()`! This method
     /// makes it a tiny bit easier as the **App** stores the **Draw** instance for you and
-    /// automatically resets the state on each call to `app.draw()`.
+    /// automatically resets the state on each call to `app.draw()`. Any baseline marked via
+    /// `Draw::checkpoint` is retained between calls - see `Draw::reset_to_checkpoint`. This reset
+    /// can be disabled via `Builder::draw_reset`.
+    ///
+    /// The same **Draw** is shared between all windows. As the state is reset on each call, a
+    /// `draw.background()` set within one window's `view` does not carry over to another window's
+    /// `view` unless it was set before the checkpoint. Windows that do not set a background are not
+    /// cleared. With `DrawReset::Manual`, the background and all drawings carry over to every
+    /// window until the **Draw** is reset.
+    ///
+    /// If smoothing is enabled via `Builder::smooth`, the returned **Draw** uses
+    /// `App::SMOOTH_TOLERANCE`.
     pub fn draw(&self) -> draw::Draw {
         let draw = self.draw_state.draw.borrow_mut();
-        draw.reset();
-        draw.clone()
+        let config = self.config.borrow();
+        match config.draw_reset {
+            DrawReset::EachFrame => draw.reset_to_checkpoint(),
+            DrawReset::Manual => (),
+        }
+        draw.set_retain_commands(config.draw_reset == DrawReset::Manual);
+        match config.smooth {
+            Some(true) => draw.tolerance(Self::SMOOTH_TOLERANCE),
+            _ => draw.clone(),
+        }
+    }
+
+    // The number of MSAA samples used by windows that do not specify their own.
+    pub(crate) fn default_msaa_samples(&self) -> u32 {
+        match self.config.borrow().smooth {
+            Some(false) => 1,
+            _ => Frame::DEFAULT_MSAA_SAMPLES,
+        }
+    }
+
+    /// Create a double-buffered render target for feedback effects on the main window's device.
+    ///
+    /// The buffer's `draw_prev()` commands are rendered into its back texture and the textures are
+    /// swapped automatically at the end of each update, after which the result may be sampled via
+    /// `prev_texture()`. See the **FeedbackBuffer** docs for an example.
+    ///
+    /// The buffer is no longer rendered once all handles to it have been dropped.
+    pub fn feedback_buffer(
+        &self,
+        size: [u32; 2],
+        format: wgpu::TextureFormat,
+    ) -> draw::FeedbackBuffer {
+        let device_queue_pair = self.main_window().device_queue_pair().clone();
+        let buffer = draw::FeedbackBuffer::new(device_queue_pair, size, format);
+        self.draw_state
+            .feedback_buffers
+            .borrow_mut()
+            .push(buffer.downgrade());
+        buffer
     }
 
     /// The number of times the focused window's **view** function has been called since the start
@@ -920,6 +1305,63 @@ impl App {
         self.duration.updates_per_second()
     }
 
+    /// The rate at which `app.time` advances relative to real time.
+    pub fn time_scale(&self) -> f32 {
+        self.clock.borrow().scale() as _
+    }
+
+    /// Set the rate at which `app.time` advances relative to real time.
+    ///
+    /// E.g. `0.5` for slow motion, `2.0` for double speed or a negative value to run backwards.
+    /// This does not affect `app.duration` or the `Update` passed to the user's functions.
+    pub fn set_time_scale(&self, scale: f32) {
+        self.clock.borrow_mut().set_scale(scale as _);
+    }
+
+    /// Stop `app.time` from advancing until `resume_time` is called.
+    pub fn pause_time(&self) {
+        self.clock.borrow_mut().set_paused(true);
+    }
+
+    /// Allow `app.time` to advance again after a call to `pause_time`.
+    pub fn resume_time(&self) {
+        self.clock.borrow_mut().set_paused(false);
+    }
+
+    /// Whether or not `app.time` is currently paused.
+    pub fn is_time_paused(&self) -> bool {
+        self.clock.borrow().is_paused()
+    }
+
+    /// Set the value of `app.time` in seconds, e.g. to scrub through an animation.
+    ///
+    /// As the **App** is shared immutably with the user's functions, `app.time` reflects the new
+    /// value from the next update onwards.
+    pub fn set_time(&self, secs: f32) {
+        self.clock.borrow_mut().set_secs(secs as _);
+    }
+
+    /// Specify the length of a loop over which `app.loop_progress()` advances from `0.0` to `1.0`.
+    ///
+    /// This is useful for perfectly looping animations that are parameterised by a normalised
+    /// time. The loop follows the same virtual clock as `app.time`, so it may also be paused,
+    /// scaled and scrubbed.
+    pub fn set_loop_duration(&self, duration: Duration) {
+        self.clock.borrow_mut().set_loop_duration(Some(duration));
+    }
+
+    /// The loop length specified via `set_loop_duration`, if any.
+    pub fn loop_duration(&self) -> Option<Duration> {
+        self.clock.borrow().loop_duration()
+    }
+
+    /// The progress through the current loop in the range `0.0..1.0`.
+    ///
+    /// Returns `0.0` if no loop duration has been specified via `set_loop_duration`.
+    pub fn loop_progress(&self) -> f32 {
+        self.clock.borrow().loop_progress() as _
+    }
+
     /// The name of the nannou executable that is currently running.
     pub fn exe_name(&self) -> std::io::Result<String> {
         let string = std::env::current_exe()?
@@ -957,6 +1399,37 @@ impl Proxy {
     }
 }
 
+impl<T> Task<T> {
+    /// Whether or not the task has completed.
+    ///
+    /// Also returns `true` once the result has been taken.
+    pub fn is_finished(&self) -> bool {
+        self.handle
+            .as_ref()
+            .map(|handle| handle.is_finished())
+            .unwrap_or(true)
+    }
+
+    /// Take the result of the task if it has completed.
+    ///
+    /// Returns `None` if the task is still running or if the result was already taken. If the
+    /// task panicked, the panic is resumed on the calling thread.
+    pub fn try_take(&mut self) -> Option<T> {
+        use futures::FutureExt;
+        let handle = self.handle.as_mut()?;
+        if !handle.is_finished() {
+            return None;
+        }
+        let result = handle.now_or_never()?;
+        self.handle = None;
+        match result {
+            Ok(output) => Some(output),
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => None,
+        }
+    }
+}
+
 impl draw::Draw {
     /// Render the **Draw**'s inner list of commands to the texture associated with the **Frame**.
     ///
@@ -1106,6 +1579,13 @@ fn run_loop<M, E>(
         total_updates: 0,
     };
 
+    // Install the panic overlay's hook if requested.
+    let mut panic_overlay = if app.config.borrow().panic_overlay {
+        Some(PanicOverlay::install())
+    } else {
+        None
+    };
+
     // Run the event loop.
     event_loop.run(move |mut event, event_loop_window_target, control_flow| {
         // Set the event loop window target pointer to allow for building windows.
@@ -1122,7 +1602,15 @@ fn run_loop<M, E>(
                     let loop_mode = app.loop_mode();
                     let now = Instant::now();
                     let mut do_update = |loop_state: &mut LoopState| {
-                        apply_update(&mut app, model, event_fn, update_fn, loop_state, now);
+                        apply_update(
+                            &mut app,
+                            model,
+                            event_fn,
+                            update_fn,
+                            loop_state,
+                            &mut panic_overlay,
+                            now,
+                        );
                     };
                     match loop_mode {
                         LoopMode::NTimes { number_of_updates }
@@ -1229,7 +1717,16 @@ fn run_loop<M, E>(
                         // Otherwise, use the fallback, default view passed to the app if there was one.
                         let window_view = window.user_functions.view.clone();
 
-                        match window_view {
+                        // Whether or not the view renders to the window's intermediary `Frame`.
+                        let view_uses_frame = match window_view {
+                            Some(window::View::Sketch(_)) | Some(window::View::WithModel(_)) => {
+                                true
+                            }
+                            Some(window::View::WithModelRaw(_)) => false,
+                            None => default_view.is_some(),
+                        };
+
+                        let view = || match window_view {
                             Some(window::View::Sketch(view)) => {
                                 let data = frame_data.as_ref().expect("missing `frame_data`");
                                 let frame =
@@ -1266,6 +1763,30 @@ fn run_loop<M, E>(
                                 }
                                 None => raw_frame.submit(),
                             },
+                        };
+
+                        // Draw the panic overlay over the result of `view` if necessary.
+                        match panic_overlay {
+                            None => view(),
+                            Some(ref mut overlay) => {
+                                let view_succeeded = overlay.catch(view);
+                                overlay.view_finished(view_succeeded);
+                                let frame_data = frame_data.as_ref().filter(|_| view_uses_frame);
+                                if let (Some(message), Some(data)) = (&overlay.message, frame_data)
+                                {
+                                    let raw_frame = RawFrame::new_empty(
+                                        window.device_queue_pair().clone(),
+                                        window_id,
+                                        nth_frame,
+                                        surface_texture,
+                                        window.surface_conf.format,
+                                        window_rect,
+                                    );
+                                    let frame =
+                                        Frame::new_empty(raw_frame, &data.render, &data.capture);
+                                    draw_panic_overlay(&app, &frame, message);
+                                }
+                            }
                         }
 
                         // Queue has been submitted by now, time to present.
@@ -1317,7 +1838,12 @@ fn run_loop<M, E>(
             // Ignore `NewEvents`.
             winit::event::Event::NewEvents(_)
             // `LoopDestroyed` is handled later in `process_and_emit_winit_event` so ignore it here.
-            | winit::event::Event::LoopDestroyed => {}
+            | winit::event::Event::LoopDestroyed => {
+                // Restore the original panic hook.
+                if let Some(overlay) = panic_overlay.take() {
+                    overlay.uninstall();
+                }
+            }
         }
 
         // We must reconfigure the wgpu surface if the window was resized.
@@ -1334,6 +1860,18 @@ fn run_loop<M, E>(
                     }
                 }
 
+                winit::event::WindowEvent::Focused(focused) => {
+                    if let Some(window) = app.windows.borrow_mut().get_mut(&window_id) {
+                        window.tracked_state.focused = *focused;
+                    }
+                }
+
+                winit::event::WindowEvent::Occluded(occluded) => {
+                    if let Some(window) = app.windows.borrow_mut().get_mut(&window_id) {
+                        window.tracked_state.occluded = *occluded;
+                    }
+                }
+
                 winit::event::WindowEvent::ScaleFactorChanged {
                     scale_factor,
                     new_inner_size,
@@ -1388,6 +1926,100 @@ fn run_loop<M, E>(
     }
 }
 
+impl PanicOverlay {
+    // Install a panic hook that records each panic before calling the existing hook.
+    fn install() -> Self {
+        let last_panic = Arc::new(Mutex::new(None));
+        let prev_hook = Arc::new(std::panic::take_hook());
+        let hook_last_panic = last_panic.clone();
+        let hook_prev_hook = prev_hook.clone();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Ok(mut last_panic) = hook_last_panic.lock() {
+                *last_panic = Some(info.to_string());
+            }
+            hook_prev_hook(info);
+        }));
+        let restore_hook = Box::new(move || {
+            let _ = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| prev_hook(info)));
+        });
+        PanicOverlay {
+            last_panic,
+            message: None,
+            panicked_since_view: false,
+            restore_hook,
+        }
+    }
+
+    // Restore the panic hook that was in place before `install`.
+    fn uninstall(self) {
+        (self.restore_hook)();
+    }
+
+    // Call `f`, catching any panic and storing its message for display.
+    //
+    // Returns whether or not `f` completed without panicking.
+    fn catch<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(),
+    {
+        let payload = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+            Ok(()) => return true,
+            Err(payload) => payload,
+        };
+        let recorded = self.last_panic.lock().ok().and_then(|mut last| last.take());
+        let message = recorded
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked with an unknown payload".to_string());
+        self.message = Some(message);
+        self.panicked_since_view = true;
+        false
+    }
+
+    // Call `f`, catching any panic if the overlay is enabled.
+    fn catch_if_enabled<F>(overlay: &mut Option<PanicOverlay>, f: F)
+    where
+        F: FnOnce(),
+    {
+        match overlay {
+            None => f(),
+            Some(overlay) => {
+                overlay.catch(f);
+            }
+        }
+    }
+
+    // Clear the message if the frame completed without panicking.
+    fn view_finished(&mut self, view_succeeded: bool) {
+        if view_succeeded && !self.panicked_since_view {
+            self.message = None;
+        }
+        self.panicked_since_view = false;
+    }
+}
+
+// Draw the given panic message over the given frame.
+fn draw_panic_overlay(app: &App, frame: &Frame, message: &str) {
+    let rect = frame.rect();
+    let draw = crate::Draw::new();
+    draw.rect()
+        .xy(rect.xy())
+        .wh(rect.wh())
+        .rgba(0.1, 0.0, 0.0, 0.85);
+    draw.text(message)
+        .xy(rect.xy())
+        .wh(rect.pad(20.0).wh())
+        .font_size(16)
+        .left_justify()
+        .align_text_top()
+        .rgb(1.0, 0.6, 0.6);
+    if let Err(err) = draw.to_frame(app, frame) {
+        // TODO: Log errors
+        eprintln!("failed to draw the panic overlay: {:?}", err);
+    }
+}
+
 // Apply an update to the model via the user's function and update the app and loop state
 // accordingly.
 fn apply_update<M, E>(
@@ -1396,6 +2028,7 @@ fn apply_update<M, E>(
     event_fn: Option<EventFn<M, E>>,
     update_fn: Option<UpdateFn<M>>,
     loop_state: &mut LoopState,
+    panic_overlay: &mut Option<PanicOverlay>,
     now: Instant,
 ) where
     M: 'static,
@@ -1406,7 +2039,12 @@ fn apply_update<M, E>(
     let since_start = now.duration_since(loop_state.loop_start);
     app.duration.since_prev_update = since_last;
     app.duration.since_start = since_start;
-    app.time = since_start.secs() as _;
+    let time = {
+        let mut clock = app.clock.borrow_mut();
+        clock.advance(since_last);
+        clock.secs()
+    };
+    app.time = time as _;
     let update = crate::event::Update {
         since_start,
         since_last,
@@ -1414,12 +2052,26 @@ fn apply_update<M, E>(
     // User event function.
     if let Some(event_fn) = event_fn {
         let event = E::from(update.clone());
-        event_fn(app, model, event);
+        PanicOverlay::catch_if_enabled(panic_overlay, || event_fn(app, model, event));
     }
     // User update function.
     if let Some(update_fn) = update_fn {
-        update_fn(app, model, update);
-    }
+        PanicOverlay::catch_if_enabled(panic_overlay, || update_fn(app, model, update));
+    }
+    // Render and swap feedback buffers, forgetting those that have been dropped.
+    app.draw_state
+        .feedback_buffers
+        .borrow_mut()
+        .retain(|weak| match weak.upgrade() {
+            Some(buffer) => {
+                buffer.render_and_swap();
+                true
+            }
+            None => false,
+        });
+    // Mouse deltas are accumulated between updates.
+    app.mouse.delta = geom::Vec2::ZERO;
+    app.mouse.scroll_delta = geom::Vec2::ZERO;
     loop_state.last_update = now;
     loop_state.total_updates += 1;
     loop_state.updates_since_event += 1;
@@ -1430,6 +2082,50 @@ fn apply_update<M, E>(
     }
 }
 
+// If the given event is a press of the capture key, produce the path for the screenshot.
+fn capture_key_path(app: &App, winit_event: &winit::event::WindowEvent) -> Option<PathBuf> {
+    let input = match *winit_event {
+        winit::event::WindowEvent::KeyboardInput { ref input, .. } => input,
+        _ => return None,
+    };
+    if input.state != event::ElementState::Pressed {
+        return None;
+    }
+    let config = app.config.borrow();
+    let (key, ref dir) = *config.capture_key.as_ref()?;
+    if input.virtual_keycode != Some(key) {
+        return None;
+    }
+    let now = std::time::SystemTime::now();
+    Some(dir.join(capture_file_name(now)))
+}
+
+// The file name of a screenshot taken at the given time, e.g. `sketch-20240131-235959.png`.
+fn capture_file_name(time: std::time::SystemTime) -> String {
+    let secs = time
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+    let (hour, min, sec) = (secs_of_day / 3600, secs_of_day / 60 % 60, secs_of_day % 60);
+
+    // Convert days since the epoch to a civil date. See Howard Hinnant's `civil_from_days`.
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "sketch-{:04}{:02}{:02}-{:02}{:02}{:02}.png",
+        year, month, day, hour, min, sec
+    )
+}
+
 // Whether or not the given event should toggle fullscreen.
 fn should_toggle_fullscreen(
     winit_event: &winit::event::WindowEvent,
@@ -1509,7 +2205,13 @@ where
         // Returns the `Window` that was removed.
         fn remove_related_window_state(app: &App, window_id: &window::Id) -> Option<Window> {
             app.draw_state.renderers.borrow_mut().remove(window_id);
-            app.windows.borrow_mut().remove(window_id)
+            let removed = app.windows.borrow_mut().remove(window_id);
+            // Move focus to one of the remaining windows, if any.
+            let mut focused = app.focused_window.borrow_mut();
+            if focused.as_ref() == Some(window_id) {
+                *focused = app.windows.borrow().keys().next().cloned();
+            }
+            removed
         }
 
         if let winit::event::WindowEvent::Destroyed = *event {
@@ -1523,6 +2225,11 @@ where
             // Get the size of the window for translating coords and dimensions.
             let (win_w, win_h, scale_factor) = match app.window(window_id) {
                 Some(win) => {
+                    // If the capture key was pressed, save a screenshot of this window.
+                    if let Some(path) = capture_key_path(app, event) {
+                        win.capture_frame(path);
+                    }
+
                     // If we should toggle fullscreen for this window, do so.
                     if app.fullscreen_on_shortcut() {
                         if should_toggle_fullscreen(event, &app.keys.mods) {
@@ -1558,6 +2265,10 @@ where
                     let (x, y) = position.to_logical::<f32>(scale_factor).into();
                     let x = tx(x);
                     let y = ty(y);
+                    // Only accumulate movement within the same window to avoid jumps.
+                    if app.mouse.window == Some(window_id) {
+                        app.mouse.delta += geom::pt2(x, y) - app.mouse.position();
+                    }
                     app.mouse.x = x;
                     app.mouse.y = y;
                     app.mouse.window = Some(window_id);
@@ -1576,6 +2287,20 @@ where
                     app.mouse.window = Some(window_id);
                 }
 
+                winit::event::WindowEvent::MouseWheel { delta, .. } => {
+                    let delta = match delta {
+                        event::MouseScrollDelta::LineDelta(x, y) => {
+                            geom::vec2(x, y) * state::Mouse::POINTS_PER_SCROLL_LINE
+                        }
+                        event::MouseScrollDelta::PixelDelta(pos) => {
+                            let (x, y) = pos.to_logical::<f32>(scale_factor).into();
+                            geom::vec2(x, y)
+                        }
+                    };
+                    app.mouse.scroll_delta += delta;
+                    app.mouse.window = Some(window_id);
+                }
+
                 winit::event::WindowEvent::KeyboardInput { input, .. } => {
                     if let Some(key) = input.virtual_keycode {
                         match input.state {
@@ -1728,6 +2453,8 @@ where
                 event::WindowEvent::DroppedFile(path) => call_user_function!(dropped_file, path),
                 event::WindowEvent::Focused => call_user_function!(focused),
                 event::WindowEvent::Unfocused => call_user_function!(unfocused),
+                event::WindowEvent::Occluded => call_user_function!(occluded),
+                event::WindowEvent::Unoccluded => call_user_function!(unoccluded),
                 event::WindowEvent::Closed => call_user_function!(closed),
             }
         }
@@ -1748,3 +2475,17 @@ where
 
     exit
 }
+
+#[test]
+fn test_capture_file_name() {
+    let at = |secs| std::time::UNIX_EPOCH + Duration::from_secs(secs);
+    assert_eq!(capture_file_name(at(0)), "sketch-19700101-000000.png");
+    assert_eq!(
+        capture_file_name(at(951_782_400)),
+        "sketch-20000229-000000.png"
+    );
+    assert_eq!(
+        capture_file_name(at(1_706_745_599)),
+        "sketch-20240131-235959.png"
+    );
+}
@@ -45,6 +45,8 @@ pub struct DrawingContext<'a> {
     pub path_points_colored_buffer: &'a mut Vec<(Point2, Color)>,
     /// A re-usable buffer for collecting textured polyline points.
     pub path_points_textured_buffer: &'a mut Vec<(Point2, TexCoords)>,
+    /// A re-usable buffer for collecting weighted polyline points.
+    pub path_points_weighted_buffer: &'a mut Vec<(Point2, f32)>,
     /// A re-usable buffer for collecting text.
     pub text_buffer: &'a mut String,
 }
@@ -77,6 +79,7 @@ impl<'a> DrawingContext<'a> {
             ref mut path_event_buffer,
             ref mut path_points_colored_buffer,
             ref mut path_points_textured_buffer,
+            ref mut path_points_weighted_buffer,
             ref mut text_buffer,
         } = *state;
         DrawingContext {
@@ -84,6 +87,7 @@ impl<'a> DrawingContext<'a> {
             path_event_buffer: path_event_buffer,
             path_points_colored_buffer: path_points_colored_buffer,
             path_points_textured_buffer: path_points_textured_buffer,
+            path_points_weighted_buffer: path_points_weighted_buffer,
             text_buffer: text_buffer,
         }
     }
@@ -104,8 +108,29 @@ impl<'a, T> Drawing<'a, T> {
     /// Complete the drawing and insert it into the parent **Draw** instance.
     ///
     /// This will be called when the **Drawing** is **Drop**ped if it has not yet been called.
-    pub fn finish(mut self) {
-        self.finish_inner()
+    /// Calling it explicitly is useful for ensuring the primitive is inserted into the command list
+    /// immediately, rather than at the end of the enclosing scope.
+    ///
+    /// Returns the index of the primitive's command within the **Draw**'s list of commands.
+    pub fn finish(mut self) -> usize {
+        self.finish_inner();
+        self.finish_on_drop = false;
+        self.index
+    }
+
+    /// Associate the drawing with a stable, user-supplied ID.
+    ///
+    /// Unlike the draw command index returned by `finish`, the ID does not depend on the order in
+    /// which shapes are drawn, allowing the same logical shape to be tracked across frames even
+    /// when other shapes are conditionally skipped. See `Draw::index_of` and `Draw::id_of`.
+    ///
+    /// IDs should be unique between resets of the **Draw**. Reusing an ID moves it to the most
+    /// recent drawing.
+    pub fn id(self, id: u64) -> Self {
+        if let Ok(mut state) = self.draw.state.try_borrow_mut() {
+            state.ids.insert(id, self.index);
+        }
+        self
     }
 
     // Map the given function onto the primitive stored within **Draw** at `index`.
@@ -179,6 +204,21 @@ impl<'a, T> Drawing<'a, T> {
         })
     }
 
+    // Apply the given function to a copy of the type stored within **Draw** along with the
+    // **Draw**'s text buffer.
+    //
+    // Returns `None` if the node has already been **Drawn** or if the state is already borrowed.
+    pub(crate) fn with_ty_and_text_buffer<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T, &str) -> R,
+        Primitive: Into<Option<T>>,
+    {
+        let state = self.draw.state.try_borrow().ok()?;
+        let ty: T = state.drawing.get(&self.index)?.clone().into()?;
+        let intermediary_state = state.intermediary_state.borrow();
+        Some(f(&ty, &intermediary_state.text_buffer))
+    }
+
     /// Apply the given function to the type stored within **Draw**.
     ///
     /// The function is only applied if the node has not yet been **Drawn**.
@@ -374,6 +414,20 @@ where
     }
 
     /// Build with the given **Absolute** **Position** along the *z* axis.
+    ///
+    /// This places the drawing itself at the given depth, allowing 2D primitives to be
+    /// interleaved with 3D geometry. It is applied on top of the **Draw**'s transform, so a
+    /// rect drawn with `draw.z(10.0).rect().z(5.0)` is placed at a depth of `15.0`. By contrast,
+    /// `Draw::z` translates every drawing made with the returned **Draw**.
+    ///
+    /// Positive values are nearer to the viewer. Depths further than the larger of the frame's
+    /// dimensions (in points) either side of `0.0` are clipped.
+    ///
+    /// While depth testing is enabled (the default), a drawing placed behind another is hidden by
+    /// it regardless of the order in which they were drawn or their `z_order`. Note that
+    /// primitives are not sorted by depth, so a translucent drawing must be submitted after any
+    /// drawings behind it in order to blend with them. See `Draw::depth_test` and
+    /// `Draw::z_order`.
     pub fn z(self, z: f32) -> Self {
         self.map_ty(|ty| SetPosition::z(ty, z))
     }
@@ -0,0 +1,158 @@
+//! A double-buffered render target for feedback effects.
+//!
+//! See the [**FeedbackBuffer** type](./struct.FeedbackBuffer.html) for more details.
+
+use crate::draw::{self, Draw};
+use crate::wgpu;
+use std::cell::RefCell;
+use std::rc::{self, Rc};
+use std::sync::Arc;
+
+/// A pair of equally sized textures for feedback (or "trail") effects.
+///
+/// Feedback effects sample the result of the previous frame, draw on top of it and then display
+/// the result. The **FeedbackBuffer** takes care of this by providing a **Draw** that renders into
+/// the *back* texture while the *front* texture, containing the previous result, may be sampled.
+///
+/// Buffers created via `app.feedback_buffer(size, format)` are rendered and swapped automatically
+/// at the end of each update. A typical sketch looks like this:
+///
+/// ```ignore
+/// fn update(app: &App, model: &mut Model, _update: Update) {
+///     let draw = model.feedback.draw_prev();
+///     // Draw the previous result, then fade it slightly.
+///     draw.texture(&model.feedback.prev_texture());
+///     draw.rect().w_h(512.0, 512.0).rgba(0.0, 0.0, 0.0, 0.05);
+///     draw.ellipse().x_y(app.mouse.x, app.mouse.y);
+/// }
+///
+/// fn view(app: &App, model: &Model, frame: Frame) {
+///     let draw = app.draw();
+///     draw.texture(&model.feedback.prev_texture());
+///     draw.to_frame(app, &frame).unwrap();
+/// }
+/// ```
+///
+/// The buffers are only rendered and swapped if something was drawn via `draw_prev` since the last
+/// update. Note that the back texture still contains the result from *two* updates ago, so either
+/// draw the previous texture or specify a `background` to cover it.
+#[derive(Clone, Debug)]
+pub struct FeedbackBuffer {
+    inner: Rc<RefCell<Inner>>,
+}
+
+// A weak handle to a **FeedbackBuffer**, allowing the **App** to track live buffers.
+#[derive(Clone, Debug)]
+pub(crate) struct WeakFeedbackBuffer {
+    inner: rc::Weak<RefCell<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    textures: [wgpu::Texture; 2],
+    // The index of the texture containing the most recent result.
+    front: usize,
+    renderer: draw::Renderer,
+    draw: Draw,
+}
+
+impl FeedbackBuffer {
+    /// The usage flags enabled on both textures.
+    pub const USAGE: wgpu::TextureUsages = wgpu::TextureUsages::RENDER_ATTACHMENT
+        .union(wgpu::TextureUsages::TEXTURE_BINDING)
+        .union(wgpu::TextureUsages::COPY_SRC);
+
+    /// Create a pair of textures with the given size and format on the given device.
+    ///
+    /// Buffers created this way are *not* swapped automatically. See `render_and_swap`.
+    pub fn new(
+        device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+        size: [u32; 2],
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let device = device_queue_pair.device();
+        let create = || {
+            wgpu::TextureBuilder::new()
+                .size(size)
+                .format(format)
+                .usage(Self::USAGE)
+                .build(device)
+        };
+        let textures = [create(), create()];
+        let renderer = draw::RendererBuilder::new()
+            .build_from_texture_descriptor(device, textures[0].descriptor());
+        let inner = Inner {
+            device_queue_pair,
+            textures,
+            front: 0,
+            renderer,
+            draw: Draw::new(),
+        };
+        let inner = Rc::new(RefCell::new(inner));
+        FeedbackBuffer { inner }
+    }
+
+    /// The **Draw** that renders into the back texture.
+    ///
+    /// Commands are rendered upon the next swap, after which the result is available via
+    /// `prev_texture`.
+    pub fn draw_prev(&self) -> Draw {
+        self.inner.borrow().draw.clone()
+    }
+
+    /// A view of the front texture containing the most recent result, ready to be sampled.
+    pub fn prev_texture(&self) -> wgpu::TextureView {
+        let inner = self.inner.borrow();
+        inner.textures[inner.front].view().build()
+    }
+
+    /// The size of each texture in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        self.inner.borrow().textures[0].size()
+    }
+
+    /// The format of each texture.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.inner.borrow().textures[0].format()
+    }
+
+    /// Render the commands of `draw_prev` into the back texture and swap the textures.
+    ///
+    /// This is called automatically at the end of each update for buffers created via
+    /// `app.feedback_buffer`. Does nothing if nothing has been drawn since the last swap.
+    pub fn render_and_swap(&self) {
+        let mut inner = self.inner.borrow_mut();
+        let Inner {
+            ref device_queue_pair,
+            ref textures,
+            ref mut front,
+            ref mut renderer,
+            ref draw,
+        } = *inner;
+        if draw.state.borrow().is_empty() {
+            return;
+        }
+        let device = device_queue_pair.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("nannou_feedback_buffer"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        let back = &textures[1 - *front];
+        renderer.render_to_texture(device, &mut encoder, draw, back);
+        device_queue_pair.queue().submit(Some(encoder.finish()));
+        draw.reset();
+        *front = 1 - *front;
+    }
+
+    pub(crate) fn downgrade(&self) -> WeakFeedbackBuffer {
+        let inner = Rc::downgrade(&self.inner);
+        WeakFeedbackBuffer { inner }
+    }
+}
+
+impl WeakFeedbackBuffer {
+    pub(crate) fn upgrade(&self) -> Option<FeedbackBuffer> {
+        self.inner.upgrade().map(|inner| FeedbackBuffer { inner })
+    }
+}
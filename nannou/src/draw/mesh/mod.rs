@@ -112,6 +112,12 @@ impl Mesh {
         mesh::clear(self);
     }
 
+    // Truncate the mesh to the given number of vertices and indices.
+    pub(crate) fn truncate(&mut self, vertices: usize, indices: usize) {
+        mesh::truncate_indices(&mut self.mesh, indices);
+        mesh::truncate_vertices(&mut self.mesh, vertices);
+    }
+
     /// Produce an iterator yielding all raw (non-index-order) vertices.
     pub fn raw_vertices(&self) -> mesh::RawVertices<&Self> {
         mesh::raw_vertices(self)
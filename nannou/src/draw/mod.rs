@@ -7,22 +7,28 @@ use crate::glam::{vec3, EulerRot, Mat4, Quat, Vec2, Vec3};
 use crate::math::{deg_to_rad, turns_to_rad};
 use crate::wgpu;
 use lyon::path::PathEvent;
+use lyon::tessellation::{FillTessellator, StrokeTessellator};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::mem;
 use std::rc::Rc;
+use std::sync::atomic::{self, AtomicU64};
 
 pub use self::background::Background;
 pub use self::drawing::{Drawing, DrawingContext};
+pub use self::feedback::FeedbackBuffer;
 use self::mesh::vertex::{Color, TexCoords};
 pub use self::mesh::Mesh;
 use self::primitive::Primitive;
+use self::renderer::RenderPrimitive;
 pub use self::renderer::{Builder as RendererBuilder, Renderer};
 pub use self::theme::Theme;
 
 pub mod background;
 mod drawing;
+pub mod feedback;
 pub mod mesh;
+mod plotter;
 pub mod primitive;
 pub mod properties;
 pub mod renderer;
@@ -68,12 +74,27 @@ pub struct Context {
     // here.
     pub topology: wgpu::PrimitiveTopology,
     pub sampler: wgpu::SamplerDescriptor<'static>,
+    /// A hint used to order primitives within a frame. See `Draw::z_order`.
+    pub z_order: i32,
+    /// Whether or not primitives are tested against and write to the depth buffer. See
+    /// `Draw::depth_test`.
+    pub depth_test: bool,
+    /// The tessellation tolerance for drawings that do not specify their own. See
+    /// `Draw::tolerance`.
+    pub tolerance: Option<f32>,
 }
 
 /// Commands generated by drawings.
 ///
 /// During rendering, the list of **DrawCommand**s are converted into a list of **RenderCommands**
 /// that are directly associated with encodable render pass commands.
+///
+/// Commands may be inspected via `Draw::peek_commands` or consumed via `Draw::drain_commands` by
+/// custom renderers and exporters. Each primitive should be drawn with the most recent `Context`
+/// command, or with the default `Context` if there is none. These two variants are stable, while
+/// new variants may be added to **Primitive** as new kinds of drawings are introduced. Primitives
+/// may refer to buffers owned by the **Draw** that produced them, so use `Draw::primitive_meshes`
+/// to access their geometry before the **Draw** is reset.
 #[derive(Clone, Debug)]
 pub enum DrawCommand {
     /// Draw a primitive.
@@ -110,6 +131,8 @@ pub struct State {
     ///
     /// Keys are indices into the `draw_commands` Vec.
     drawing: HashMap<usize, Primitive>,
+    /// Maps the IDs specified via `Drawing::id` to the indices of their draw commands.
+    ids: HashMap<u64, usize>,
     /// The list of recorded draw commands.
     ///
     /// An element may be `None` if it is a primitive in the process of being drawn.
@@ -118,6 +141,37 @@ pub struct State {
     intermediary_state: RefCell<IntermediaryState>,
     /// The theme containing default values.
     theme: Theme,
+    /// The static baseline marked via `Draw::checkpoint`, if any.
+    checkpoint: Option<Checkpoint>,
+    /// Whether or not renderers should retain the commands they draw rather than draining them.
+    ///
+    /// Set by the **App** for `DrawReset::Manual`.
+    retain_commands: bool,
+}
+
+/// The state of a **Draw** at the time of a call to `Draw::checkpoint`.
+#[derive(Clone, Debug)]
+struct Checkpoint {
+    /// Uniquely identifies the baseline so that renderers may cache its vertex data.
+    id: u64,
+    /// The number of draw commands making up the baseline.
+    draw_commands: usize,
+    /// The background color specified prior to the checkpoint.
+    background_color: Option<properties::LinSrgba>,
+    /// The lengths of the intermediary buffers referred to by the baseline.
+    intermediary_lens: IntermediaryLens,
+}
+
+/// The lengths of each of the buffers within the `IntermediaryState`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct IntermediaryLens {
+    mesh_vertices: usize,
+    mesh_indices: usize,
+    path_events: usize,
+    path_points_colored: usize,
+    path_points_textured: usize,
+    path_points_weighted: usize,
+    text: usize,
 }
 
 /// State made accessible via the `DrawingContext`.
@@ -131,6 +185,8 @@ pub struct IntermediaryState {
     path_points_colored_buffer: Vec<(Point2, Color)>,
     /// A re-usable buffer for collecting textured polyline points.
     path_points_textured_buffer: Vec<(Point2, TexCoords)>,
+    /// A re-usable buffer for collecting weighted polyline points.
+    path_points_weighted_buffer: Vec<(Point2, f32)>,
     /// A buffer containing all text.
     text_buffer: String,
 }
@@ -141,8 +197,36 @@ impl IntermediaryState {
         self.path_event_buffer.clear();
         self.path_points_colored_buffer.clear();
         self.path_points_textured_buffer.clear();
+        self.path_points_weighted_buffer.clear();
         self.text_buffer.clear();
     }
+
+    // The current length of each buffer.
+    fn lens(&self) -> IntermediaryLens {
+        IntermediaryLens {
+            mesh_vertices: self.intermediary_mesh.points().len(),
+            mesh_indices: self.intermediary_mesh.indices().len(),
+            path_events: self.path_event_buffer.len(),
+            path_points_colored: self.path_points_colored_buffer.len(),
+            path_points_textured: self.path_points_textured_buffer.len(),
+            path_points_weighted: self.path_points_weighted_buffer.len(),
+            text: self.text_buffer.len(),
+        }
+    }
+
+    // Truncate each buffer to the given length.
+    fn truncate(&mut self, lens: &IntermediaryLens) {
+        self.intermediary_mesh
+            .truncate(lens.mesh_vertices, lens.mesh_indices);
+        self.path_event_buffer.truncate(lens.path_events);
+        self.path_points_colored_buffer
+            .truncate(lens.path_points_colored);
+        self.path_points_textured_buffer
+            .truncate(lens.path_points_textured);
+        self.path_points_weighted_buffer
+            .truncate(lens.path_points_weighted);
+        self.text_buffer.truncate(lens.text);
+    }
 }
 
 impl State {
@@ -152,7 +236,58 @@ impl State {
         self.last_draw_context = None;
         self.drawing.clear();
         self.draw_commands.clear();
+        self.ids.clear();
         self.intermediary_state.borrow_mut().reset();
+        self.checkpoint = None;
+    }
+
+    // Resets all state recorded since the checkpoint, or all state if there is no checkpoint.
+    fn reset_to_checkpoint(&mut self) {
+        let checkpoint = match self.checkpoint {
+            None => return self.reset(),
+            Some(ref checkpoint) => checkpoint,
+        };
+        self.background_color = checkpoint.background_color;
+        self.last_draw_context = None;
+        self.drawing.clear();
+        self.draw_commands.truncate(checkpoint.draw_commands);
+        let len = checkpoint.draw_commands;
+        self.ids.retain(|_, index| *index < len);
+        self.intermediary_state
+            .borrow_mut()
+            .truncate(&checkpoint.intermediary_lens);
+    }
+
+    // Mark all commands recorded so far as the baseline.
+    fn checkpoint(&mut self) {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        self.finish_remaining_drawings();
+        // Ensure commands drawn after the checkpoint begin by specifying their context.
+        self.last_draw_context = None;
+        self.checkpoint = Some(Checkpoint {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            draw_commands: self.draw_commands.len(),
+            background_color: self.background_color,
+            intermediary_lens: self.intermediary_state.borrow().lens(),
+        });
+    }
+
+    // Remove the IDs of any commands at or beyond the given index.
+    fn truncate_ids(&mut self, len: usize) {
+        self.ids.retain(|_, index| *index < len);
+    }
+
+    // The number of draw commands within the baseline.
+    fn baseline_len(&self) -> usize {
+        self.checkpoint
+            .as_ref()
+            .map(|c| c.draw_commands)
+            .unwrap_or(0)
+    }
+
+    // Whether or not nothing has been drawn since the last reset.
+    fn is_empty(&self) -> bool {
+        self.background_color.is_none() && self.draw_commands.is_empty()
     }
 
     // Drain any remaining `drawing`s and insert them as draw commands.
@@ -180,6 +315,9 @@ impl State {
 }
 
 impl Draw {
+    /// The default distance within which `hit_test` considers a point to touch a shape.
+    pub const DEFAULT_HIT_TEST_TOLERANCE: f32 = 1.0;
+
     /// Create a new **Draw** instance.
     ///
     /// This is the same as calling **Draw::default**.
@@ -187,11 +325,80 @@ impl Draw {
         Self::default()
     }
 
-    /// Resets all state within the `Draw` instance.
+    /// Resets all state within the `Draw` instance, including any baseline marked via
+    /// `checkpoint`.
     pub fn reset(&self) {
         self.state.borrow_mut().reset();
     }
 
+    /// Mark all commands drawn so far as a static baseline.
+    ///
+    /// The baseline is retained by `reset_to_checkpoint`, which `app.draw()` calls in place of
+    /// `reset`, so that only the commands drawn after the checkpoint need be drawn each frame.
+    /// Renderers tessellate the baseline once and reuse the resulting vertex data for as long as
+    /// the baseline and the size of the output remain unchanged. This is useful for sketches with
+    /// a heavy static background and a small animated region.
+    ///
+    /// The baseline is always drawn beneath the commands drawn after it, regardless of their
+    /// `z_order`. Call `reset` to discard the baseline, e.g. when the background should change.
+    ///
+    /// Calling `checkpoint` again extends the baseline with the commands drawn since. Baselines
+    /// containing text are not reused and are instead tessellated each frame, as the location of
+    /// their glyphs within a renderer's glyph cache may change as other text is drawn.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// fn view(app: &App, frame: Frame) {
+    ///     let draw = app.draw();
+    ///     if !draw.has_checkpoint() {
+    ///         draw.background().color(BLACK);
+    ///         for i in 0..10_000 {
+    ///             let x = (i % 100) as f32 * 4.0 - 200.0;
+    ///             let y = (i / 100) as f32 * 4.0 - 200.0;
+    ///             draw.rect().x_y(x, y).w_h(2.0, 2.0).color(GRAY);
+    ///         }
+    ///         draw.checkpoint();
+    ///     }
+    ///     draw.ellipse().x(app.time.sin() * 200.0).w_h(20.0, 20.0).color(RED);
+    ///     draw.to_frame(app, &frame).unwrap();
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn checkpoint(&self) {
+        self.state.borrow_mut().checkpoint();
+    }
+
+    /// Whether or not a baseline has been marked via `checkpoint` since the last `reset`.
+    pub fn has_checkpoint(&self) -> bool {
+        self.state.borrow().checkpoint.is_some()
+    }
+
+    /// Resets all state drawn since the last `checkpoint`, retaining the baseline.
+    ///
+    /// This is the same as `reset` if there is no checkpoint.
+    pub fn reset_to_checkpoint(&self) {
+        self.state.borrow_mut().reset_to_checkpoint();
+    }
+
+    // Specify whether renderers should retain the commands they draw rather than draining them.
+    pub(crate) fn set_retain_commands(&self, retain: bool) {
+        self.state.borrow_mut().retain_commands = retain;
+    }
+
+    // The ID of the current baseline, if any.
+    pub(crate) fn checkpoint_id(&self) -> Option<u64> {
+        self.state.borrow().checkpoint.as_ref().map(|c| c.id)
+    }
+
+    /// The transform that is applied to all drawings made via this **Draw** instance.
+    ///
+    /// This is the accumulation of all transforms applied via methods like `transform`, `xyz`,
+    /// `scale` and `rotate`, and may be used to convert local coordinates to world space. See
+    /// `transform` for producing a new **Draw** instance with an additional transform applied.
+    pub fn transform_matrix(&self) -> Mat4 {
+        self.context.transform
+    }
+
     // Context changes.
 
     /// Produce a new **Draw** instance transformed by the given transform matrix.
@@ -204,6 +411,20 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance in screen space, ignoring all transforms and scissors
+    /// applied to this **Draw**.
+    ///
+    /// The origin is at the centre of the window with one unit per point, as for the **Draw**
+    /// returned by `app.draw()`. This is useful for separating overlays such as FPS counters,
+    /// crosshairs and coordinate readouts from a transformed scene. The blend mode, sampler,
+    /// topology and `z_order` of this **Draw** are retained.
+    pub fn screen(&self) -> Self {
+        let mut context = self.context.clone();
+        context.transform = Mat4::IDENTITY;
+        context.scissor = Scissor::Full;
+        self.context(context)
+    }
+
     /// Translate the position of the origin by the given translation vector.
     pub fn translate(&self, v: Vec3) -> Self {
         self.transform(Mat4::from_translation(v))
@@ -242,6 +463,8 @@ impl Draw {
     }
 
     /// Translate the position of the origin along the z axis.
+    ///
+    /// To place a single drawing at a given depth, see `Drawing::z`.
     pub fn z(&self, z: f32) -> Self {
         self.x_y_z(0.0, 0.0, z)
     }
@@ -381,6 +604,27 @@ impl Draw {
         self.z_radians(radians)
     }
 
+    /// The same as `rotate`, but rotates about the given `pivot` rather than the origin.
+    ///
+    /// The pivot is specified relative to the current origin.
+    pub fn rotate_around(&self, pivot: Vec2, radians: f32) -> Self {
+        self.rotate_around_axis(pivot.extend(0.0), Vec3::Z, radians)
+    }
+
+    /// Rotate by the given number of radians about the given `axis` passing through `point`.
+    ///
+    /// The point is specified relative to the current origin. The axis need not be normalised. If
+    /// the axis has zero length, no rotation is applied.
+    pub fn rotate_around_axis(&self, point: Vec3, axis: Vec3, radians: f32) -> Self {
+        let axis = axis.normalize_or_zero();
+        if axis == Vec3::ZERO {
+            return self.clone();
+        }
+        let rotation = Mat4::from_axis_angle(axis, radians);
+        let transform = Mat4::from_translation(point) * rotation * Mat4::from_translation(-point);
+        self.transform(transform)
+    }
+
     /// Produce a new **Draw** instance that will draw with the given alpha blend descriptor.
     pub fn alpha_blend(&self, blend_descriptor: wgpu::BlendComponent) -> Self {
         let mut context = self.context.clone();
@@ -400,6 +644,34 @@ impl Draw {
         self.color_blend(blend_descriptor)
     }
 
+    /// Short-hand for `color_blend` with the `wgpu::blend::ADDITIVE` preset.
+    ///
+    /// Overlapping drawings brighten towards white, useful for glow and particle effects.
+    pub fn blend_additive(&self) -> Self {
+        self.color_blend(wgpu::blend::ADDITIVE)
+    }
+
+    /// Short-hand for `color_blend` with the `wgpu::blend::SUBTRACTIVE` preset.
+    ///
+    /// Overlapping drawings darken towards black.
+    pub fn blend_subtract(&self) -> Self {
+        self.color_blend(wgpu::blend::SUBTRACTIVE)
+    }
+
+    /// Short-hand for `color_blend` with the `wgpu::blend::MULTIPLY` preset.
+    ///
+    /// Drawings darken the colours beneath them, ignoring their own alpha.
+    pub fn blend_multiply(&self) -> Self {
+        self.color_blend(wgpu::blend::MULTIPLY)
+    }
+
+    /// Short-hand for `color_blend` with the `wgpu::blend::SCREEN` preset.
+    ///
+    /// Drawings lighten the colours beneath them, ignoring their own alpha.
+    pub fn blend_screen(&self) -> Self {
+        self.color_blend(wgpu::blend::SCREEN)
+    }
+
     /// Produce a new **Draw** instance that will be cropped to the given rectangle.
     ///
     /// If the current **Draw** instance already contains a scissor, the result will be the overlap
@@ -461,6 +733,71 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance whose drawings are ordered by the given hint.
+    ///
+    /// By default, primitives are rendered in the order in which they were submitted (painter's
+    /// algorithm) and every **Draw** has a `z_order` of `0`. When the commands are drained, all
+    /// primitives are stably sorted by their `z_order` so that those with a higher value are
+    /// rendered after (and in turn on top of) those with a lower value, regardless of the order in
+    /// which they were drawn. Primitives that share a `z_order` retain their submission order.
+    ///
+    /// Note that this only affects the order in which primitives are rendered. The renderer also
+    /// performs depth testing, so a primitive that is positioned further back along the *z* axis
+    /// (e.g. via `draw.z(z)`) will still be hidden behind a nearer primitive that was rendered
+    /// before it. The `z_order` is most useful for layering 2D drawings that share the same depth.
+    pub fn z_order(&self, z_order: i32) -> Self {
+        let mut context = self.context.clone();
+        context.z_order = z_order;
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance whose drawings are offset along the *z* axis by `layer`.
+    ///
+    /// Unlike `z`, the offset is applied in the output's coordinate space, so it is unaffected by
+    /// any scaling or rotation applied to this **Draw**. As the renderer performs depth testing,
+    /// drawings on a higher layer always appear on top of those on a lower layer regardless of the
+    /// order in which they were drawn, while drawings on the same layer retain their submission
+    /// order.
+    ///
+    /// Note that the depth range of the renderer spans `-max(w, h)..max(w, h)` of the output
+    /// attachment, so layers beyond this range are clipped.
+    pub fn layer(&self, layer: f32) -> Self {
+        let mut context = self.context.clone();
+        context.transform = Mat4::from_translation(vec3(0.0, 0.0, layer)) * context.transform;
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance with depth testing enabled or disabled.
+    ///
+    /// Depth testing is enabled by default, so primitives positioned further back along the *z*
+    /// axis are hidden behind nearer primitives regardless of the order in which they were drawn.
+    ///
+    /// When disabled, primitives are neither tested against nor written to the depth buffer. They
+    /// are always drawn on top of whatever has been rendered before them and never hide the
+    /// primitives rendered after them, regardless of their depth. This is useful for overlays that
+    /// should follow the painter's algorithm strictly.
+    pub fn depth_test(&self, enabled: bool) -> Self {
+        let mut context = self.context.clone();
+        context.depth_test = enabled;
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance with the given tessellation tolerance.
+    ///
+    /// The tolerance is the maximum allowed distance between a curve and the line segments used to
+    /// approximate it, applying to the paths, ellipses and other curved shapes of this **Draw**.
+    /// Lower values produce smoother curves at the cost of more vertices, while higher values
+    /// improve performance when drawing many small curves. Note that the tolerance is in the local
+    /// coordinates of each drawing, so heavily scaled drawings may require a lower tolerance.
+    ///
+    /// Drawings that specify their own tolerance (e.g. via `stroke_tolerance`) keep it, and
+    /// ellipses with a `resolution` are unaffected. By default, lyon's tolerance of `0.1` is used.
+    pub fn tolerance(&self, tolerance: f32) -> Self {
+        let mut context = self.context.clone();
+        context.tolerance = Some(tolerance);
+        self.context(context)
+    }
+
     /// Specify the primitive topology to use within the render pipeline.
     ///
     /// This method is shared between the `line_mode`, `point_mode` and `triangle_mode` methods.
@@ -479,6 +816,10 @@ impl Draw {
     // Primitives.
 
     /// Specify a color with which the background should be cleared.
+    ///
+    /// The color applies to the whole **Draw** state rather than the current context and is used
+    /// to clear the frame it is next rendered to. It is removed by `reset` and restored to its
+    /// value at the checkpoint (if any) by `reset_to_checkpoint`.
     pub fn background(&self) -> Background {
         background::new(self)
     }
@@ -513,11 +854,23 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Circle**.
+    ///
+    /// Unlike an **Ellipse**, a **Circle** may only be sized via its radius or diameter.
+    pub fn circle(&self) -> Drawing<primitive::Circle> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing an **Ellipse**.
     pub fn ellipse(&self) -> Drawing<primitive::Ellipse> {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Grid** of reference lines.
+    pub fn grid(&self) -> Drawing<primitive::Grid> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing a **Line**.
     pub fn line(&self) -> Drawing<primitive::Line> {
         self.a(Default::default())
@@ -548,6 +901,28 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a star **Polygon**. Short-hand for `polygon().star(...)`.
+    pub fn star(
+        &self,
+        points: usize,
+        inner_radius: f32,
+        outer_radius: f32,
+    ) -> Drawing<primitive::Polygon> {
+        self.polygon().star(points, inner_radius, outer_radius)
+    }
+
+    /// Begin drawing a gear **Polygon**. Short-hand for `polygon().gear(...)`.
+    pub fn gear(
+        &self,
+        teeth: usize,
+        inner_radius: f32,
+        outer_radius: f32,
+        tooth_depth: f32,
+    ) -> Drawing<primitive::Polygon> {
+        self.polygon()
+            .gear(teeth, inner_radius, outer_radius, tooth_depth)
+    }
+
     /// Begin drawing a **Mesh**.
     pub fn mesh(&self) -> Drawing<primitive::mesh::Vertexless> {
         self.a(Default::default())
@@ -576,22 +951,416 @@ impl Draw {
         self.a(primitive::Texture::new(view))
     }
 
+    /// Begin drawing a custom primitive.
+    ///
+    /// See the **RenderPrimitive** trait for implementing custom primitives.
+    pub fn custom<T>(&self, primitive: T) -> Drawing<primitive::Custom>
+    where
+        T: 'static + RenderPrimitive + Clone + std::fmt::Debug,
+    {
+        self.a(primitive::Custom::new(primitive))
+    }
+
+    /// Begin drawing a custom primitive produced with access to the **Draw**'s reusable buffers.
+    ///
+    /// The given function may write path events, points or text to the **DrawingContext** and
+    /// return a primitive that refers to them by index range.
+    pub fn custom_with_context<F, T>(&self, f: F) -> Drawing<primitive::Custom>
+    where
+        F: FnOnce(DrawingContext) -> T,
+        T: 'static + RenderPrimitive + Clone + std::fmt::Debug,
+    {
+        let primitive = {
+            let state = self.state.borrow();
+            let mut intermediary_state = state.intermediary_state.borrow_mut();
+            let ctxt = DrawingContext::from_intermediary_state(&mut *intermediary_state);
+            f(ctxt)
+        };
+        self.custom(primitive)
+    }
+
     /// Finish any drawings-in-progress and produce an iterator draining the inner draw commands
     /// and yielding them by value.
+    ///
+    /// If any of the drawings were made with a non-default `z_order`, the commands are yielded in
+    /// `z_order` sorted order.
+    ///
+    /// The commands of any baseline marked via `checkpoint` are yielded first, but are retained.
     pub fn drain_commands(&self) -> impl Iterator<Item = DrawCommand> {
+        let baseline = self.baseline_commands();
+        let dynamic = self.drain_dynamic_commands();
+        baseline.into_iter().chain(dynamic)
+    }
+
+    // The commands making up the baseline in `z_order` sorted order.
+    pub(crate) fn baseline_commands(&self) -> Vec<DrawCommand> {
+        self.finish_remaining_drawings();
+        let state = self.state.borrow();
+        let cmds: Vec<_> = state.draw_commands[..state.baseline_len()]
+            .iter()
+            .filter_map(|opt| opt.clone())
+            .collect();
+        sort_by_z_order(cmds)
+    }
+
+    // Drain the commands drawn since the baseline in `z_order` sorted order.
+    pub(crate) fn drain_dynamic_commands(&self) -> Vec<DrawCommand> {
         self.finish_remaining_drawings();
         let cmds = {
             let mut state = self.state.borrow_mut();
-            let empty = Vec::with_capacity(state.draw_commands.len());
-            std::mem::replace(&mut state.draw_commands, empty)
+            let baseline_len = state.baseline_len();
+            state.truncate_ids(baseline_len);
+            state.draw_commands.split_off(baseline_len)
         };
-        cmds.into_iter().filter_map(|opt| opt)
+        let cmds: Vec<_> = cmds.into_iter().filter_map(|opt| opt).collect();
+        sort_by_z_order(cmds)
+    }
+
+    // The commands drawn since the baseline in `z_order` sorted order for rendering.
+    //
+    // The commands are drained unless they are to be retained, e.g. for `DrawReset::Manual`.
+    pub(crate) fn take_dynamic_commands(&self) -> Vec<DrawCommand> {
+        if !self.state.borrow().retain_commands {
+            return self.drain_dynamic_commands();
+        }
+        self.finish_remaining_drawings();
+        let state = self.state.borrow();
+        let dynamic = &state.draw_commands[state.baseline_len()..];
+        sort_by_z_order(dynamic.iter().filter_map(|opt| opt.clone()).collect())
+    }
+
+    /// Finish any drawings-in-progress and produce a copy of the inner draw commands without
+    /// draining them.
+    ///
+    /// The commands are ordered the same as those yielded by `drain_commands`.
+    pub fn peek_commands(&self) -> Vec<DrawCommand> {
+        self.finish_remaining_drawings();
+        let state = self.state.borrow();
+        let (baseline, dynamic) = state.draw_commands.split_at(state.baseline_len());
+        let sorted = |cmds: &[Option<DrawCommand>]| {
+            sort_by_z_order(cmds.iter().filter_map(|opt| opt.clone()).collect())
+        };
+        let mut cmds = sorted(baseline);
+        cmds.extend(sorted(dynamic));
+        cmds
+    }
+
+    /// Tessellate each primitive drawn so far exactly as it would be for rendering.
+    ///
+    /// Produces one mesh for each primitive command in the order returned by `peek_commands`.
+    /// Each mesh has the transform of the context in which the primitive was drawn applied, along
+    /// with the color of each vertex.
+    pub fn primitive_meshes(&self) -> Vec<Mesh> {
+        let mut tessellator = PrimitiveTessellator::new();
+        let mut ctxt = Context::default();
+        let mut meshes = vec![];
+        for cmd in self.peek_commands() {
+            match cmd {
+                DrawCommand::Context(c) => ctxt = c,
+                DrawCommand::Primitive(prim) => {
+                    let mut mesh = Mesh::default();
+                    let state = self.state.borrow();
+                    tessellator.tessellate(&state, &ctxt, prim, &mut mesh, None);
+                    meshes.push(mesh);
+                }
+            }
+        }
+        meshes
+    }
+
+    /// Flatten the drawings made so far into polylines suitable for a pen plotter.
+    ///
+    /// Stroked paths, polylines, lines and arrows produce their centre line, while polygons,
+    /// ellipses, rects, quads and tris produce their outline regardless of whether they are filled
+    /// or stroked. Filled polygons also produce any hatching specified via `hatch`. Other
+    /// primitives such as text, meshes and textures are ignored. The points are in the coordinate
+    /// space of the output with the transform of each drawing applied, and are clipped to the
+    /// scissor of each drawing.
+    ///
+    /// Colinear points are merged and the polylines are ordered, starting from the origin, so as
+    /// to reduce the distance travelled by the pen between them. Polylines may be reversed to
+    /// this end.
+    pub fn to_plotter_paths(&self) -> Vec<Vec<Vec2>> {
+        let mut tessellator = PrimitiveTessellator::new();
+        let mut ctxt = Context::default();
+        let mut outlines = vec![];
+        let mut mesh = Mesh::default();
+        for cmd in self.peek_commands() {
+            match cmd {
+                DrawCommand::Context(c) => ctxt = c,
+                DrawCommand::Primitive(prim) => {
+                    mesh.clear();
+                    let state = self.state.borrow();
+                    let start = outlines.len();
+                    tessellator.tessellate(&state, &ctxt, prim, &mut mesh, Some(&mut outlines));
+                    match ctxt.scissor {
+                        Scissor::Full => (),
+                        Scissor::Rect(rect) => {
+                            let clipped: Vec<_> = outlines
+                                .drain(start..)
+                                .flat_map(|outline| plotter::clip_to_rect(outline, rect))
+                                .collect();
+                            outlines.extend(clipped);
+                        }
+                        Scissor::NoOverlap => outlines.truncate(start),
+                    }
+                }
+            }
+        }
+        let outlines = outlines.into_iter().map(plotter::merge_colinear).collect();
+        plotter::order_for_travel(outlines)
     }
 
     /// Drain any remaining `drawing`s and convert them to draw commands.
     pub fn finish_remaining_drawings(&self) {
         self.state.borrow_mut().finish_remaining_drawings()
     }
+
+    /// The index of the draw command for the drawing with the given ID.
+    ///
+    /// Returns `None` if no drawing has been given the ID via `Drawing::id` since the last reset.
+    pub fn index_of(&self, id: u64) -> Option<usize> {
+        self.state.borrow().ids.get(&id).cloned()
+    }
+
+    /// The ID given via `Drawing::id` to the drawing with the given draw command index, if any.
+    ///
+    /// This is useful for identifying the logical shape returned by `hit_test` regardless of the
+    /// order in which shapes were drawn:
+    ///
+    /// ```
+    /// # use nannou::prelude::*;
+    /// # let draw = Draw::new();
+    /// draw.rect().w_h(100.0, 100.0).id(42);
+    /// let hit = draw.hit_test(pt2(10.0, 10.0)).and_then(|index| draw.id_of(index));
+    /// assert_eq!(hit, Some(42));
+    /// ```
+    pub fn id_of(&self, index: usize) -> Option<u64> {
+        let state = self.state.borrow();
+        state
+            .ids
+            .iter()
+            .find(|&(_, &i)| i == index)
+            .map(|(&id, _)| id)
+    }
+
+    /// Find the topmost primitive drawn so far that lies under the given point.
+    ///
+    /// Returns the index of the primitive's draw command (as returned by `Drawing::finish`) or
+    /// `None` if the point does not touch any primitive.
+    ///
+    /// Each primitive is tessellated exactly as it would be for rendering, taking into account the
+    /// transform and scissor of the **Draw** with which it was drawn. Stroked shapes are tested
+    /// against their stroked outline. Points within `DEFAULT_HIT_TEST_TOLERANCE` of a shape are
+    /// considered to touch it. See `hit_test_with_tolerance` to specify a different tolerance.
+    ///
+    /// The topmost primitive is the one that the renderer would draw last at the point. Primitives
+    /// with depth testing enabled are hidden by nearer primitives (e.g. those on a higher `layer`)
+    /// regardless of their `z_order` or submission order.
+    ///
+    /// Primitives drawn in `line_mode` or `point_mode` are ignored.
+    pub fn hit_test(&self, point: Vec2) -> Option<usize> {
+        self.hit_test_with_tolerance(point, Self::DEFAULT_HIT_TEST_TOLERANCE)
+    }
+
+    /// The same as `hit_test`, but considers points within the given distance of a shape to touch
+    /// it.
+    pub fn hit_test_with_tolerance(&self, point: Vec2, tolerance: f32) -> Option<usize> {
+        self.finish_remaining_drawings();
+        let state = self.state.borrow();
+
+        // Pair each primitive with its index and the context in which it was drawn.
+        let default_ctxt = Context::default();
+        let mut ctxt = &default_ctxt;
+        let mut prims = vec![];
+        for (index, cmd) in state.draw_commands.iter().enumerate() {
+            match cmd {
+                Some(DrawCommand::Context(c)) => ctxt = c,
+                Some(DrawCommand::Primitive(prim)) => prims.push((index, ctxt, prim)),
+                None => (),
+            }
+        }
+
+        // Primitives are drawn in `z_order` and then submission order above any baseline.
+        let baseline_len = state.baseline_len();
+        prims.sort_by_key(|&(index, ctxt, _)| (index >= baseline_len, ctxt.z_order));
+
+        // Replay the depth test of the renderer at the point. A depth tested primitive is only
+        // drawn if it is no further back than those depth tested primitives drawn before it.
+        let mut tessellator = PrimitiveTessellator::new();
+        let mut mesh = Mesh::default();
+        let mut nearest_z = f32::NEG_INFINITY;
+        let mut topmost = None;
+        for (index, ctxt, prim) in prims {
+            if ctxt.topology != wgpu::PrimitiveTopology::TriangleList {
+                continue;
+            }
+            match ctxt.scissor {
+                Scissor::Full => (),
+                Scissor::Rect(rect) if rect.contains(point) => (),
+                Scissor::Rect(_) | Scissor::NoOverlap => continue,
+            }
+            mesh.clear();
+            tessellator.tessellate(&state, ctxt, prim.clone(), &mut mesh, None);
+            let z = match mesh_z_at(&mesh, point, tolerance) {
+                Some(z) => z,
+                None => continue,
+            };
+            if ctxt.depth_test {
+                if z < nearest_z {
+                    continue;
+                }
+                nearest_z = z;
+            }
+            topmost = Some(index);
+        }
+        topmost
+    }
+}
+
+// Tessellates primitives outside of a renderer, e.g. for hit testing.
+struct PrimitiveTessellator {
+    fill: FillTessellator,
+    stroke: StrokeTessellator,
+    // Empty until a primitive that may render text is tessellated.
+    glyph_cache: renderer::GlyphCache,
+}
+
+impl PrimitiveTessellator {
+    fn new() -> Self {
+        PrimitiveTessellator {
+            fill: FillTessellator::new(),
+            stroke: StrokeTessellator::new(),
+            glyph_cache: glyph_cache([0; 2]),
+        }
+    }
+
+    // Tessellate the given primitive of the given draw state into the mesh.
+    //
+    // If `outlines` is `Some`, the flattened outline of the primitive is also collected.
+    fn tessellate(
+        &mut self,
+        state: &State,
+        ctxt: &Context,
+        prim: Primitive,
+        mesh: &mut Mesh,
+        outlines: Option<&mut Vec<Vec<Vec2>>>,
+    ) {
+        // Only allocate the glyph cache's pixel buffer once it may be required.
+        let may_render_text = matches!(prim, Primitive::Text(_) | Primitive::Custom(_));
+        if may_render_text && self.glyph_cache.pixel_buffer.is_empty() {
+            self.glyph_cache = glyph_cache(Renderer::DEFAULT_GLYPH_CACHE_SIZE);
+        }
+        let intermediary_state = state.intermediary_state.borrow();
+        let render_ctxt = renderer::RenderContext {
+            intermediary_mesh: &intermediary_state.intermediary_mesh,
+            path_event_buffer: &intermediary_state.path_event_buffer,
+            path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+            path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+            path_points_weighted_buffer: &intermediary_state.path_points_weighted_buffer,
+            text_buffer: &intermediary_state.text_buffer,
+            theme: &state.theme,
+            transform: &ctxt.transform,
+            sampler: &ctxt.sampler,
+            tolerance: ctxt.tolerance,
+            outlines,
+            fill_tessellator: &mut self.fill,
+            stroke_tessellator: &mut self.stroke,
+            glyph_cache: &mut self.glyph_cache,
+            output_attachment_size: Vec2::ONE,
+            output_attachment_scale_factor: 1.0,
+        };
+        prim.render_primitive(render_ctxt, mesh);
+    }
+}
+
+// A glyph cache of the given size with the renderer's default tolerances.
+fn glyph_cache(size: [u32; 2]) -> renderer::GlyphCache {
+    renderer::GlyphCache::new(
+        size,
+        Renderer::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
+        Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+    )
+}
+
+// The greatest depth of the mesh's triangles that contain or lie within `tolerance` of the given
+// point, or `None` if the point does not touch the mesh.
+fn mesh_z_at(mesh: &Mesh, point: Vec2, tolerance: f32) -> Option<f32> {
+    let points = mesh.points();
+    let mut nearest_z = None;
+    for ixs in mesh.indices().chunks_exact(3) {
+        let [a, b, c] = [ixs[0], ixs[1], ixs[2]].map(|i| points[i as usize]);
+        let [a2, b2, c2] = [a, b, c].map(|p| p.truncate());
+        // Degenerate triangles would otherwise appear to contain every point.
+        let area = (b2 - a2).perp_dot(c2 - a2);
+        let z = if area != 0.0 && geom::Tri([a2, b2, c2]).contains(&point) {
+            // Interpolate the depth of the triangle at the point.
+            let u = (point - a2).perp_dot(c2 - a2) / area;
+            let v = (b2 - a2).perp_dot(point - a2) / area;
+            a.z + (b.z - a.z) * u + (c.z - a.z) * v
+        } else {
+            let edges = [(a, b), (b, c), (c, a)];
+            let touching = edges.iter().filter_map(|&(start, end)| {
+                let t = closest_on_segment(point, start.truncate(), end.truncate());
+                let closest = start.lerp(end, t);
+                (point.distance(closest.truncate()) <= tolerance).then_some(closest.z)
+            });
+            match touching.reduce(f32::max) {
+                Some(z) => z,
+                None => continue,
+            }
+        };
+        nearest_z = Some(nearest_z.map_or(z, |nearest: f32| nearest.max(z)));
+    }
+    nearest_z
+}
+
+// The position along the line segment `a`-`b` closest to the point `p` as a fraction of its length.
+fn closest_on_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_squared = ab.length_squared();
+    if len_squared > 0.0 {
+        ((p - a).dot(ab) / len_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+// Stably sort the given commands by the `z_order` of the context in which each primitive was drawn.
+//
+// Context commands are re-emitted as necessary so that each primitive is still drawn with the
+// context that was active when it was submitted.
+fn sort_by_z_order(cmds: Vec<DrawCommand>) -> Vec<DrawCommand> {
+    let requires_sort = cmds.iter().any(|cmd| match cmd {
+        DrawCommand::Context(ctxt) => ctxt.z_order != 0,
+        _ => false,
+    });
+    if !requires_sort {
+        return cmds;
+    }
+
+    // Pair each primitive with its context.
+    let mut ctxt = Context::default();
+    let mut prims = Vec::with_capacity(cmds.len());
+    for cmd in cmds {
+        match cmd {
+            DrawCommand::Context(c) => ctxt = c,
+            DrawCommand::Primitive(prim) => prims.push((ctxt.clone(), prim)),
+        }
+    }
+    prims.sort_by_key(|(ctxt, _)| ctxt.z_order);
+
+    // Re-emit context changes between primitives where necessary.
+    let mut last_ctxt = None;
+    let mut sorted = Vec::with_capacity(prims.len());
+    for (ctxt, prim) in prims {
+        if last_ctxt.as_ref() != Some(&ctxt) {
+            sorted.push(DrawCommand::Context(ctxt.clone()));
+            last_ctxt = Some(ctxt);
+        }
+        sorted.push(DrawCommand::Primitive(prim));
+    }
+    sorted
 }
 
 impl Default for IntermediaryState {
@@ -600,12 +1369,14 @@ impl Default for IntermediaryState {
         let path_event_buffer = Default::default();
         let path_points_colored_buffer = Default::default();
         let path_points_textured_buffer = Default::default();
+        let path_points_weighted_buffer = Default::default();
         let text_buffer = Default::default();
         IntermediaryState {
             intermediary_mesh,
             path_event_buffer,
             path_points_colored_buffer,
             path_points_textured_buffer,
+            path_points_weighted_buffer,
             text_buffer,
         }
     }
@@ -617,15 +1388,21 @@ impl Default for State {
         let background_color = Default::default();
         let draw_commands = Default::default();
         let drawing = Default::default();
+        let ids = Default::default();
         let intermediary_state = RefCell::new(Default::default());
         let theme = Default::default();
+        let checkpoint = None;
+        let retain_commands = false;
         State {
             last_draw_context,
             draw_commands,
             drawing,
+            ids,
             intermediary_state,
             theme,
             background_color,
+            checkpoint,
+            retain_commands,
         }
     }
 }
@@ -649,6 +1426,371 @@ impl Default for Context {
             scissor: Scissor::Full,
             topology: wgpu::RenderPipelineBuilder::DEFAULT_PRIMITIVE_TOPOLOGY,
             sampler: wgpu::SamplerBuilder::new().into_descriptor(),
+            z_order: 0,
+            depth_test: true,
+            tolerance: None,
         }
     }
 }
+
+#[test]
+fn test_hit_test() {
+    let draw = Draw::new();
+    let rect = draw.rect().w_h(100.0, 100.0).finish();
+    let ellipse = draw.ellipse().x(25.0).w_h(50.0, 50.0).finish();
+    let line = draw
+        .line()
+        .start(geom::pt2(-200.0, 200.0))
+        .end(geom::pt2(200.0, 200.0))
+        .weight(2.0)
+        .finish();
+    let hidden = draw.z_order(-1).rect().x(-150.0).w_h(100.0, 100.0).finish();
+    let _rotated = draw.rotate(1.0).rect().x(-150.0).w_h(10.0, 10.0).finish();
+
+    assert_eq!(draw.hit_test(geom::pt2(-25.0, 0.0)), Some(rect));
+    assert_eq!(draw.hit_test(geom::pt2(25.0, 0.0)), Some(ellipse));
+    assert_eq!(draw.hit_test(geom::pt2(0.0, 201.5)), Some(line));
+    assert_eq!(draw.hit_test(geom::pt2(0.0, 210.0)), None);
+    assert_eq!(
+        draw.hit_test_with_tolerance(geom::pt2(0.0, 210.0), 10.0),
+        Some(line)
+    );
+    assert_eq!(draw.hit_test(geom::pt2(-150.0, 0.0)), Some(hidden));
+    assert_eq!(draw.hit_test(geom::pt2(0.0, -100.0)), None);
+}
+
+#[test]
+fn test_hit_test_layer() {
+    let draw = Draw::new();
+    let top = draw.layer(1.0).rect().w_h(100.0, 100.0).finish();
+    let bottom = draw.z_order(1).rect().w_h(200.0, 200.0).finish();
+    // Higher layers are hit regardless of `z_order` and submission order.
+    assert_eq!(draw.hit_test(geom::pt2(0.0, 0.0)), Some(top));
+    assert_eq!(draw.hit_test(geom::pt2(75.0, 0.0)), Some(bottom));
+
+    // Primitives without depth testing are drawn over those rendered before them.
+    let overlay = draw
+        .z_order(2)
+        .depth_test(false)
+        .ellipse()
+        .w_h(20.0, 20.0)
+        .finish();
+    assert_eq!(draw.hit_test(geom::pt2(0.0, 0.0)), Some(overlay));
+    let covering = draw.z_order(3).layer(2.0).rect().w_h(10.0, 10.0).finish();
+    assert_eq!(draw.hit_test(geom::pt2(0.0, 0.0)), Some(covering));
+    assert_eq!(draw.hit_test(geom::pt2(8.0, 0.0)), Some(overlay));
+}
+
+#[test]
+fn test_transform_matrix() {
+    let draw = Draw::new();
+    assert_eq!(draw.transform_matrix(), Mat4::IDENTITY);
+    let local = draw.x(10.0).scale(2.0);
+    let p = local
+        .transform_matrix()
+        .transform_point3(Vec3::new(1.0, 1.0, 0.0));
+    assert!(p.abs_diff_eq(Vec3::new(12.0, 2.0, 0.0), 1e-6));
+}
+
+#[test]
+fn test_screen() {
+    let draw = Draw::new();
+    let scene = draw
+        .scale(2.0)
+        .x(100.0)
+        .scissor(geom::Rect::from_w_h(10.0, 10.0));
+    let hud = scene.screen();
+    assert_eq!(hud.transform_matrix(), Mat4::IDENTITY);
+    let rect = hud.rect().x(50.0).w_h(10.0, 10.0).finish();
+    assert_eq!(draw.hit_test(geom::pt2(50.0, 0.0)), Some(rect));
+}
+
+#[test]
+fn test_layer() {
+    let draw = Draw::new().scale(2.0);
+    // The layer offset is unaffected by the scale, unlike `z`.
+    let p = draw
+        .layer(5.0)
+        .transform_matrix()
+        .transform_point3(Vec3::ZERO);
+    assert_eq!(p.z, 5.0);
+    let p = draw.z(5.0).transform_matrix().transform_point3(Vec3::ZERO);
+    assert_eq!(p.z, 10.0);
+}
+
+#[test]
+fn test_peek_commands() {
+    let draw = Draw::new();
+    draw.rect().w_h(10.0, 10.0);
+    draw.x(100.0).ellipse();
+    let count_prims = |cmds: &[DrawCommand]| {
+        cmds.iter()
+            .filter(|cmd| matches!(cmd, DrawCommand::Primitive(_)))
+            .count()
+    };
+    assert_eq!(count_prims(&draw.peek_commands()), 2);
+    // Peeking does not drain the commands.
+    assert_eq!(count_prims(&draw.peek_commands()), 2);
+    let meshes = draw.primitive_meshes();
+    assert_eq!(meshes.len(), 2);
+    // The ellipse mesh has the translation of its context applied.
+    let ellipse_x = meshes[1].points().iter().map(|p| p.x);
+    assert!(ellipse_x.clone().all(|x| x >= 49.0 && x <= 151.0));
+    assert_eq!(count_prims(&draw.drain_commands().collect::<Vec<_>>()), 2);
+    // The glyph cache is created on demand when tessellating text.
+    draw.text("nannou");
+    assert!(!draw.primitive_meshes()[0].indices().is_empty());
+}
+
+#[test]
+fn test_to_plotter_paths() {
+    let draw = Draw::new();
+    draw.rect().x_y(100.0, 0.0).w_h(10.0, 10.0);
+    draw.polyline().points(
+        [(0.0, 0.0), (5.0, 0.0), (10.0, 0.0), (10.0, 10.0)]
+            .iter()
+            .cloned(),
+    );
+    draw.text("ignored");
+    let paths = draw.to_plotter_paths();
+    assert_eq!(paths.len(), 2);
+    // The polyline is nearest the origin, and its colinear point is merged.
+    let polyline = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)].map(|(x, y)| Vec2::new(x, y));
+    assert_eq!(paths[0], polyline);
+    // The rect outline is closed.
+    assert_eq!(paths[1].len(), 5);
+    assert_eq!(paths[1].first(), paths[1].last());
+
+    // Filled polygons are hatched, and drawings are clipped to their scissor.
+    let draw = Draw::new();
+    draw.rect().w_h(10.0, 10.0).hatch(0.0, 2.5);
+    draw.rect().w_h(10.0, 10.0).no_fill().hatch(0.0, 2.5);
+    assert_eq!(draw.to_plotter_paths().len(), 2 + 4);
+    let draw = Draw::new();
+    let scissor = geom::Rect::from_x_y_w_h(0.0, 0.0, 20.0, 20.0);
+    draw.scissor(scissor)
+        .line()
+        .start(geom::pt2(-20.0, 0.0))
+        .end(geom::pt2(0.0, 0.0));
+    draw.scissor(scissor).x(100.0).rect();
+    let paths = draw.to_plotter_paths();
+    assert_eq!(paths, vec![vec![Vec2::ZERO, Vec2::new(-10.0, 0.0)]]);
+}
+
+#[test]
+fn test_tolerance() {
+    // Points just inside the edge of a circle lie outside of its coarsely approximated outline.
+    let misses = |draw: &Draw| {
+        let circle = draw.ellipse().radius(100.0).finish();
+        (0..360)
+            .map(|deg| (deg as f32).to_radians())
+            .map(|rad| Vec2::new(rad.cos(), rad.sin()) * 99.5)
+            .filter(|&p| draw.hit_test_with_tolerance(p, 0.0) != Some(circle))
+            .count()
+    };
+    assert_eq!(misses(&Draw::new()), 0);
+    assert!(misses(&Draw::new().tolerance(10.0)) > 0);
+
+    // A tolerance specified for the drawing takes precedence, even if it is lyon's default.
+    let draw = Draw::new().tolerance(10.0);
+    let stroke_misses = |draw: &Draw, tolerance: Option<f32>| {
+        let ellipse = draw.ellipse().no_fill().stroke_weight(1.0).radius(100.0);
+        let circle = match tolerance {
+            Some(tolerance) => ellipse.stroke_tolerance(tolerance).finish(),
+            None => ellipse.finish(),
+        };
+        let misses = (0..360)
+            .map(|deg| (deg as f32).to_radians())
+            .map(|rad| Vec2::new(rad.cos(), rad.sin()) * 100.0)
+            .filter(|&p| draw.hit_test_with_tolerance(p, 0.0) != Some(circle))
+            .count();
+        draw.reset();
+        misses
+    };
+    assert!(stroke_misses(&draw, None) > 0);
+    assert_eq!(stroke_misses(&draw, Some(0.1)), 0);
+    let (from, ctrl, to) = (
+        Vec2::new(-100.0, 0.0),
+        Vec2::Y * 200.0,
+        Vec2::new(100.0, 0.0),
+    );
+    let path = draw
+        .path()
+        .stroke()
+        .weight(1.0)
+        .tolerance(0.1)
+        .move_to(from)
+        .quadratic_to(ctrl, to)
+        .finish();
+    let on_curve = (1..100).map(|i| i as f32 / 100.0).map(|t| {
+        let s = 1.0 - t;
+        from * s * s + ctrl * 2.0 * s * t + to * t * t
+    });
+    for p in on_curve {
+        assert_eq!(draw.hit_test_with_tolerance(p, 0.0), Some(path));
+    }
+}
+
+#[test]
+fn test_rotate_around() {
+    let draw = Draw::new().x(10.0);
+    let rotated = draw.rotate_around(Vec2::new(5.0, 0.0), std::f32::consts::PI);
+    // A point 5 to the right of the pivot ends up 5 to the left of it, offset by the parent `x`.
+    let p = rotated
+        .context
+        .transform
+        .transform_point3(Vec3::new(10.0, 0.0, 0.0));
+    assert!(p.abs_diff_eq(Vec3::new(10.0, 0.0, 0.0), 1e-4));
+    // The pivot itself is unaffected by the rotation.
+    let p = rotated
+        .context
+        .transform
+        .transform_point3(Vec3::new(5.0, 0.0, 0.0));
+    assert!(p.abs_diff_eq(Vec3::new(15.0, 0.0, 0.0), 1e-4));
+    // A zero-length axis leaves the transform unchanged rather than producing NaNs.
+    let unrotated = draw.rotate_around_axis(Vec3::new(5.0, 0.0, 0.0), Vec3::ZERO, 1.0);
+    assert_eq!(unrotated.transform_matrix(), draw.transform_matrix());
+}
+
+#[test]
+fn test_redundant_blend() {
+    let draw = Draw::new();
+    let count_contexts = |draw: &Draw| {
+        draw.peek_commands()
+            .iter()
+            .filter(|cmd| matches!(cmd, DrawCommand::Context(_)))
+            .count()
+    };
+    draw.rect();
+    // Specifying the blend mode that is already in use does not introduce a context change.
+    let same = draw.color_blend(draw.context.blend.color);
+    same.ellipse();
+    assert_eq!(count_contexts(&draw), 1);
+    draw.blend_additive().ellipse();
+    assert_eq!(count_contexts(&draw), 2);
+}
+
+#[test]
+fn test_checkpoint() {
+    let draw = Draw::new();
+    let count_prims = |cmds: &[DrawCommand]| {
+        cmds.iter()
+            .filter(|cmd| matches!(cmd, DrawCommand::Primitive(_)))
+            .count()
+    };
+    draw.polyline()
+        .points(vec![geom::pt2(0.0, 0.0), geom::pt2(10.0, 0.0)]);
+    draw.checkpoint();
+    let lens = draw.state.borrow().intermediary_state.borrow().lens();
+    // The baseline is drawn beneath later commands regardless of their `z_order`.
+    draw.z_order(-1).x(5.0).rect().w_h(10.0, 10.0);
+    draw.polyline()
+        .points(vec![geom::pt2(0.0, 5.0), geom::pt2(10.0, 5.0)]);
+    assert_eq!(draw.hit_test(geom::pt2(5.0, 0.0)), Some(3));
+    assert_eq!(count_prims(&draw.drain_commands().collect::<Vec<_>>()), 3);
+    // Draining and resetting to the checkpoint retains the baseline and its buffers.
+    assert_eq!(count_prims(&draw.peek_commands()), 1);
+    draw.rect();
+    draw.reset_to_checkpoint();
+    assert_eq!(count_prims(&draw.peek_commands()), 1);
+    let new_lens = draw.state.borrow().intermediary_state.borrow().lens();
+    assert_eq!(new_lens.path_points_colored, lens.path_points_colored);
+    assert!(draw.has_checkpoint());
+    draw.reset();
+    assert!(!draw.has_checkpoint());
+    assert!(draw.peek_commands().is_empty());
+}
+
+#[test]
+fn test_ids() {
+    let draw = Draw::new();
+    // The ID of a shape is unaffected by whether other shapes were drawn before it.
+    for &skip in &[false, true] {
+        draw.reset();
+        if !skip {
+            draw.ellipse().x(-100.0);
+        }
+        draw.rect().x(100.0).w_h(10.0, 10.0).id(7);
+        let index = draw.hit_test(geom::pt2(100.0, 0.0));
+        assert_eq!(index, draw.index_of(7));
+        assert_eq!(index.and_then(|i| draw.id_of(i)), Some(7));
+    }
+    draw.drain_commands().for_each(drop);
+    assert_eq!(draw.index_of(7), None);
+}
+
+#[test]
+fn test_background_per_view() {
+    use crate::color::{IntoLinSrgba, BLACK, RED};
+    let background = |draw: &Draw| draw.state.borrow().background_color;
+    // Emulates the **App**'s shared **Draw** across the `view`s of two windows.
+    let draw = Draw::new();
+    draw.background().color(BLACK);
+    assert!(background(&draw).is_some());
+    // The second window does not clear the background unless it sets its own.
+    draw.reset_to_checkpoint();
+    assert_eq!(background(&draw), None);
+    draw.background().color(RED);
+    assert_eq!(background(&draw), Some(RED.into_lin_srgba()));
+    draw.reset_to_checkpoint();
+    assert_eq!(background(&draw), None);
+    // A background set before the checkpoint applies to all windows.
+    draw.background().color(BLACK);
+    draw.checkpoint();
+    draw.background().color(RED);
+    draw.reset_to_checkpoint();
+    assert_eq!(background(&draw), Some(BLACK.into_lin_srgba()));
+}
+
+#[test]
+fn test_drawing_z() {
+    let draw = Draw::new();
+    draw.rect().z(5.0);
+    draw.z(10.0).rect().z(5.0);
+    let state = draw.state.borrow();
+    let mut tessellator = PrimitiveTessellator::new();
+    let mut ctxt = Context::default();
+    let mut depths = vec![];
+    for cmd in state.draw_commands.iter().flatten() {
+        match cmd {
+            DrawCommand::Context(c) => ctxt = c.clone(),
+            DrawCommand::Primitive(prim) => {
+                let mut mesh = Mesh::default();
+                tessellator.tessellate(&state, &ctxt, prim.clone(), &mut mesh, None);
+                assert!(mesh.points().iter().all(|p| p.z == mesh.points()[0].z));
+                depths.push(mesh.points()[0].z);
+            }
+        }
+    }
+    assert_eq!(depths, vec![5.0, 15.0]);
+}
+
+#[test]
+fn test_retain_commands() {
+    let count_prims = |cmds: Vec<DrawCommand>| {
+        cmds.iter()
+            .filter(|cmd| matches!(cmd, DrawCommand::Primitive(_)))
+            .count()
+    };
+    let lens = |draw: &Draw| draw.state.borrow().intermediary_state.borrow().lens();
+    let points = || vec![geom::pt2(0.0, 0.0), geom::pt2(10.0, 0.0)];
+    // Emulates `DrawReset::Manual` across two frames.
+    let draw = Draw::new();
+    draw.set_retain_commands(true);
+    draw.polyline().points(points());
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 1);
+    let one = lens(&draw);
+    draw.polyline().points(points());
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 2);
+    assert!(lens(&draw).path_events > one.path_events);
+    // Resetting discards the accumulated drawings along with their buffers.
+    draw.reset();
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 0);
+    draw.polyline().points(points());
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 1);
+    assert_eq!(lens(&draw), one);
+    // Otherwise the commands are drained when rendered.
+    draw.set_retain_commands(false);
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 1);
+    assert_eq!(count_prims(draw.take_dynamic_commands()), 0);
+}
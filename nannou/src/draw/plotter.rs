@@ -0,0 +1,242 @@
+//! Items related to producing polylines for pen plotters. See `Draw::to_plotter_paths`.
+
+use crate::geom;
+use crate::glam::Vec2;
+use std::mem;
+
+// The maximum sine of the angle between two segments for them to be considered colinear.
+const COLINEAR_EPSILON: f32 = 1e-4;
+
+// Remove repeated points and points lying on the straight line between their neighbours.
+pub(crate) fn merge_colinear(polyline: Vec<Vec2>) -> Vec<Vec2> {
+    let mut merged: Vec<Vec2> = Vec::with_capacity(polyline.len());
+    for p in polyline {
+        if merged.last() == Some(&p) {
+            continue;
+        }
+        if let [.., a, b] = merged[..] {
+            let (ab, bp) = ((b - a).normalize(), (p - b).normalize());
+            if ab.perp_dot(bp).abs() < COLINEAR_EPSILON && ab.dot(bp) > 0.0 {
+                merged.pop();
+            }
+        }
+        merged.push(p);
+    }
+    merged
+}
+
+// Order the polylines to reduce the distance travelled with the pen up between them.
+//
+// Starting from the origin, the nearest remaining polyline is repeatedly chosen next, reversing it
+// if its end is nearer than its start.
+pub(crate) fn order_for_travel(mut polylines: Vec<Vec<Vec2>>) -> Vec<Vec<Vec2>> {
+    polylines.retain(|polyline| !polyline.is_empty());
+    let mut ordered = Vec::with_capacity(polylines.len());
+    let mut pen = Vec2::ZERO;
+    while !polylines.is_empty() {
+        let mut nearest = (0, false, f32::INFINITY);
+        for (i, polyline) in polylines.iter().enumerate() {
+            let start = pen.distance_squared(polyline[0]);
+            let end = pen.distance_squared(polyline[polyline.len() - 1]);
+            if start < nearest.2 {
+                nearest = (i, false, start);
+            }
+            if end < nearest.2 {
+                nearest = (i, true, end);
+            }
+        }
+        let (index, reverse, _) = nearest;
+        let mut polyline = polylines.swap_remove(index);
+        if reverse {
+            polyline.reverse();
+        }
+        pen = polyline[polyline.len() - 1];
+        ordered.push(polyline);
+    }
+    ordered
+}
+
+// Produce lines `spacing` apart at the given `angle` in radians, filling the region enclosed by the
+// given outlines under the even-odd rule.
+//
+// Lines are offset from the origin by half of the spacing, so that they do not coincide with the
+// edges of shapes aligned to the spacing.
+pub(crate) fn hatch(outlines: &[Vec<Vec2>], angle: f32, spacing: f32) -> Vec<Vec<Vec2>> {
+    let mut lines = vec![];
+    if spacing.is_nan() || spacing <= 0.0 {
+        return lines;
+    }
+    // Rotate the outlines so that the lines are horizontal, and rotate the lines back afterwards.
+    let (sin, cos) = angle.sin_cos();
+    let unrotate = |p: Vec2| Vec2::new(p.x * cos + p.y * sin, p.y * cos - p.x * sin);
+    let rotate = |p: Vec2| Vec2::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos);
+    let outlines: Vec<Vec<Vec2>> = outlines
+        .iter()
+        .map(|outline| outline.iter().cloned().map(unrotate).collect())
+        .collect();
+    let (min_y, max_y) = outlines
+        .iter()
+        .flatten()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+            (min.min(p.y), max.max(p.y))
+        });
+    if min_y > max_y {
+        return lines;
+    }
+    let mut xs = vec![];
+    let first = (min_y / spacing - 0.5).ceil() as i64;
+    let last = (max_y / spacing - 0.5).floor() as i64;
+    for i in first..=last {
+        let y = (i as f32 + 0.5) * spacing;
+        xs.clear();
+        for outline in &outlines {
+            let next = outline.iter().skip(1).chain(outline.first());
+            for (a, b) in outline.iter().zip(next) {
+                if (a.y > y) != (b.y > y) {
+                    xs.push(a.x + (y - a.y) / (b.y - a.y) * (b.x - a.x));
+                }
+            }
+        }
+        xs.sort_by(f32::total_cmp);
+        for pair in xs.chunks_exact(2) {
+            let (a, b) = (Vec2::new(pair[0], y), Vec2::new(pair[1], y));
+            lines.push(vec![rotate(a), rotate(b)]);
+        }
+    }
+    lines
+}
+
+// Clip the polyline to the given rectangle, splitting it wherever it leaves the rectangle.
+pub(crate) fn clip_to_rect(polyline: Vec<Vec2>, rect: geom::Rect) -> Vec<Vec<Vec2>> {
+    if let [p] = polyline[..] {
+        return match rect.contains(p) {
+            true => vec![polyline],
+            false => vec![],
+        };
+    }
+    let mut clipped = vec![];
+    let mut current: Vec<Vec2> = vec![];
+    for (&a, &b) in polyline.iter().zip(polyline.iter().skip(1)) {
+        match clip_segment(a, b, rect) {
+            None => {
+                if !current.is_empty() {
+                    clipped.push(mem::take(&mut current));
+                }
+            }
+            Some((a, b)) => {
+                // Start a new polyline if the segment re-enters the rectangle.
+                if current.last() != Some(&a) {
+                    if !current.is_empty() {
+                        clipped.push(mem::take(&mut current));
+                    }
+                    current.push(a);
+                }
+                current.push(b);
+            }
+        }
+    }
+    if !current.is_empty() {
+        clipped.push(current);
+    }
+    clipped
+}
+
+// Clip the segment to the given rectangle via the Liang-Barsky algorithm.
+fn clip_segment(a: Vec2, b: Vec2, rect: geom::Rect) -> Option<(Vec2, Vec2)> {
+    let d = b - a;
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+    let edges = [
+        (-d.x, a.x - rect.left()),
+        (d.x, rect.right() - a.x),
+        (-d.y, a.y - rect.bottom()),
+        (d.y, rect.top() - a.y),
+    ];
+    for &(p, q) in &edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    // Retain the exact end points where unclipped so that consecutive segments remain joined.
+    let start = if t0 == 0.0 { a } else { a + d * t0 };
+    let end = if t1 == 1.0 { b } else { a + d * t1 };
+    Some((start, end))
+}
+
+#[test]
+fn test_merge_colinear() {
+    let v = Vec2::new;
+    let line = vec![
+        v(0.0, 0.0),
+        v(1.0, 0.0),
+        v(1.0, 0.0),
+        v(2.0, 0.0),
+        v(2.0, 1.0),
+    ];
+    assert_eq!(
+        merge_colinear(line),
+        vec![v(0.0, 0.0), v(2.0, 0.0), v(2.0, 1.0)]
+    );
+    // Doubling back is not colinear continuation.
+    let line = vec![v(0.0, 0.0), v(2.0, 0.0), v(1.0, 0.0)];
+    assert_eq!(merge_colinear(line.clone()), line);
+}
+
+#[test]
+fn test_order_for_travel() {
+    let v = Vec2::new;
+    let far = vec![v(10.0, 0.0), v(20.0, 0.0)];
+    let near = vec![v(5.0, 0.0), v(1.0, 0.0)];
+    let ordered = order_for_travel(vec![far.clone(), near]);
+    assert_eq!(ordered[0], vec![v(1.0, 0.0), v(5.0, 0.0)]);
+    assert_eq!(ordered[1], far);
+}
+
+#[test]
+fn test_hatch() {
+    let v = Vec2::new;
+    let square = vec![v(0.0, 0.0), v(10.0, 0.0), v(10.0, 10.0), v(0.0, 10.0)];
+    let hole = vec![v(4.0, 4.0), v(6.0, 4.0), v(6.0, 6.0), v(4.0, 6.0)];
+    let lines = hatch(&[square.clone()], 0.0, 2.5);
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], vec![v(0.0, 1.25), v(10.0, 1.25)]);
+    // Lines are split around holes.
+    let lines = hatch(&[square.clone(), hole], 0.0, 10.0);
+    assert_eq!(
+        lines,
+        vec![
+            vec![v(0.0, 5.0), v(4.0, 5.0)],
+            vec![v(6.0, 5.0), v(10.0, 5.0)]
+        ]
+    );
+    // Vertical lines.
+    let lines = hatch(&[square], std::f32::consts::FRAC_PI_2, 10.0);
+    assert_eq!(lines.len(), 1);
+    assert!((lines[0][0] - v(5.0, 0.0)).length() < 1e-4);
+    assert!((lines[0][1] - v(5.0, 10.0)).length() < 1e-4);
+}
+
+#[test]
+fn test_clip_to_rect() {
+    let v = Vec2::new;
+    let rect = geom::Rect::from_x_y_w_h(0.0, 0.0, 10.0, 10.0);
+    // A polyline leaving and re-entering the rect is split in two.
+    let polyline = vec![v(0.0, 0.0), v(10.0, 0.0), v(10.0, 2.0), v(0.0, 2.0)];
+    let clipped = clip_to_rect(polyline, rect);
+    assert_eq!(
+        clipped,
+        vec![
+            vec![v(0.0, 0.0), v(5.0, 0.0)],
+            vec![v(5.0, 2.0), v(0.0, 2.0)],
+        ]
+    );
+    assert!(clip_to_rect(vec![v(20.0, 0.0), v(30.0, 0.0)], rect).is_empty());
+}
@@ -9,6 +9,9 @@ use crate::geom::{pt2, Point2};
 use crate::glam::vec2;
 use lyon::tessellation::StrokeOptions;
 
+// How far along the head (from base to tip) the notch of a `Barbed` head sits.
+const BARB_NOTCH: f32 = 0.35;
+
 /// A path containing only two points - a start and end.
 ///
 /// A triangle is drawn on the end to indicate direction.
@@ -17,6 +20,22 @@ pub struct Arrow {
     line: Line,
     head_length: Option<f32>,
     head_width: Option<f32>,
+    head_style: ArrowHead,
+    angle: Option<f32>,
+    length: Option<f32>,
+}
+
+/// The shape used to draw the head of an **Arrow**.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ArrowHead {
+    /// A solid, filled triangle. This is the default.
+    Triangle,
+    /// A filled triangle whose base is notched back towards the tip.
+    Barbed,
+    /// Two strokes meeting at the tip, leaving the head unfilled.
+    ///
+    /// The strokes use the same weight and stroke options as the line.
+    Open,
 }
 
 /// The drawing context for a line.
@@ -39,13 +58,47 @@ impl Arrow {
     }
 
     /// Specify the end point of the arrow.
-    pub fn end(self, end: Point2) -> Self {
+    ///
+    /// This overrides any previously specified `angle` or `length`.
+    pub fn end(mut self, end: Point2) -> Self {
+        self.angle = None;
+        self.length = None;
         self.map_line(|l| l.end(end))
     }
 
     /// Specify the start and end points of the arrow.
+    ///
+    /// This overrides any previously specified `angle` or `length`.
     pub fn points(self, start: Point2, end: Point2) -> Self {
-        self.map_line(|l| l.points(start, end))
+        self.start(start).end(end)
+    }
+
+    /// Specify the point from which the arrow starts.
+    ///
+    /// The same as `start`. Useful along with `angle` and `length`, e.g. for vector fields.
+    pub fn origin(self, origin: Point2) -> Self {
+        self.start(origin)
+    }
+
+    /// Specify the direction in which the arrow points from its `origin` as an angle in radians.
+    ///
+    /// An angle of `0.0` points along the positive *x* axis, increasing counter-clockwise. The end
+    /// point is calculated from the `origin`, `angle` and `length`, overriding any previously
+    /// specified `end`. If no `length` is specified, the length between any previously specified
+    /// points is used, or `0.0` if no `end` was specified, in which case nothing is drawn.
+    pub fn angle(mut self, radians: f32) -> Self {
+        self.angle = Some(radians);
+        self
+    }
+
+    /// Specify the length of the arrow from its `origin` to the tip of its head.
+    ///
+    /// The end point is calculated from the `origin`, `angle` and `length`, overriding any
+    /// previously specified `end`. If no `angle` is specified, the direction between any previously
+    /// specified points is used, or `0.0` if no `end` was specified.
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
     }
 
     /// The length of the arrow head.
@@ -66,6 +119,14 @@ impl Arrow {
         self
     }
 
+    /// The shape used to draw the arrow head.
+    ///
+    /// By default, this is `ArrowHead::Triangle`.
+    pub fn head_style(mut self, style: ArrowHead) -> Self {
+        self.head_style = style;
+        self
+    }
+
     // Map the inner `PathStroke<S>` using the given function.
     fn map_line<F>(self, map: F) -> Self
     where
@@ -75,12 +136,18 @@ impl Arrow {
             line,
             head_length,
             head_width,
+            head_style,
+            angle,
+            length,
         } = self;
         let line = map(line);
         Arrow {
             line,
             head_length,
             head_width,
+            head_style,
+            angle,
+            length,
         }
     }
 }
@@ -102,15 +169,45 @@ impl<'a> DrawingArrow<'a> {
     }
 
     /// Specify the end point of the arrow.
+    ///
+    /// This overrides any previously specified `angle` or `length`.
     pub fn end(self, end: Point2) -> Self {
         self.map_ty(|ty| ty.end(end))
     }
 
     /// Specify the start and end points of the arrow.
+    ///
+    /// This overrides any previously specified `angle` or `length`.
     pub fn points(self, start: Point2, end: Point2) -> Self {
         self.map_ty(|ty| ty.points(start, end))
     }
 
+    /// Specify the point from which the arrow starts.
+    ///
+    /// The same as `start`. Useful along with `angle` and `length`, e.g. for vector fields.
+    pub fn origin(self, origin: Point2) -> Self {
+        self.map_ty(|ty| ty.origin(origin))
+    }
+
+    /// Specify the direction in which the arrow points from its `origin` as an angle in radians.
+    ///
+    /// An angle of `0.0` points along the positive *x* axis, increasing counter-clockwise. The end
+    /// point is calculated from the `origin`, `angle` and `length`, overriding any previously
+    /// specified `end`. If no `length` is specified, the length between any previously specified
+    /// points is used, or `0.0` if no `end` was specified, in which case nothing is drawn.
+    pub fn angle(self, radians: f32) -> Self {
+        self.map_ty(|ty| ty.angle(radians))
+    }
+
+    /// Specify the length of the arrow from its `origin` to the tip of its head.
+    ///
+    /// The end point is calculated from the `origin`, `angle` and `length`, overriding any
+    /// previously specified `end`. If no `angle` is specified, the direction between any previously
+    /// specified points is used, or `0.0` if no `end` was specified.
+    pub fn length(self, length: f32) -> Self {
+        self.map_ty(|ty| ty.length(length))
+    }
+
     /// The length of the arrow head.
     ///
     /// By default, this is equal to `weight * 4.0`.
@@ -126,12 +223,23 @@ impl<'a> DrawingArrow<'a> {
     pub fn head_width(self, width: f32) -> Self {
         self.map_ty(|ty| ty.head_width(width))
     }
+
+    /// The shape used to draw the arrow head.
+    ///
+    /// By default, this is `ArrowHead::Triangle`.
+    pub fn head_style(self, style: ArrowHead) -> Self {
+        self.map_ty(|ty| ty.head_style(style))
+    }
 }
 
 impl SetStroke for Arrow {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.line)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.line)
+    }
 }
 
 impl SetOrientation for Arrow {
@@ -177,9 +285,19 @@ impl draw::renderer::RenderPrimitive for Arrow {
             line,
             head_length,
             head_width,
+            head_style,
+            angle,
+            length,
         } = self;
         let start = line.start.unwrap_or(pt2(0.0, 0.0));
-        let end = line.end.unwrap_or(pt2(0.0, 0.0));
+        let mut end = line.end.unwrap_or(pt2(0.0, 0.0));
+        if angle.is_some() || length.is_some() {
+            // Only fall back to the direction and length of the line if an `end` was specified.
+            let v = line.end.map(|end| end - start);
+            let angle = angle.or_else(|| v.map(|v| v.y.atan2(v.x))).unwrap_or(0.0);
+            let length = length.or_else(|| v.map(|v| v.length())).unwrap_or(0.0);
+            end = start + vec2(angle.cos(), angle.sin()) * length;
+        }
         if start == end {
             return draw::renderer::PrimitiveRender::default();
         }
@@ -195,30 +313,55 @@ impl draw::renderer::RenderPrimitive for Arrow {
         let tri_dir_norm = line_dir.normalize() * tri_len;
         let tri_start = end - tri_dir_norm;
         let tri_end = end;
-        let line_start = start;
-        let line_end = tri_start;
         let tri_a = tri_end;
         let tri_w_dir = vec2(-tri_dir_norm.y, tri_dir_norm.x).normalize() * head_width;
         let tri_b = tri_start + tri_w_dir;
         let tri_c = tri_start - tri_w_dir;
-        // The line should only be drawn if there is space after drawing the triangle.
-        let draw_line = line_dir_len > tri_len;
+        // The point at which the line meets the head. For barbed heads this is the notch, for
+        // open heads the line runs all the way to the tip.
+        let notch = tri_start + tri_dir_norm * BARB_NOTCH;
+        let line_start = start;
+        let line_end = match head_style {
+            ArrowHead::Triangle => tri_start,
+            ArrowHead::Barbed => notch,
+            ArrowHead::Open => tri_end,
+        };
+        // The line should only be drawn if there is space after drawing the head.
+        let draw_line = match head_style {
+            ArrowHead::Open => true,
+            _ => line_dir_len > tri_len,
+        };
 
         // Determine the transform to apply to all points.
         let global_transform = *ctxt.transform;
         let local_transform = line.path.position.transform() * line.path.orientation.transform();
         let transform = global_transform * local_transform;
 
-        // Draw the tri.
-        let tri_points = [tri_a, tri_b, tri_c];
-        let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
-        let close_tri = true;
-        let tri_events = lyon::path::iterator::FromPolyline::new(close_tri, tri_points);
+        // Draw the head.
+        let fill = path::Options::Fill(Default::default());
+        let (head_points, close_head, head_opts) = match head_style {
+            ArrowHead::Triangle => (&[tri_a, tri_b, tri_c][..], true, fill),
+            ArrowHead::Barbed => (&[tri_a, tri_b, notch, tri_c][..], true, fill),
+            ArrowHead::Open => (
+                &[tri_b, tri_a, tri_c][..],
+                false,
+                path::Options::Stroke(line.path.opts),
+            ),
+        };
+        let head_events = || {
+            let head_points = head_points.iter().cloned().map(|p| p.to_array().into());
+            lyon::path::iterator::FromPolyline::new(close_head, head_points)
+        };
+        let tolerance = path::outline_tolerance(ctxt.tolerance);
+        let line_tolerance = line.path.tolerance.or(ctxt.tolerance);
+        if let Some(outlines) = ctxt.outlines.as_mut() {
+            path::flatten_path_events(head_events(), transform, tolerance, outlines);
+        }
         path::render_path_events(
-            tri_events,
+            head_events(),
             line.path.color,
             transform,
-            path::Options::Fill(Default::default()),
+            head_opts.with_tolerance(line_tolerance),
             &ctxt.theme,
             &draw::theme::Primitive::Arrow,
             &mut ctxt.fill_tessellator,
@@ -229,14 +372,19 @@ impl draw::renderer::RenderPrimitive for Arrow {
         // Draw the line.
         if draw_line {
             let line_points = [line_start, line_end];
-            let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
             let close_line = false;
-            let line_events = lyon::path::iterator::FromPolyline::new(close_line, line_points);
+            let line_events = || {
+                let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
+                lyon::path::iterator::FromPolyline::new(close_line, line_points)
+            };
+            if let Some(outlines) = ctxt.outlines.as_mut() {
+                path::flatten_path_events(line_events(), transform, tolerance, outlines);
+            }
             path::render_path_events(
-                line_events,
+                line_events(),
                 line.path.color,
                 transform,
-                path::Options::Stroke(line.path.opts),
+                path::Options::Stroke(line.path.opts).with_tolerance(line_tolerance),
                 &ctxt.theme,
                 &draw::theme::Primitive::Arrow,
                 &mut ctxt.fill_tessellator,
@@ -249,15 +397,146 @@ impl draw::renderer::RenderPrimitive for Arrow {
     }
 }
 
+impl Default for ArrowHead {
+    fn default() -> Self {
+        ArrowHead::Triangle
+    }
+}
+
 impl Default for Arrow {
     fn default() -> Self {
         let line = Default::default();
         let head_length = Default::default();
         let head_width = Default::default();
+        let head_style = Default::default();
+        let angle = Default::default();
+        let length = Default::default();
         Arrow {
             line,
             head_length,
             head_width,
+            head_style,
+            angle,
+            length,
         }
     }
 }
+
+#[test]
+fn test_arrow_angle_length() {
+    let draw = draw::Draw::new();
+    let angle = std::f32::consts::FRAC_PI_2;
+    let arrow = draw
+        .arrow()
+        .origin(pt2(10.0, 0.0))
+        .angle(angle)
+        .length(20.0)
+        .finish();
+    assert_eq!(draw.hit_test(pt2(10.0, 18.0)), Some(arrow));
+    assert_eq!(draw.hit_test(pt2(30.0, 0.0)), None);
+
+    // The last setter wins.
+    draw.reset();
+    let arrow = draw
+        .arrow()
+        .angle(angle)
+        .length(20.0)
+        .points(pt2(0.0, 0.0), pt2(20.0, 0.0))
+        .finish();
+    assert_eq!(draw.hit_test(pt2(18.0, 0.0)), Some(arrow));
+    assert_eq!(draw.hit_test(pt2(0.0, 18.0)), None);
+
+    // Without a length or end, nothing is drawn.
+    draw.reset();
+    draw.arrow().origin(pt2(10.0, 10.0)).angle(angle);
+    assert_eq!(draw.hit_test(pt2(5.0, 5.0)), None);
+    assert_eq!(draw.hit_test(pt2(10.0, 10.0)), None);
+
+    // Without an angle or end, the arrow points along the positive x axis.
+    let arrow = draw.arrow().origin(pt2(10.0, 10.0)).length(20.0).finish();
+    assert_eq!(draw.hit_test(pt2(28.0, 10.0)), Some(arrow));
+    assert_eq!(draw.hit_test(pt2(0.0, 0.0)), None);
+}
+
+#[test]
+fn test_arrow_head_default() {
+    assert_eq!(ArrowHead::default(), ArrowHead::Triangle);
+    let mesh = |style: Option<ArrowHead>| {
+        let draw = draw::Draw::new();
+        let arrow = draw
+            .arrow()
+            .weight(2.0)
+            .points(pt2(0.0, 0.0), pt2(100.0, 0.0));
+        if let Some(style) = style {
+            arrow.head_style(style);
+        }
+        draw.primitive_meshes().remove(0)
+    };
+    let default = mesh(None);
+    let triangle = mesh(Some(ArrowHead::Triangle));
+    assert_eq!(default.points(), triangle.points());
+    assert_eq!(default.indices(), triangle.indices());
+
+    // A filled triangle of twice the weight in width and four times in length, followed by the
+    // line up to the base of the head.
+    let mut points: Vec<_> = default.points().iter().map(|p| (p.x, p.y)).collect();
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let expected = [
+        (0.0, -1.0),
+        (0.0, 1.0),
+        (92.0, -4.0),
+        (92.0, -1.0),
+        (92.0, 1.0),
+        (92.0, 4.0),
+        (100.0, 0.0),
+    ];
+    assert_eq!(points, expected);
+    assert_eq!(default.indices().len(), 9);
+}
+
+#[test]
+fn test_arrow_head_styles() {
+    let arrow = |style: ArrowHead| {
+        let draw = draw::Draw::new();
+        draw.arrow()
+            .weight(2.0)
+            .head_length(40.0)
+            .head_width(20.0)
+            .head_style(style)
+            .points(pt2(0.0, 0.0), pt2(100.0, 0.0));
+        draw
+    };
+    let hit = |style: ArrowHead, point: Point2| arrow(style).hit_test(point).is_some();
+
+    // Inside the triangle, but behind the notch of the barbed head.
+    let behind_notch = pt2(62.0, 8.0);
+    // Inside the barbed head, away from the strokes of the open head.
+    let inside = pt2(75.0, 8.0);
+    // On the edge from a barb to the tip.
+    let edge = pt2(80.0, 10.0);
+
+    assert!(hit(ArrowHead::Triangle, behind_notch));
+    assert!(hit(ArrowHead::Triangle, inside));
+    assert!(hit(ArrowHead::Triangle, edge));
+
+    assert!(!hit(ArrowHead::Barbed, behind_notch));
+    assert!(hit(ArrowHead::Barbed, inside));
+    assert!(hit(ArrowHead::Barbed, edge));
+
+    assert!(!hit(ArrowHead::Open, behind_notch));
+    assert!(!hit(ArrowHead::Open, inside));
+    assert!(hit(ArrowHead::Open, edge));
+
+    // The line meets the barbed head at its notch and runs to the tip of the open head.
+    let line_end = |style: ArrowHead| {
+        let mesh = arrow(style).primitive_meshes().remove(0);
+        mesh.points()
+            .iter()
+            .filter(|p| p.y.abs() == 1.0)
+            .map(|p| p.x)
+            .fold(0.0, f32::max)
+    };
+    assert_eq!(line_end(ArrowHead::Triangle), 60.0);
+    assert_eq!(line_end(ArrowHead::Barbed), 74.0);
+    assert_eq!(line_end(ArrowHead::Open), 100.0);
+}
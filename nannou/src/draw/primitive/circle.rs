@@ -0,0 +1,166 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw;
+use crate::draw::primitive::polygon::{PolygonOptions, SetPolygon};
+use crate::draw::primitive::{Ellipse, Primitive};
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::Drawing;
+use lyon::tessellation::StrokeOptions;
+
+/// Properties related to drawing a **Circle**.
+///
+/// Unlike an **Ellipse**, the size of a **Circle** may only be specified via its radius or
+/// diameter, guaranteeing that it remains round. Circles are tessellated and themed as ellipses.
+#[derive(Clone, Debug)]
+pub struct Circle {
+    ellipse: Ellipse,
+}
+
+/// The drawing context for a circle.
+pub type DrawingCircle<'a> = Drawing<'a, Circle>;
+
+// Circle-specific methods.
+
+impl Circle {
+    /// The radius used if none is specified.
+    pub const DEFAULT_RADIUS: f32 = 50.0;
+
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.stroke_color(color)
+    }
+
+    /// Specify the radius of the **Circle**.
+    pub fn radius(self, radius: f32) -> Self {
+        let ellipse = self.ellipse.radius(radius);
+        Circle { ellipse }
+    }
+
+    /// Specify the diameter of the **Circle**.
+    pub fn diameter(self, diameter: f32) -> Self {
+        self.radius(diameter * 0.5)
+    }
+
+    /// The number of sides used to draw the circle.
+    ///
+    /// By default, circle does not use a resolution, but rather uses a stroke tolerance to
+    /// determine how many vertices to use during tessellation.
+    pub fn resolution(self, resolution: f32) -> Self {
+        let ellipse = self.ellipse.resolution(resolution);
+        Circle { ellipse }
+    }
+}
+
+// Trait implementations.
+
+impl Default for Circle {
+    fn default() -> Self {
+        let ellipse = Ellipse::default().radius(Self::DEFAULT_RADIUS);
+        Circle { ellipse }
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Circle {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        self.ellipse.render_primitive(ctxt, mesh)
+    }
+}
+
+impl SetOrientation for Circle {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.ellipse)
+    }
+}
+
+impl SetPosition for Circle {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.ellipse)
+    }
+}
+
+impl SetColor<ColorScalar> for Circle {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.ellipse)
+    }
+}
+
+impl SetStroke for Circle {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.ellipse)
+    }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.ellipse)
+    }
+}
+
+impl SetPolygon for Circle {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.ellipse)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Circle> for Primitive {
+    fn from(prim: Circle) -> Self {
+        Primitive::Circle(prim)
+    }
+}
+
+impl Into<Option<Circle>> for Primitive {
+    fn into(self) -> Option<Circle> {
+        match self {
+            Primitive::Circle(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingCircle<'a> {
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.stroke(color))
+    }
+
+    /// Specify the radius of the **Circle**.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// Specify the diameter of the **Circle**.
+    pub fn diameter(self, diameter: f32) -> Self {
+        self.map_ty(|ty| ty.diameter(diameter))
+    }
+
+    /// The number of sides used to draw the circle.
+    pub fn resolution(self, resolution: f32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+#[test]
+fn test_circle() {
+    use crate::geom::pt2;
+    let draw = draw::Draw::new();
+    let circle = draw.circle().x(100.0).radius(10.0).finish();
+    assert_eq!(draw.hit_test(pt2(109.0, 0.0)), Some(circle));
+    assert_eq!(draw.hit_test(pt2(112.0, 0.0)), None);
+    let circle = draw.circle().diameter(40.0).finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 19.0)), Some(circle));
+    assert_eq!(draw.hit_test(pt2(0.0, 22.0)), None);
+}
@@ -0,0 +1,136 @@
+use crate::draw::primitive::Primitive;
+use crate::draw::renderer::{PrimitiveRender, RenderContext, RenderPrimitive};
+use crate::draw::{self, Drawing};
+use std::fmt;
+
+/// A primitive type defined outside of nannou.
+///
+/// Any type implementing **RenderPrimitive** along with `Clone` and `Debug` may be drawn via
+/// `draw.custom(primitive)`. Types that build up path events, points or text while being drawn
+/// may instead use `draw.custom_with_context` to write them into the **Draw**'s reusable buffers
+/// and read them back from the **RenderContext** while rendering, avoiding allocating a new
+/// buffer for every primitive.
+pub struct Custom {
+    primitive: Box<dyn DynRenderPrimitive>,
+}
+
+/// The drawing context for a custom primitive.
+pub type DrawingCustom<'a> = Drawing<'a, Custom>;
+
+// An object-safe form of **RenderPrimitive** for boxed custom primitives.
+trait DynRenderPrimitive: fmt::Debug {
+    fn clone_box(&self) -> Box<dyn DynRenderPrimitive>;
+    fn render_box(self: Box<Self>, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender;
+}
+
+impl Custom {
+    /// Wrap the given primitive.
+    pub fn new<T>(primitive: T) -> Self
+    where
+        T: 'static + RenderPrimitive + Clone + fmt::Debug,
+    {
+        let primitive = Box::new(primitive);
+        Custom { primitive }
+    }
+}
+
+impl<T> DynRenderPrimitive for T
+where
+    T: 'static + RenderPrimitive + Clone + fmt::Debug,
+{
+    fn clone_box(&self) -> Box<dyn DynRenderPrimitive> {
+        Box::new(self.clone())
+    }
+
+    fn render_box(self: Box<Self>, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
+        (*self).render_primitive(ctxt, mesh)
+    }
+}
+
+impl Clone for Custom {
+    fn clone(&self) -> Self {
+        let primitive = self.primitive.clone_box();
+        Custom { primitive }
+    }
+}
+
+impl fmt::Debug for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Custom").field(&self.primitive).finish()
+    }
+}
+
+impl RenderPrimitive for Custom {
+    fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
+        self.primitive.render_box(ctxt, mesh)
+    }
+}
+
+impl From<Custom> for Primitive {
+    fn from(prim: Custom) -> Self {
+        Primitive::Custom(prim)
+    }
+}
+
+impl Into<Option<Custom>> for Primitive {
+    fn into(self) -> Option<Custom> {
+        match self {
+            Primitive::Custom(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_custom() {
+    use crate::geom::pt2;
+
+    // A unit square drawn via the reusable path event buffer.
+    #[derive(Clone, Debug)]
+    struct Square {
+        events: std::ops::Range<usize>,
+    }
+
+    impl RenderPrimitive for Square {
+        fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
+            let events = ctxt.path_event_buffer[self.events].iter().cloned();
+            let mut geometry: lyon::tessellation::VertexBuffers<_, u32> =
+                lyon::tessellation::VertexBuffers::new();
+            let opts = lyon::tessellation::FillOptions::default();
+            let mut builder = lyon::tessellation::BuffersBuilder::new(
+                &mut geometry,
+                |v: lyon::tessellation::FillVertex| v.position(),
+            );
+            ctxt.fill_tessellator
+                .tessellate(events, &opts, &mut builder)
+                .unwrap();
+            let start = mesh.points().len() as u32;
+            for p in geometry.vertices {
+                let point = ctxt.transform.transform_point3([p.x, p.y, 0.0].into());
+                let color = crate::color::lin_srgba(1.0, 1.0, 1.0, 1.0);
+                let tex_coords = draw::mesh::vertex::default_tex_coords();
+                mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+            }
+            for i in geometry.indices {
+                mesh.push_index(start + i);
+            }
+            PrimitiveRender::default()
+        }
+    }
+
+    let draw = draw::Draw::new();
+    let square = draw
+        .x(10.0)
+        .custom_with_context(|ctxt| {
+            let start = ctxt.path_event_buffer.len();
+            let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+            let points = points.iter().map(|&(x, y)| lyon::math::point(x, y));
+            ctxt.path_event_buffer
+                .extend(lyon::path::iterator::FromPolyline::closed(points));
+            let end = ctxt.path_event_buffer.len();
+            Square { events: start..end }
+        })
+        .finish();
+    assert_eq!(draw.hit_test(pt2(10.5, 0.5)), Some(square));
+    assert_eq!(draw.hit_test(pt2(5.0, 0.5)), None);
+}
@@ -140,6 +140,10 @@ impl SetStroke for Ellipse {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.polygon)
+    }
 }
 
 impl SetPolygon for Ellipse {
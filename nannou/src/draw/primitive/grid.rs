@@ -0,0 +1,199 @@
+use crate::color::LinSrgba;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::{ColorScalar, SetColor};
+use crate::draw::{self, Drawing};
+use crate::geom::{self, pt2, Point2};
+use crate::glam::Mat4;
+
+/// A grid of evenly spaced horizontal and vertical lines, useful as a reference for layouts.
+///
+/// Lines are placed at multiples of the spacing so that the grid always aligns with the origin,
+/// regardless of the extent. All lines are tessellated into a single primitive.
+#[derive(Clone, Debug)]
+pub struct Grid {
+    extent: geom::Rect,
+    spacing: f32,
+    weight: f32,
+    axes: bool,
+    color: Option<LinSrgba>,
+}
+
+/// The drawing context for a grid.
+pub type DrawingGrid<'a> = Drawing<'a, Grid>;
+
+impl Grid {
+    /// The default distance between lines.
+    pub const DEFAULT_SPACING: f32 = 10.0;
+    /// The default width and height of the area covered by the grid.
+    pub const DEFAULT_EXTENT_W_H: f32 = 100.0;
+    /// The factor by which the weight of the *x* and *y* axes is multiplied when emphasised.
+    pub const AXES_WEIGHT_SCALE: f32 = 3.0;
+
+    /// The distance between each line of the grid.
+    ///
+    /// Non-positive values produce no grid lines.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// The area covered by the grid, e.g. `app.window_rect()`.
+    pub fn extent(mut self, extent: geom::Rect) -> Self {
+        self.extent = extent;
+        self
+    }
+
+    /// The thickness of each line.
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Whether or not to emphasise the *x* and *y* axes with thicker lines.
+    pub fn axes(mut self, axes: bool) -> Self {
+        self.axes = axes;
+        self
+    }
+}
+
+impl<'a> DrawingGrid<'a> {
+    /// The distance between each line of the grid.
+    pub fn spacing(self, spacing: f32) -> Self {
+        self.map_ty(|ty| ty.spacing(spacing))
+    }
+
+    /// The area covered by the grid, e.g. `app.window_rect()`.
+    pub fn extent(self, extent: geom::Rect) -> Self {
+        self.map_ty(|ty| ty.extent(extent))
+    }
+
+    /// The thickness of each line.
+    pub fn weight(self, weight: f32) -> Self {
+        self.map_ty(|ty| ty.weight(weight))
+    }
+
+    /// Whether or not to emphasise the *x* and *y* axes with thicker lines.
+    pub fn axes(self, axes: bool) -> Self {
+        self.map_ty(|ty| ty.axes(axes))
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        let extent = geom::Rect::from_w_h(Self::DEFAULT_EXTENT_W_H, Self::DEFAULT_EXTENT_W_H);
+        Grid {
+            extent,
+            spacing: Self::DEFAULT_SPACING,
+            weight: 1.0,
+            axes: false,
+            color: None,
+        }
+    }
+}
+
+impl SetColor<ColorScalar> for Grid {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.color)
+    }
+}
+
+impl From<Grid> for Primitive {
+    fn from(prim: Grid) -> Self {
+        Primitive::Grid(prim)
+    }
+}
+
+impl Into<Option<Grid>> for Primitive {
+    fn into(self) -> Option<Grid> {
+        match self {
+            Primitive::Grid(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Grid {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Grid {
+            extent,
+            spacing,
+            weight,
+            axes,
+            color,
+        } = self;
+        let color =
+            color.unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(&draw::theme::Primitive::Grid));
+        let transform = *ctxt.transform;
+        if spacing <= 0.0 {
+            return draw::renderer::PrimitiveRender::default();
+        }
+
+        // Lines are placed at each multiple of `spacing` within the given range.
+        let multiples = |start: f32, end: f32| {
+            let first = (start / spacing).ceil() as i64;
+            let last = (end / spacing).floor() as i64;
+            (first..=last).map(move |i| i as f32 * spacing)
+        };
+        let line_weight = |at: f32| {
+            if axes && at == 0.0 {
+                weight * Self::AXES_WEIGHT_SCALE
+            } else {
+                weight
+            }
+        };
+
+        let (l, r, b, t) = extent.l_r_b_t();
+        for x in multiples(l, r) {
+            let hw = line_weight(x) * 0.5;
+            let corners = [
+                pt2(x - hw, b),
+                pt2(x + hw, b),
+                pt2(x + hw, t),
+                pt2(x - hw, t),
+            ];
+            push_quad(mesh, transform, color, corners);
+        }
+        for y in multiples(b, t) {
+            let hw = line_weight(y) * 0.5;
+            let corners = [
+                pt2(l, y - hw),
+                pt2(r, y - hw),
+                pt2(r, y + hw),
+                pt2(l, y + hw),
+            ];
+            push_quad(mesh, transform, color, corners);
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+// Push a quad with the given corners in counter-clockwise order.
+fn push_quad(mesh: &mut draw::Mesh, transform: Mat4, color: LinSrgba, corners: [Point2; 4]) {
+    let start_index = mesh.points().len() as u32;
+    for &corner in &corners {
+        let point = transform.transform_point3(corner.extend(0.0));
+        let tex_coords = draw::mesh::vertex::default_tex_coords();
+        mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+    }
+    for &index in &[0, 1, 2, 0, 2, 3] {
+        mesh.push_index(start_index + index);
+    }
+}
+
+#[test]
+fn test_grid() {
+    let draw = draw::Draw::new();
+    let grid = draw.grid().spacing(10.0).axes(true).finish();
+    assert_eq!(draw.hit_test(pt2(20.0, 3.0)), Some(grid));
+    assert_eq!(draw.hit_test(pt2(5.0, 5.0)), None);
+    // The axes are thicker than the other lines.
+    assert_eq!(draw.hit_test(pt2(2.0, 5.0)), Some(grid));
+    assert_eq!(draw.hit_test(pt2(22.0, 5.0)), None);
+    // Nothing is drawn beyond the extent.
+    assert_eq!(draw.hit_test(pt2(0.0, 60.0)), None);
+}
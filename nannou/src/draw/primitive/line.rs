@@ -91,6 +91,10 @@ impl SetStroke for Line {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.path)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.path)
+    }
 }
 
 impl SetOrientation for Line {
@@ -140,19 +144,27 @@ impl draw::renderer::RenderPrimitive for Line {
         }
         let close = false;
         let points = [start, end];
-        let points = points.iter().cloned().map(|p| p.to_array().into());
-        let events = lyon::path::iterator::FromPolyline::new(close, points);
+        let events = || {
+            let points = points.iter().cloned().map(|p| p.to_array().into());
+            lyon::path::iterator::FromPolyline::new(close, points)
+        };
 
         // Determine the transform to apply to all points.
         let global_transform = *ctxt.transform;
         let local_transform = path.position.transform() * path.orientation.transform();
         let transform = global_transform * local_transform;
 
+        // Collect the outline if requested.
+        if let Some(outlines) = ctxt.outlines.as_mut() {
+            let tolerance = path::outline_tolerance(ctxt.tolerance);
+            path::flatten_path_events(events(), transform, tolerance, outlines);
+        }
+
         path::render_path_events(
-            events,
+            events(),
             path.color,
             transform,
-            path::Options::Stroke(path.opts),
+            path::Options::Stroke(path.opts).with_tolerance(path.tolerance.or(ctxt.tolerance)),
             &ctxt.theme,
             &draw::theme::Primitive::Line,
             &mut ctxt.fill_tessellator,
@@ -540,6 +540,8 @@ impl draw::renderer::RenderPrimitive for Mesh {
         draw::renderer::PrimitiveRender {
             texture_view,
             vertex_mode,
+            sampler: None,
+            color_vertex_count: 0,
         }
     }
 }
@@ -1,5 +1,8 @@
 pub mod arrow;
+pub mod circle;
+pub mod custom;
 pub mod ellipse;
+pub mod grid;
 pub mod line;
 pub mod mesh;
 pub mod path;
@@ -10,16 +13,19 @@ pub mod text;
 pub mod texture;
 pub mod tri;
 
-pub use self::arrow::Arrow;
+pub use self::arrow::{Arrow, ArrowHead};
+pub use self::circle::Circle;
+pub use self::custom::Custom;
 pub use self::ellipse::Ellipse;
+pub use self::grid::Grid;
 pub use self::line::Line;
 pub use self::mesh::Mesh;
 pub use self::path::{Path, PathFill, PathInit, PathStroke};
-pub use self::polygon::{Polygon, PolygonInit};
+pub use self::polygon::{Hatch, Polygon, PolygonInit, StrokeAlign};
 pub use self::quad::Quad;
 pub use self::rect::Rect;
 pub use self::text::Text;
-pub use self::texture::Texture;
+pub use self::texture::{Insets, Texture};
 pub use self::tri::Tri;
 
 /// A wrapper around all primitive sets of properties so that they may be stored within the
@@ -30,7 +36,10 @@ pub use self::tri::Tri;
 #[derive(Clone, Debug)]
 pub enum Primitive {
     Arrow(Arrow),
+    Circle(Circle),
+    Custom(Custom),
     Ellipse(Ellipse),
+    Grid(Grid),
     Line(Line),
     MeshVertexless(mesh::Vertexless),
     Mesh(Mesh),
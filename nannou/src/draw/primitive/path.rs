@@ -1,5 +1,5 @@
 use crate::color::conv::IntoLinSrgba;
-use crate::color::LinSrgba;
+use crate::color::{LinSrgba, Mix};
 use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
@@ -8,7 +8,7 @@ use crate::draw::properties::{
 };
 use crate::draw::{self, Drawing, DrawingContext};
 use crate::geom::Point2;
-use crate::glam::Mat4;
+use crate::glam::{vec3, Mat4, Vec2};
 use crate::wgpu;
 use lyon::path::PathEvent;
 use lyon::tessellation::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
@@ -35,6 +35,11 @@ pub(crate) enum PathEventSource {
         range: std::ops::Range<usize>,
         close: bool,
     },
+    /// Generate a ribbon from the `path_points_weighted_buffer`.
+    WeightedPoints {
+        range: std::ops::Range<usize>,
+        close: bool,
+    },
 }
 
 pub(crate) enum PathEventSourceIter<'a> {
@@ -61,6 +66,15 @@ pub struct PathOptions<T> {
     pub(crate) color: Option<LinSrgba>,
     pub(crate) position: position::Properties,
     pub(crate) orientation: orientation::Properties,
+    // Colors to be assigned along the points of the path. See `PathStroke::colors_along`.
+    pub(crate) colors_along: Vec<LinSrgba>,
+    // The radius with which interior corners are rounded. See `PathStroke::smooth`.
+    pub(crate) corner_radius: f32,
+    // Whether or not submitted points are joined from the end back to the start. See
+    // `PathStroke::closed`.
+    pub(crate) closed: bool,
+    // The tolerance explicitly specified for the path, overriding that of the **Draw** if any.
+    pub(crate) tolerance: Option<f32>,
 }
 
 /// Mutable access to stroke and fill tessellators.
@@ -83,6 +97,7 @@ pub struct Path {
     orientation: orientation::Properties,
     path_event_src: PathEventSource,
     options: Options,
+    tolerance: Option<f32>,
     vertex_mode: draw::renderer::VertexMode,
     texture_view: Option<wgpu::TextureView>,
 }
@@ -133,11 +148,19 @@ impl<T> PathOptions<T> {
         let orientation = Default::default();
         let position = Default::default();
         let color = Default::default();
+        let colors_along = Default::default();
+        let corner_radius = 0.0;
+        let closed = false;
+        let tolerance = None;
         PathOptions {
             opts,
             orientation,
             position,
             color,
+            colors_along,
+            corner_radius,
+            closed,
+            tolerance,
         }
     }
 }
@@ -161,6 +184,42 @@ impl PathFill {
 }
 
 impl PathStroke {
+    /// Assign the given colors to successive points of the path, producing a gradient stroke.
+    ///
+    /// If there are fewer colors than points, the colors are spread evenly along the points and
+    /// interpolated between. Any colors beyond the number of points are ignored. Only applies to
+    /// paths submitted via `points` or `points_closed`.
+    pub fn colors_along<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<ColorScalar>,
+    {
+        self.colors_along = colors.into_iter().map(|c| c.into_lin_srgba()).collect();
+        self
+    }
+
+    /// Round each corner of the polyline with a fillet of the given radius.
+    ///
+    /// The straight segments between corners are preserved. Where segments are too short to fit
+    /// the fillets of both of their corners, the fillets are reduced to meet at the middle of the
+    /// segment. Only applies to paths submitted via `points` or `points_closed` without
+    /// `colors_along`.
+    pub fn smooth(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Specify whether or not the polyline should be closed.
+    ///
+    /// When `true`, the end point is joined back to the start point using the stroke's line join,
+    /// as with the `_closed` variants of the `points` methods. A closed polyline has no caps. If
+    /// the last point repeats the first, it is ignored so that the two meet with a proper join
+    /// rather than overlapping.
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
     /// Short-hand for the `stroke_weight` method.
     pub fn weight(self, weight: f32) -> Self {
         self.stroke_weight(weight)
@@ -170,6 +229,70 @@ impl PathStroke {
     pub fn tolerance(self, tolerance: f32) -> Self {
         self.stroke_tolerance(tolerance)
     }
+
+    /// Submit a polyline of points, each with its own stroke weight.
+    ///
+    /// Rather than tessellating a stroke of uniform width, a ribbon is built whose width is
+    /// interpolated between the weight of each point. A weight of `0.0` at either end of the line
+    /// produces a tapered cap. The `stroke_weight`, caps and joins of the stroke options are
+    /// ignored.
+    pub fn points_weighted<I, P>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Point2>,
+    {
+        self.points_weighted_inner(ctxt, false, points)
+    }
+
+    /// Submit a polyline of points, each with its own stroke weight.
+    ///
+    /// The path will automatically close from the end point to the start point.
+    pub fn points_weighted_closed<I, P>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Point2>,
+    {
+        self.points_weighted_inner(ctxt, true, points)
+    }
+
+    // Consumes an iterator of weighted points and buffers them for rendering.
+    fn points_weighted_inner<I, P>(self, ctxt: DrawingContext, close: bool, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Point2>,
+    {
+        let close = close || self.closed;
+        let DrawingContext {
+            path_points_weighted_buffer,
+            ..
+        } = ctxt;
+        let start = path_points_weighted_buffer.len();
+        // Skip consecutive duplicate points as they have no direction.
+        for (p, w) in points {
+            let p = p.into();
+            if path_points_weighted_buffer[start..]
+                .last()
+                .map(|&(last, _)| last)
+                != Some(p)
+            {
+                path_points_weighted_buffer.push((p, w));
+            }
+        }
+        if close {
+            pop_repeated_end(path_points_weighted_buffer, start);
+        }
+        let end = path_points_weighted_buffer.len();
+        let path_event_src = PathEventSource::WeightedPoints {
+            range: start..end,
+            close,
+        };
+        Path::new(
+            self,
+            path_event_src,
+            draw::renderer::VertexMode::Color,
+            None,
+        )
+    }
 }
 
 impl<T> PathOptions<T>
@@ -188,16 +311,28 @@ where
         path_event_buffer.extend(events);
         let end = path_event_buffer.len();
         Path::new(
-            self.position,
-            self.orientation,
-            self.color,
+            self,
             PathEventSource::Buffered(start..end),
-            self.opts.into_options(),
             draw::renderer::VertexMode::Color,
             None,
         )
     }
 
+    /// Begin building the path from the given point.
+    ///
+    /// Segments may then be appended via the `line_to`, `quadratic_to`, `cubic_to` and `close`
+    /// methods of the returned **Path**.
+    pub fn move_to(self, ctxt: DrawingContext, to: Point2) -> Path {
+        let at = lyon::math::point(to.x, to.y);
+        let begin = PathEvent::Begin { at };
+        let end = PathEvent::End {
+            last: at,
+            first: at,
+            close: false,
+        };
+        self.events(ctxt, [begin, end].iter().cloned())
+    }
+
     /// Consumes an iterator of points and converts them to an iterator yielding path events.
     pub fn points<I>(self, ctxt: DrawingContext, points: I) -> Path
     where
@@ -274,6 +409,36 @@ where
         I: IntoIterator,
         I::Item: Into<Point2>,
     {
+        let close = close || self.closed;
+        if close {
+            // A repeated end point would produce a degenerate segment in place of the join.
+            let mut points: Vec<Point2> = points.into_iter().map(Into::into).collect();
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            return self.points_unique_inner(ctxt, close, points);
+        }
+        self.points_unique_inner(ctxt, close, points)
+    }
+
+    // Consumes an iterator of points with no repeated end point and converts them to events.
+    fn points_unique_inner<I>(self, ctxt: DrawingContext, close: bool, points: I) -> Path
+    where
+        I: IntoIterator,
+        I::Item: Into<Point2>,
+    {
+        if !self.colors_along.is_empty() {
+            let points: Vec<Point2> = points.into_iter().map(Into::into).collect();
+            let colors = colors_along(&self.colors_along, points.len());
+            let points_colored = points.into_iter().zip(colors).collect::<Vec<_>>();
+            return self.points_colored_inner(ctxt, close, points_colored);
+        }
+        if self.corner_radius > 0.0 {
+            let points: Vec<Point2> = points.into_iter().map(Into::into).collect();
+            let radius = self.corner_radius;
+            let path = rounded_polyline(&points, close, radius);
+            return self.events(ctxt, &path);
+        }
         let iter = points
             .into_iter()
             .map(Into::into)
@@ -289,6 +454,7 @@ where
         P: Into<Point2>,
         C: IntoLinSrgba<ColorScalar>,
     {
+        let close = close || self.closed;
         let DrawingContext {
             path_points_colored_buffer,
             ..
@@ -298,17 +464,17 @@ where
             .into_iter()
             .map(|(p, c)| (p.into(), c.into_lin_srgba()));
         path_points_colored_buffer.extend(points);
+        if close {
+            pop_repeated_end(path_points_colored_buffer, start);
+        }
         let end = path_points_colored_buffer.len();
         let path_event_src = PathEventSource::ColoredPoints {
             range: start..end,
             close,
         };
         Path::new(
-            self.position,
-            self.orientation,
-            self.color,
+            self,
             path_event_src,
-            self.opts.into_options(),
             draw::renderer::VertexMode::Color,
             None,
         )
@@ -327,6 +493,7 @@ where
         P: Into<Point2>,
         TC: Into<TexCoords>,
     {
+        let close = close || self.closed;
         let DrawingContext {
             path_points_textured_buffer,
             ..
@@ -334,23 +501,33 @@ where
         let start = path_points_textured_buffer.len();
         let points = points.into_iter().map(|(p, tc)| (p.into(), tc.into()));
         path_points_textured_buffer.extend(points);
+        if close {
+            pop_repeated_end(path_points_textured_buffer, start);
+        }
         let end = path_points_textured_buffer.len();
         let path_event_src = PathEventSource::TexturedPoints {
             range: start..end,
             close,
         };
         Path::new(
-            self.position,
-            self.orientation,
-            self.color,
+            self,
             path_event_src,
-            self.opts.into_options(),
             draw::renderer::VertexMode::Texture,
             Some(texture_view),
         )
     }
 }
 
+// Drop the last of the points buffered since `start` if it repeats the first.
+//
+// A repeated end point would produce a degenerate segment in place of the join of a closed path.
+fn pop_repeated_end<T>(buffer: &mut Vec<(Point2, T)>, start: usize) {
+    let first = buffer.get(start).map(|&(p, _)| p);
+    if buffer.len() > start + 1 && first == buffer.last().map(|&(p, _)| p) {
+        buffer.pop();
+    }
+}
+
 pub(crate) fn render_path_events<I>(
     events: I,
     color: Option<LinSrgba>,
@@ -381,6 +558,158 @@ pub(crate) fn render_path_events<I>(
     }
 }
 
+// Build a path along the given points with each corner rounded by a fillet of the given radius.
+//
+// Each fillet is a circular arc approximated by a cubic bézier curve.
+fn rounded_polyline(points: &[Point2], close: bool, radius: f32) -> lyon::path::Path {
+    let len = points.len();
+    let to_lyon = |p: Point2| lyon::math::point(p.x, p.y);
+
+    // The start, control points and end of the fillet for the corner at the given index, if any.
+    let fillet = |i: usize| -> Option<[Point2; 4]> {
+        if !close && (i == 0 || i == len - 1) {
+            return None;
+        }
+        let corner = points[i];
+        let prev = points[(i + len - 1) % len];
+        let next = points[(i + 1) % len];
+        let (to_prev, to_next) = (prev - corner, next - corner);
+        let (prev_len, next_len) = (to_prev.length(), to_next.length());
+        if prev_len <= 0.0 || next_len <= 0.0 {
+            return None;
+        }
+        let (u, v) = (to_prev / prev_len, to_next / next_len);
+        let angle = u.dot(v).clamp(-1.0, 1.0).acos();
+        let tangent_len = radius / (angle * 0.5).tan();
+        if !tangent_len.is_finite() || tangent_len <= 0.0 {
+            return None;
+        }
+        // Clamp the fillet so that it does not overlap with those of the adjacent corners. This
+        // reduces the radius of the arc while keeping it tangent to both segments.
+        let d = tangent_len.min(prev_len * 0.5).min(next_len * 0.5);
+        // The length of each control handle as a fraction of `d` for an arc sweeping `sweep`.
+        let sweep = std::f32::consts::PI - angle;
+        let k = 4.0 / 3.0 * (sweep * 0.25).tan() / (sweep * 0.5).tan();
+        let (a, b) = (corner + u * d, corner + v * d);
+        Some([a, corner + u * d * (1.0 - k), corner + v * d * (1.0 - k), b])
+    };
+
+    let mut builder = lyon::path::Path::builder();
+    if len < 3 {
+        let mut iter = points.iter().cloned();
+        if let Some(first) = iter.next() {
+            builder.begin(to_lyon(first));
+            for p in iter {
+                builder.line_to(to_lyon(p));
+            }
+            builder.end(close);
+        }
+        return builder.build();
+    }
+
+    let first = fillet(0);
+    let start = first.map(|[.., end]| end).unwrap_or(points[0]);
+    builder.begin(to_lyon(start));
+    for (i, &point) in points.iter().enumerate().skip(1) {
+        match fillet(i) {
+            None => {
+                builder.line_to(to_lyon(point));
+            }
+            Some([a, ctrl1, ctrl2, b]) => {
+                builder.line_to(to_lyon(a));
+                builder.cubic_bezier_to(to_lyon(ctrl1), to_lyon(ctrl2), to_lyon(b));
+            }
+        }
+    }
+    if let Some([a, ctrl1, ctrl2, b]) = first {
+        builder.line_to(to_lyon(a));
+        builder.cubic_bezier_to(to_lyon(ctrl1), to_lyon(ctrl2), to_lyon(b));
+    }
+    builder.end(close);
+    builder.build()
+}
+
+// Produce a color for each of `len` points, interpolating between the given colors as necessary.
+fn colors_along(colors: &[LinSrgba], len: usize) -> impl Iterator<Item = LinSrgba> + '_ {
+    (0..len).map(move |i| {
+        if colors.len() >= len || colors.len() == 1 {
+            return colors[i.min(colors.len() - 1)];
+        }
+        let pos = i as f32 / (len - 1) as f32 * (colors.len() - 1) as f32;
+        let ix = (pos as usize).min(colors.len() - 2);
+        colors[ix].mix(&colors[ix + 1], pos - ix as f32)
+    })
+}
+
+// The tolerance with which outlines are flattened for the given context tolerance.
+pub(crate) fn outline_tolerance(tolerance: Option<f32>) -> f32 {
+    tolerance.unwrap_or(FillOptions::DEFAULT_TOLERANCE)
+}
+
+// Flatten the given path events into polylines, appending them to `outlines`.
+pub(crate) fn flatten_path_events<I>(
+    events: I,
+    transform: Mat4,
+    tolerance: f32,
+    outlines: &mut Vec<Vec<Vec2>>,
+) where
+    I: IntoIterator<Item = PathEvent>,
+{
+    use lyon::path::iterator::PathIterator;
+    let to_vec2 = |p: lyon::math::Point| transform.transform_point3(vec3(p.x, p.y, 0.0)).truncate();
+    for event in events.into_iter().flattened(tolerance) {
+        match event {
+            PathEvent::Begin { at } => outlines.push(vec![to_vec2(at)]),
+            PathEvent::Line { to, .. } => {
+                if let Some(outline) = outlines.last_mut() {
+                    outline.push(to_vec2(to));
+                }
+            }
+            PathEvent::End {
+                first, close: true, ..
+            } => {
+                if let Some(outline) = outlines.last_mut() {
+                    outline.push(to_vec2(first));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+// Flatten the path of the given source into polylines, appending them to `outlines`.
+pub(crate) fn flatten_path_event_source(
+    src: &PathEventSource,
+    ctxt: &draw::renderer::RenderContext,
+    transform: Mat4,
+    outlines: &mut Vec<Vec<Vec2>>,
+) {
+    let tolerance = outline_tolerance(ctxt.tolerance);
+    let mut flatten_points = |points: &mut dyn Iterator<Item = Point2>, close| {
+        let points = points.map(|p| lyon::math::point(p.x, p.y));
+        let events = lyon::path::iterator::FromPolyline::new(close, points);
+        flatten_path_events(events, transform, tolerance, outlines);
+    };
+    match *src {
+        PathEventSource::Buffered(ref range) => {
+            let events = ctxt.path_event_buffer[range.clone()].iter().cloned();
+            flatten_path_events(events, transform, tolerance, outlines);
+        }
+        PathEventSource::ColoredPoints { ref range, close } => {
+            let buffer = &ctxt.path_points_colored_buffer[range.clone()];
+            flatten_points(&mut buffer.iter().map(|&(p, _)| p), close);
+        }
+        PathEventSource::TexturedPoints { ref range, close } => {
+            let buffer = &ctxt.path_points_textured_buffer[range.clone()];
+            flatten_points(&mut buffer.iter().map(|&(p, _)| p), close);
+        }
+        PathEventSource::WeightedPoints { ref range, close } => {
+            let buffer = &ctxt.path_points_weighted_buffer[range.clone()];
+            flatten_points(&mut buffer.iter().map(|&(p, _)| p), close);
+        }
+    }
+}
+
 pub(crate) fn render_path_points_colored<I>(
     points_colored: I,
     close: bool,
@@ -459,6 +788,78 @@ pub(crate) fn render_path_points_textured<I>(
     }
 }
 
+/// The maximum length of a join relative to the half-width of the ribbon, beyond which sharp
+/// corners are clipped. Matches lyon's default miter limit.
+const MITER_LIMIT: f32 = 4.0;
+
+// Tessellate a ribbon along the given weighted points.
+//
+// Consecutive duplicate points and the repeated end point of a closed ribbon are expected to have
+// been removed while buffering the points.
+pub(crate) fn render_path_points_weighted(
+    points: &[(Point2, f32)],
+    close: bool,
+    color: Color,
+    transform: Mat4,
+    mesh: &mut draw::Mesh,
+) {
+    let n = points.len();
+    if n < 2 {
+        return;
+    }
+
+    // The normal of the segment starting at point `i`.
+    let segment_normal = |i: usize| {
+        let (a, b) = (points[i].0, points[(i + 1) % n].0);
+        (b - a).normalize().perp()
+    };
+
+    // Add a vertex on either side of each point.
+    let start_index = mesh.points().len() as u32;
+    for (i, &(p, weight)) in points.iter().enumerate() {
+        let prev = if i > 0 || close {
+            Some(segment_normal((i + n - 1) % n))
+        } else {
+            None
+        };
+        let next = if i < n - 1 || close {
+            Some(segment_normal(i))
+        } else {
+            None
+        };
+        let offset = match (prev, next) {
+            (Some(a), Some(b)) => {
+                let miter = (a + b).normalize_or_zero();
+                let cos = miter.dot(b).max(1.0 / MITER_LIMIT);
+                if miter == Point2::ZERO {
+                    b
+                } else {
+                    miter / cos
+                }
+            }
+            (Some(normal), None) | (None, Some(normal)) => normal,
+            (None, None) => unreachable!(),
+        };
+        let offset = offset * weight * 0.5;
+        for &side in &[p + offset, p - offset] {
+            let point = transform.transform_point3(side.extend(0.0));
+            let tex_coords = draw::mesh::vertex::default_tex_coords();
+            mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+        }
+    }
+
+    // Join the sides of each segment with a pair of triangles.
+    let n_segments = if close { n } else { n - 1 };
+    for i in 0..n_segments {
+        let [l0, r0] = [2 * i as u32, 2 * i as u32 + 1];
+        let j = ((i + 1) % n) as u32;
+        let [l1, r1] = [2 * j, 2 * j + 1];
+        for &index in &[l0, r0, l1, r0, r1, l1] {
+            mesh.push_index(start_index + index);
+        }
+    }
+}
+
 pub(crate) fn render_path_source(
     // TODO:
     path_src: PathEventSourceIter,
@@ -516,6 +917,7 @@ impl draw::renderer::RenderPrimitive for Path {
             orientation,
             path_event_src,
             options,
+            tolerance,
             vertex_mode,
             texture_view,
         } = self;
@@ -524,6 +926,12 @@ impl draw::renderer::RenderPrimitive for Path {
         let global_transform = *ctxt.transform;
         let local_transform = position.transform() * orientation.transform();
         let transform = global_transform * local_transform;
+        let options = options.with_tolerance(tolerance.or(ctxt.tolerance));
+
+        // Collect the outline if requested.
+        if let Some(mut outlines) = ctxt.outlines.take() {
+            flatten_path_event_source(&path_event_src, &ctxt, transform, &mut outlines);
+        }
 
         // A function for rendering the path.
         let render =
@@ -581,11 +989,19 @@ impl draw::renderer::RenderPrimitive for Path {
                     &mut ctxt.stroke_tessellator,
                 );
             }
+            PathEventSource::WeightedPoints { range, close } => {
+                let points_weighted = &ctxt.path_points_weighted_buffer[range];
+                let color = color
+                    .unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(&draw::theme::Primitive::Path));
+                render_path_points_weighted(points_weighted, close, color, transform, mesh);
+            }
         }
 
         draw::renderer::PrimitiveRender {
             texture_view,
             vertex_mode,
+            sampler: None,
+            color_vertex_count: 0,
         }
     }
 }
@@ -652,25 +1068,160 @@ where
 
 impl Path {
     // Initialise a new `Path` with its ranges into the intermediary mesh, ready for drawing.
-    fn new(
-        position: position::Properties,
-        orientation: orientation::Properties,
-        color: Option<LinSrgba>,
+    fn new<T>(
+        opts: PathOptions<T>,
         path_event_src: PathEventSource,
-        options: Options,
         vertex_mode: draw::renderer::VertexMode,
         texture_view: Option<wgpu::TextureView>,
-    ) -> Self {
+    ) -> Self
+    where
+        T: TessellationOptions,
+    {
         Path {
-            color,
-            orientation,
-            position,
+            color: opts.color,
+            orientation: opts.orientation,
+            position: opts.position,
             path_event_src,
-            options,
+            options: opts.opts.into_options(),
+            tolerance: opts.tolerance,
             vertex_mode,
             texture_view,
         }
     }
+
+    /// Begin a new sub-path at the given point.
+    pub fn move_to(self, ctxt: DrawingContext, to: Point2) -> Self {
+        self.extend_events(ctxt, |events, _| {
+            let at = lyon::math::point(to.x, to.y);
+            events.push(PathEvent::Begin { at });
+            events.push(PathEvent::End {
+                last: at,
+                first: at,
+                close: false,
+            });
+        })
+    }
+
+    /// Append a straight line from the current point to the given point.
+    pub fn line_to(self, ctxt: DrawingContext, to: Point2) -> Self {
+        let to = lyon::math::point(to.x, to.y);
+        self.push_segment(ctxt, |from| PathEvent::Line { from, to })
+    }
+
+    /// Append a quadratic bézier curve from the current point to the given point.
+    pub fn quadratic_to(self, ctxt: DrawingContext, ctrl: Point2, to: Point2) -> Self {
+        let ctrl = lyon::math::point(ctrl.x, ctrl.y);
+        let to = lyon::math::point(to.x, to.y);
+        self.push_segment(ctxt, |from| PathEvent::Quadratic { from, ctrl, to })
+    }
+
+    /// Append a cubic bézier curve from the current point to the given point.
+    pub fn cubic_to(self, ctxt: DrawingContext, ctrl1: Point2, ctrl2: Point2, to: Point2) -> Self {
+        let ctrl1 = lyon::math::point(ctrl1.x, ctrl1.y);
+        let ctrl2 = lyon::math::point(ctrl2.x, ctrl2.y);
+        let to = lyon::math::point(to.x, to.y);
+        self.push_segment(ctxt, |from| PathEvent::Cubic {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        })
+    }
+
+    /// Close the current sub-path with a line back to its first point.
+    pub fn close(self, ctxt: DrawingContext) -> Self {
+        self.extend_events(ctxt, |events, start| {
+            let (last, first) = match events[start..].last() {
+                None | Some(PathEvent::End { close: true, .. }) => return,
+                Some(&PathEvent::End { last, first, .. }) => {
+                    events.pop();
+                    (last, first)
+                }
+                Some(event) => (event.to(), first_point(&events[start..])),
+            };
+            events.push(PathEvent::End {
+                last,
+                first,
+                close: true,
+            });
+        })
+    }
+
+    // Append a segment to the current sub-path, produced by `segment` given the current point.
+    fn push_segment<F>(self, ctxt: DrawingContext, segment: F) -> Self
+    where
+        F: FnOnce(lyon::math::Point) -> PathEvent,
+    {
+        self.extend_events(ctxt, |events, start| {
+            let (from, first) = match events[start..].last() {
+                // Continue the open sub-path.
+                Some(&PathEvent::End {
+                    last,
+                    first,
+                    close: false,
+                }) => {
+                    events.pop();
+                    (last, first)
+                }
+                // Continue from the start of the closed sub-path, as in SVG.
+                Some(&PathEvent::End { first, .. }) => {
+                    events.push(PathEvent::Begin { at: first });
+                    (first, first)
+                }
+                Some(event) => (event.to(), first_point(&events[start..])),
+                None => {
+                    let at = lyon::math::point(0.0, 0.0);
+                    events.push(PathEvent::Begin { at });
+                    (at, at)
+                }
+            };
+            let event = segment(from);
+            events.push(event);
+            events.push(PathEvent::End {
+                last: event.to(),
+                first,
+                close: false,
+            });
+        })
+    }
+
+    // Extend the path's events via the given function, which is passed the buffer along with the
+    // index of the path's first event.
+    //
+    // If other events have since been written to the buffer, the path's events are first moved to
+    // the end. Has no effect on paths that were not built from path events.
+    fn extend_events<F>(mut self, ctxt: DrawingContext, extend: F) -> Self
+    where
+        F: FnOnce(&mut Vec<PathEvent>, usize),
+    {
+        let range = match self.path_event_src {
+            PathEventSource::Buffered(ref range) => range.clone(),
+            _ => return self,
+        };
+        let events = ctxt.path_event_buffer;
+        let start = if range.end == events.len() {
+            range.start
+        } else {
+            let start = events.len();
+            events.extend_from_within(range);
+            start
+        };
+        extend(events, start);
+        self.path_event_src = PathEventSource::Buffered(start..events.len());
+        self
+    }
+}
+
+// The first point of the last sub-path within the given events.
+fn first_point(events: &[PathEvent]) -> lyon::math::Point {
+    events
+        .iter()
+        .rev()
+        .find_map(|event| match *event {
+            PathEvent::Begin { at } => Some(at),
+            _ => None,
+        })
+        .unwrap_or_else(|| lyon::math::point(0.0, 0.0))
 }
 
 impl<'a> DrawingPathInit<'a> {
@@ -704,6 +1255,37 @@ impl<'a> DrawingPathFill<'a> {
 }
 
 impl<'a> DrawingPathStroke<'a> {
+    /// Assign the given colors to successive points of the path, producing a gradient stroke.
+    ///
+    /// If there are fewer colors than points, the colors are spread evenly along the points and
+    /// interpolated between.
+    pub fn colors_along<I>(self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.colors_along(colors))
+    }
+
+    /// Round each corner of the polyline with a fillet of the given radius.
+    ///
+    /// The straight segments between corners are preserved. Where segments are too short to fit
+    /// the fillets of both of their corners, the fillets are reduced to meet at the middle of the
+    /// segment.
+    pub fn smooth(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.smooth(radius))
+    }
+
+    /// Specify whether or not the polyline should be closed.
+    ///
+    /// When `true`, the end point is joined back to the start point using the stroke's line join,
+    /// as with the `_closed` variants of the `points` methods. A closed polyline has no caps. If
+    /// the last point repeats the first, it is ignored so that the two meet with a proper join
+    /// rather than overlapping.
+    pub fn closed(self, closed: bool) -> Self {
+        self.map_ty(|ty| ty.closed(closed))
+    }
+
     /// Short-hand for the `stroke_weight` method.
     pub fn weight(self, weight: f32) -> Self {
         self.map_ty(|ty| ty.stroke_weight(weight))
@@ -713,6 +1295,31 @@ impl<'a> DrawingPathStroke<'a> {
     pub fn tolerance(self, tolerance: f32) -> Self {
         self.map_ty(|ty| ty.stroke_tolerance(tolerance))
     }
+
+    /// Submit a polyline of points, each with its own stroke weight.
+    ///
+    /// Rather than tessellating a stroke of uniform width, a ribbon is built whose width is
+    /// interpolated between the weight of each point. A weight of `0.0` at either end of the line
+    /// produces a tapered cap. The `stroke_weight`, caps and joins of the stroke options are
+    /// ignored.
+    pub fn points_weighted<I, P>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Point2>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_weighted(ctxt, points))
+    }
+
+    /// Submit a polyline of points, each with its own stroke weight.
+    ///
+    /// The path will automatically close from the end point to the start point.
+    pub fn points_weighted_closed<I, P>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Point2>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_weighted_closed(ctxt, points))
+    }
 }
 
 impl<'a, T> DrawingPathOptions<'a, T>
@@ -729,6 +1336,29 @@ where
         self.map_ty_with_context(|ty, ctxt| ty.events(ctxt, events))
     }
 
+    /// Begin building the path from the given point.
+    ///
+    /// Segments may then be appended via `line_to`, `quadratic_to`, `cubic_to` and `close`.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # fn view(app: &App, frame: Frame) {
+    /// let draw = app.draw();
+    /// draw.path()
+    ///     .stroke()
+    ///     .weight(4.0)
+    ///     .move_to(pt2(-100.0, 0.0))
+    ///     .quadratic_to(pt2(-50.0, 100.0), pt2(0.0, 0.0))
+    ///     .cubic_to(pt2(25.0, -100.0), pt2(75.0, -100.0), pt2(100.0, 0.0))
+    ///     .line_to(pt2(0.0, -150.0))
+    ///     .close()
+    ///     .color(WHITE);
+    /// # }
+    /// ```
+    pub fn move_to(self, to: Point2) -> DrawingPath<'a> {
+        self.map_ty_with_context(|ty, ctxt| ty.move_to(ctxt, to))
+    }
+
     /// Submit the path events as a polyline of points.
     pub fn points<I>(self, points: I) -> DrawingPath<'a>
     where
@@ -802,16 +1432,76 @@ where
     }
 }
 
+impl<'a> DrawingPath<'a> {
+    /// Begin a new sub-path at the given point.
+    ///
+    /// Only applies to paths built from path events, e.g. via `move_to`, `events` or `points`.
+    pub fn move_to(self, to: Point2) -> Self {
+        self.map_ty_with_context(|ty, ctxt| ty.move_to(ctxt, to))
+    }
+
+    /// Append a straight line from the current point to the given point.
+    ///
+    /// Only applies to paths built from path events, e.g. via `move_to`, `events` or `points`.
+    pub fn line_to(self, to: Point2) -> Self {
+        self.map_ty_with_context(|ty, ctxt| ty.line_to(ctxt, to))
+    }
+
+    /// Append a quadratic bézier curve from the current point to the given point, bending
+    /// towards the given control point.
+    ///
+    /// Only applies to paths built from path events, e.g. via `move_to`, `events` or `points`.
+    pub fn quadratic_to(self, ctrl: Point2, to: Point2) -> Self {
+        self.map_ty_with_context(|ty, ctxt| ty.quadratic_to(ctxt, ctrl, to))
+    }
+
+    /// Append a cubic bézier curve from the current point to the given point, bending towards
+    /// the two given control points.
+    ///
+    /// Only applies to paths built from path events, e.g. via `move_to`, `events` or `points`.
+    pub fn cubic_to(self, ctrl1: Point2, ctrl2: Point2, to: Point2) -> Self {
+        self.map_ty_with_context(|ty, ctxt| ty.cubic_to(ctxt, ctrl1, ctrl2, to))
+    }
+
+    /// Close the current sub-path with a line back to its first point.
+    ///
+    /// A segment appended after closing begins a new sub-path at the same first point.
+    pub fn close(self) -> Self {
+        self.map_ty_with_context(|ty, ctxt| ty.close(ctxt))
+    }
+}
+
 impl SetFill for PathFill {
     fn fill_options_mut(&mut self) -> &mut FillOptions {
         &mut self.opts
     }
+
+    fn fill_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        Some(&mut self.tolerance)
+    }
 }
 
 impl SetStroke for PathStroke {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         &mut self.opts
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        Some(&mut self.tolerance)
+    }
+}
+
+impl Options {
+    // Tessellate with the given tolerance, if any, rather than that of the options.
+    pub(crate) fn with_tolerance(mut self, tolerance: Option<f32>) -> Self {
+        if let Some(tolerance) = tolerance {
+            match self {
+                Options::Fill(ref mut opts) => opts.tolerance = tolerance,
+                Options::Stroke(ref mut opts) => opts.tolerance = tolerance,
+            }
+        }
+        self
+    }
 }
 
 impl TessellationOptions for FillOptions {
@@ -923,3 +1613,152 @@ impl Into<Option<Path>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_points_weighted() {
+    let draw = draw::Draw::new();
+    // Consecutive duplicate points are skipped.
+    let points = [(0.0, 0.0), (100.0, 20.0), (100.0, 20.0), (200.0, 0.0)];
+    let points = points.iter().map(|&(x, w)| (crate::geom::pt2(x, 0.0), w));
+    let line = draw.polyline().points_weighted(points).finish();
+    let hit = |x, y| draw.hit_test_with_tolerance(crate::geom::pt2(x, y), 0.0);
+    assert_eq!(hit(100.0, 9.0), Some(line));
+    assert_eq!(hit(100.0, -9.0), Some(line));
+    assert_eq!(hit(100.0, 11.0), None);
+    // The ends taper to zero width.
+    assert_eq!(hit(10.0, 1.0), Some(line));
+    assert_eq!(hit(10.0, 3.0), None);
+    let state = draw.state.borrow();
+    let intermediary_state = state.intermediary_state.borrow();
+    assert_eq!(intermediary_state.path_points_weighted_buffer.len(), 3);
+}
+
+#[test]
+fn test_colors_along() {
+    let (black, white) = (
+        LinSrgba::new(0.0, 0.0, 0.0, 1.0),
+        LinSrgba::new(1.0, 1.0, 1.0, 1.0),
+    );
+    let colors: Vec<_> = colors_along(&[black, white], 5).map(|c| c.red).collect();
+    assert_eq!(colors, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    let colors: Vec<_> = colors_along(&[black, white, black], 2)
+        .map(|c| c.red)
+        .collect();
+    assert_eq!(colors, vec![0.0, 1.0]);
+    let colors: Vec<_> = colors_along(&[white], 3).map(|c| c.red).collect();
+    assert_eq!(colors, vec![1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn test_smooth() {
+    use crate::geom::pt2;
+    let points = [pt2(0.0, 0.0), pt2(100.0, 0.0), pt2(100.0, 100.0)];
+    let draw = draw::Draw::new();
+    let sharp = draw
+        .polyline()
+        .weight(2.0)
+        .points(points.iter().cloned())
+        .finish();
+    assert_eq!(draw.hit_test(pt2(100.0, 0.0)), Some(sharp));
+    let draw = draw::Draw::new();
+    let smooth = draw
+        .polyline()
+        .weight(2.0)
+        .smooth(30.0)
+        .points(points.iter().cloned())
+        .finish();
+    // The corner is cut by the fillet, while the straight segments remain.
+    assert_eq!(draw.hit_test(pt2(100.0, 0.0)), None);
+    assert_eq!(draw.hit_test(pt2(91.2, 8.8)), Some(smooth));
+    assert_eq!(draw.hit_test(pt2(30.0, 0.0)), Some(smooth));
+    assert_eq!(draw.hit_test(pt2(100.0, 70.0)), Some(smooth));
+
+    // The fillet is a circular arc of the given radius.
+    let path = rounded_polyline(&points, false, 30.0);
+    let mut outlines = vec![];
+    flatten_path_events(path.iter(), Mat4::IDENTITY, 0.01, &mut outlines);
+    let centre = pt2(70.0, 30.0);
+    let mut arc = outlines[0].iter().filter(|p| p.x > 70.0 && p.y < 30.0);
+    assert!(arc.clone().count() > 2);
+    assert!(arc.all(|p| (p.distance(centre) - 30.0).abs() < 0.05));
+}
+
+#[test]
+fn test_closed() {
+    use crate::geom::pt2;
+    let draw = draw::Draw::new();
+    let square = [
+        pt2(0.0, 0.0),
+        pt2(10.0, 0.0),
+        pt2(10.0, 10.0),
+        pt2(0.0, 10.0),
+        pt2(0.0, 0.0),
+    ];
+    let index = draw
+        .polyline()
+        .weight(2.0)
+        .closed(true)
+        .points(square.iter().cloned())
+        .finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 5.0)), Some(index));
+    assert_eq!(draw.hit_test(pt2(5.0, 5.0)), None);
+
+    draw.polyline()
+        .closed(true)
+        .points_weighted(square.iter().map(|&p| (p, 2.0)));
+    draw.polyline()
+        .points_colored_closed(square.iter().map(|&p| (p, crate::color::RED)));
+
+    // The repeated end point is dropped in favour of closing the path for each kind of points.
+    let state = draw.state.borrow();
+    let intermediary_state = state.intermediary_state.borrow();
+    let events = &intermediary_state.path_event_buffer;
+    let lines = events
+        .iter()
+        .filter(|e| matches!(e, PathEvent::Line { .. }))
+        .count();
+    assert_eq!(lines, 3);
+    assert!(matches!(
+        events.last(),
+        Some(PathEvent::End { close: true, .. })
+    ));
+    assert_eq!(intermediary_state.path_points_weighted_buffer.len(), 4);
+    assert_eq!(intermediary_state.path_points_colored_buffer.len(), 4);
+}
+
+#[test]
+fn test_path_builder() {
+    use crate::geom::pt2;
+    let draw = draw::Draw::new();
+    let path = draw
+        .path()
+        .fill()
+        .move_to(pt2(0.0, 0.0))
+        .line_to(pt2(10.0, 0.0))
+        .quadratic_to(pt2(20.0, 5.0), pt2(10.0, 10.0))
+        .cubic_to(pt2(7.0, 12.0), pt2(3.0, 12.0), pt2(0.0, 10.0))
+        .close();
+    // Drawing something else in between moves the path's events to the end of the buffer.
+    draw.ellipse().x(-100.0).radius(1.0);
+    let path = path
+        .move_to(pt2(50.0, 0.0))
+        .line_to(pt2(60.0, 0.0))
+        .line_to(pt2(60.0, 10.0));
+    let index = path.finish();
+    assert_eq!(draw.hit_test(pt2(14.0, 5.0)), Some(index));
+    assert_eq!(draw.hit_test(pt2(5.0, 11.0)), Some(index));
+    assert_eq!(draw.hit_test(pt2(58.0, 2.0)), Some(index));
+    assert_eq!(draw.hit_test(pt2(5.0, 15.0)), None);
+
+    let state = draw.state.borrow();
+    let events = &state.intermediary_state.borrow().path_event_buffer;
+    let closed = events
+        .iter()
+        .filter(|e| matches!(e, PathEvent::End { close: true, .. }))
+        .count();
+    assert_eq!(closed, 1);
+    assert!(matches!(
+        events.last(),
+        Some(PathEvent::End { close: false, .. })
+    ));
+}
@@ -8,7 +8,8 @@ use crate::draw::properties::{
     ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
-use crate::geom::Point2;
+use crate::geom::{self, pt2, Point2};
+use crate::glam::{Mat4, Vec2};
 use crate::wgpu;
 use lyon::path::PathEvent;
 use lyon::tessellation::StrokeOptions;
@@ -24,6 +25,17 @@ pub trait SetPolygon: Sized {
         self
     }
 
+    /// Specify no stroke and in turn no stroke tessellation for the polygon.
+    ///
+    /// This clears any stroke color and stroke options set so far.
+    fn no_stroke(mut self) -> Self {
+        let opts = self.polygon_options_mut();
+        opts.stroke_color = None;
+        opts.stroke = None;
+        opts.stroke_tolerance = None;
+        self
+    }
+
     /// Specify a color to use for stroke tessellation.
     ///
     /// Stroke tessellation will only be performed if this method or one of the `SetStroke` methods
@@ -36,6 +48,24 @@ pub trait SetPolygon: Sized {
         self
     }
 
+    /// Specify where the stroke lies relative to the outline of the polygon.
+    ///
+    /// By default, the stroke is centred on the outline. See **StrokeAlign** for details.
+    fn stroke_align(mut self, align: StrokeAlign) -> Self {
+        self.polygon_options_mut().stroke_align = align;
+        self
+    }
+
+    /// Fill the polygon with parallel lines within the output of `Draw::to_plotter_paths`.
+    ///
+    /// Lines are produced at the given `angle` in radians and `spacing` apart in the coordinate
+    /// space of the output. Hatching is only produced for filled polygons and does not affect
+    /// rendering. See **Hatch** for details.
+    fn hatch(mut self, angle: f32, spacing: f32) -> Self {
+        self.polygon_options_mut().hatch = Some(Hatch { angle, spacing });
+        self
+    }
+
     /// Specify the whole set of polygon options.
     fn polygon_options(mut self, opts: PolygonOptions) -> Self {
         *self.polygon_options_mut() = opts;
@@ -58,6 +88,39 @@ pub struct PolygonOptions {
     pub stroke_color: Option<LinSrgba>,
     pub color: Option<LinSrgba>,
     pub stroke: Option<StrokeOptions>,
+    /// The stroke tolerance explicitly specified for the polygon, overriding that of the **Draw**.
+    pub stroke_tolerance: Option<f32>,
+    pub stroke_align: StrokeAlign,
+    pub hatch: Option<Hatch>,
+}
+
+/// Where the stroke of a polygon lies relative to its outline.
+///
+/// Non-centred strokes are produced by flattening the outline and offsetting each closed
+/// sub-path by half of the stroke weight before tessellating the stroke. Sub-paths lying within
+/// another, such as holes, are offset relative to the filled region under the even-odd rule.
+/// Open sub-paths are always stroked along their centre. Very thick inner strokes on small or
+/// concave polygons may overlap themselves.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StrokeAlign {
+    /// The stroke lies entirely within the outline.
+    Inner,
+    /// The stroke straddles the outline. This is the default.
+    Center,
+    /// The stroke lies entirely outside the outline.
+    Outer,
+}
+
+/// Parallel lines filling a polygon within pen plotter output.
+///
+/// Lines are clipped to the filled region under the even-odd rule, so holes are left empty. The
+/// lines of neighbouring polygons with the same hatch are aligned with one another.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hatch {
+    /// The angle of the lines in radians, anticlockwise from the *x* axis.
+    pub angle: f32,
+    /// The distance between neighbouring lines.
+    pub spacing: f32,
 }
 
 /// A polygon with vertices already submitted.
@@ -116,6 +179,90 @@ impl PolygonInit {
         self.events(ctxt, events)
     }
 
+    /// Describe a regular polygon with the given number of sides, centred on the origin.
+    ///
+    /// The first vertex lies at `radius` along the *y* axis so that the polygon points up. Use the
+    /// `SetOrientation` methods to rotate it. `sides` is clamped to a minimum of `3`.
+    pub fn regular(self, ctxt: DrawingContext, sides: usize, radius: f32) -> Polygon {
+        let sides = sides.max(3);
+        let step = std::f32::consts::TAU / sides as f32;
+        let points = (0..sides).map(|i| {
+            let angle = std::f32::consts::FRAC_PI_2 + i as f32 * step;
+            pt2(angle.cos() * radius, angle.sin() * radius)
+        });
+        self.points(ctxt, points)
+    }
+
+    /// Describe a star with the given number of points, centred on the origin.
+    ///
+    /// Vertices alternate between `outer_radius` and `inner_radius`, starting with an outer vertex
+    /// along the *y* axis so that the star points up. `points` is clamped to a minimum of `2` and
+    /// `inner_radius` is clamped to the range `0.0..=outer_radius`.
+    pub fn star(
+        self,
+        ctxt: DrawingContext,
+        points: usize,
+        inner_radius: f32,
+        outer_radius: f32,
+    ) -> Polygon {
+        let points = points.max(2);
+        let inner_radius = inner_radius.max(0.0).min(outer_radius);
+        let step = std::f32::consts::PI / points as f32;
+        let vertices = (0..points * 2).map(|i| {
+            let angle = std::f32::consts::FRAC_PI_2 + i as f32 * step;
+            let radius = if i % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+            pt2(angle.cos() * radius, angle.sin() * radius)
+        });
+        self.points(ctxt, vertices)
+    }
+
+    /// Describe a gear with the given number of teeth, centred on the origin.
+    ///
+    /// The tips of the teeth lie at `outer_radius` and their roots `tooth_depth` closer to the
+    /// centre, with a tooth along the *y* axis. If `inner_radius` is greater than `0.0`, a hole of
+    /// that radius is cut from the centre of the gear.
+    ///
+    /// `teeth` is clamped to a minimum of `3`, `tooth_depth` to the range `0.0..=outer_radius` and
+    /// `inner_radius` to the range between `0.0` and the root of the teeth.
+    pub fn gear(
+        self,
+        ctxt: DrawingContext,
+        teeth: usize,
+        inner_radius: f32,
+        outer_radius: f32,
+        tooth_depth: f32,
+    ) -> Polygon {
+        // The angle of each profile vertex relative to the centre of the tooth as a fraction of
+        // the pitch, and whether or not the vertex lies at the tip.
+        const PROFILE: [(f32, bool); 4] =
+            [(-0.25, false), (-0.15, true), (0.15, true), (0.25, false)];
+        let teeth = teeth.max(3);
+        let root_radius = outer_radius - tooth_depth.max(0.0).min(outer_radius);
+        let inner_radius = inner_radius.max(0.0).min(root_radius);
+        let pitch = std::f32::consts::TAU / teeth as f32;
+        let point = |angle: f32, radius: f32| lyon::math::point(angle.cos(), angle.sin()) * radius;
+        let outline = (0..teeth).flat_map(move |i| {
+            let centre = std::f32::consts::FRAC_PI_2 + i as f32 * pitch;
+            PROFILE.iter().map(move |&(offset, tip)| {
+                let radius = if tip { outer_radius } else { root_radius };
+                point(centre + offset * pitch, radius)
+            })
+        });
+        let close = true;
+        let mut events: Vec<_> = lyon::path::iterator::FromPolyline::new(close, outline).collect();
+        if inner_radius > 0.0 {
+            let resolution = teeth * PROFILE.len();
+            let step = std::f32::consts::TAU / resolution as f32;
+            let hole = (0..resolution).map(|i| point(i as f32 * step, inner_radius));
+            events.extend(lyon::path::iterator::FromPolyline::new(close, hole));
+        }
+        self.events(ctxt, events)
+    }
+
     /// Consumes an iterator of points and converts them to an iterator yielding path events.
     pub fn points_colored<I, P, C>(self, ctxt: DrawingContext, points: I) -> Polygon
     where
@@ -191,6 +338,9 @@ pub fn render_events_themed<F, I>(
         stroke_color,
         color,
         stroke,
+        stroke_tolerance,
+        stroke_align,
+        hatch,
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -198,15 +348,24 @@ pub fn render_events_themed<F, I>(
     let local_transform = position.transform() * orientation.transform();
     let transform = global_transform * local_transform;
 
+    // Collect the outline if requested.
+    if let Some(outlines) = ctxt.outlines.as_mut() {
+        let tolerance = path::outline_tolerance(ctxt.tolerance);
+        let start = outlines.len();
+        path::flatten_path_events(events(), transform, tolerance, outlines);
+        push_hatching(outlines, start, hatch.filter(|_| !no_fill));
+    }
+
     // A function for rendering the path.
     let mut render =
-        |opts: path::Options,
+        |events: &mut dyn Iterator<Item = PathEvent>,
+         opts: path::Options,
          color: Option<LinSrgba>,
          theme: &draw::Theme,
          fill_tessellator: &mut lyon::tessellation::FillTessellator,
          stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
             path::render_path_events(
-                events(),
+                events,
                 color,
                 transform,
                 opts,
@@ -220,8 +379,10 @@ pub fn render_events_themed<F, I>(
 
     // Do the fill tessellation first.
     if !no_fill {
-        let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
+        let opts = path::Options::Fill(lyon::tessellation::FillOptions::default())
+            .with_tolerance(ctxt.tolerance);
         render(
+            &mut events(),
             opts,
             color,
             &ctxt.theme,
@@ -232,9 +393,24 @@ pub fn render_events_themed<F, I>(
 
     // Do the stroke tessellation on top.
     if let Some(stroke_opts) = stroke {
-        let opts = path::Options::Stroke(stroke_opts);
+        let tolerance = stroke_tolerance.or(ctxt.tolerance);
+        let opts = path::Options::Stroke(stroke_opts).with_tolerance(tolerance);
         let color = stroke_color;
+        let mut aligned_events;
+        let mut centred_events;
+        let events: &mut dyn Iterator<Item = PathEvent> = if stroke_align == StrokeAlign::Center {
+            centred_events = events();
+            &mut centred_events
+        } else {
+            let tolerance = path::outline_tolerance(ctxt.tolerance);
+            let mut outlines = vec![];
+            path::flatten_path_events(events(), Mat4::IDENTITY, tolerance, &mut outlines);
+            let weight = stroke_opts.line_width;
+            aligned_events = aligned_stroke_events(outlines, stroke_align, weight).into_iter();
+            &mut aligned_events
+        };
         render(
+            events,
             opts,
             color,
             &ctxt.theme,
@@ -279,9 +455,35 @@ impl Polygon {
                     stroke_color,
                     color,
                     stroke,
+                    stroke_tolerance,
+                    stroke_align,
+                    hatch,
                 },
             texture_view,
         } = self;
+
+        // Collect the outline if requested.
+        let mut ctxt = ctxt;
+        if let Some(mut outlines) = ctxt.outlines.take() {
+            let transform = *ctxt.transform * position.transform() * orientation.transform();
+            let start = outlines.len();
+            path::flatten_path_event_source(&path_event_src, &ctxt, transform, &mut outlines);
+            push_hatching(&mut outlines, start, hatch.filter(|_| !no_fill));
+        }
+
+        // Offset the outline for strokes that are not centred. Textured strokes are always
+        // centred as the offset outline has no texture coordinates.
+        let aligned_stroke = match (&stroke, &path_event_src) {
+            (_, PathEventSource::TexturedPoints { .. }) => None,
+            (Some(stroke_opts), src) if stroke_align != StrokeAlign::Center => {
+                let mut outlines = vec![];
+                path::flatten_path_event_source(src, &ctxt, Mat4::IDENTITY, &mut outlines);
+                let weight = stroke_opts.line_width;
+                Some(aligned_stroke_events(outlines, stroke_align, weight))
+            }
+            _ => None,
+        };
+
         let draw::renderer::RenderContext {
             fill_tessellator,
             stroke_tessellator,
@@ -290,6 +492,7 @@ impl Polygon {
             path_points_textured_buffer,
             transform,
             theme,
+            tolerance,
             ..
         } = ctxt;
 
@@ -321,7 +524,8 @@ impl Polygon {
 
         // Do the fill tessellation first.
         if !no_fill {
-            let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
+            let opts = path::Options::Fill(lyon::tessellation::FillOptions::default())
+                .with_tolerance(tolerance);
             match path_event_src {
                 PathEventSource::Buffered(ref range) => {
                     let mut events = path_event_buffer[range.clone()].iter().cloned();
@@ -367,14 +571,29 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
+                PathEventSource::WeightedPoints { .. } => {
+                    unreachable!("polygons are never built from weighted points")
+                }
             }
         }
 
         // Then the the stroked outline.
         if let Some(stroke_opts) = stroke {
-            let opts = path::Options::Stroke(stroke_opts);
-            match path_event_src {
-                PathEventSource::Buffered(range) => {
+            let tolerance = stroke_tolerance.or(tolerance);
+            let opts = path::Options::Stroke(stroke_opts).with_tolerance(tolerance);
+            match (path_event_src, aligned_stroke) {
+                (_, Some(events)) => {
+                    let src = path::PathEventSourceIter::Events(&mut events.into_iter());
+                    render(
+                        src,
+                        opts,
+                        stroke_color,
+                        theme,
+                        fill_tessellator,
+                        stroke_tessellator,
+                    );
+                }
+                (PathEventSource::Buffered(range), None) => {
                     let mut events = path_event_buffer[range].iter().cloned();
                     let src = path::PathEventSourceIter::Events(&mut events);
                     render(
@@ -386,7 +605,7 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
-                PathEventSource::ColoredPoints { range, close } => {
+                (PathEventSource::ColoredPoints { range, close }, None) => {
                     let color =
                         stroke_color.unwrap_or_else(|| theme.stroke_lin_srgba(theme_primitive));
                     let mut points_colored = path_points_colored_buffer[range]
@@ -406,7 +625,7 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
-                PathEventSource::TexturedPoints { range, close } => {
+                (PathEventSource::TexturedPoints { range, close }, None) => {
                     let mut textured_points = path_points_textured_buffer[range].iter().cloned();
                     let src = path::PathEventSourceIter::TexturedPoints {
                         points: &mut textured_points,
@@ -421,6 +640,9 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
+                (PathEventSource::WeightedPoints { .. }, None) => {
+                    unreachable!("polygons are never built from weighted points")
+                }
             }
         }
 
@@ -429,11 +651,98 @@ impl Polygon {
             Some(texture_view) => draw::renderer::PrimitiveRender {
                 texture_view: Some(texture_view),
                 vertex_mode: draw::renderer::VertexMode::Texture,
+                sampler: None,
+                color_vertex_count: 0,
             },
         }
     }
 }
 
+// Append the hatching of the outlines collected from `start` onwards, if any.
+fn push_hatching(outlines: &mut Vec<Vec<Vec2>>, start: usize, hatch: Option<Hatch>) {
+    if let Some(Hatch { angle, spacing }) = hatch {
+        let lines = draw::plotter::hatch(&outlines[start..], angle, spacing);
+        outlines.extend(lines);
+    }
+}
+
+// Offset each closed outline by half of the stroke weight in the direction described by `align`,
+// producing the path events along which the stroke should be tessellated.
+fn aligned_stroke_events(
+    outlines: Vec<Vec<Vec2>>,
+    align: StrokeAlign,
+    weight: f32,
+) -> Vec<PathEvent> {
+    // Limit the length of offset corners relative to the offset, as with a miter limit.
+    const MITER_LIMIT: f32 = 4.0;
+    let offset = match align {
+        StrokeAlign::Inner => -weight * 0.5,
+        StrokeAlign::Center => 0.0,
+        StrokeAlign::Outer => weight * 0.5,
+    };
+    let outlines: Vec<_> = outlines
+        .into_iter()
+        .map(|mut outline| {
+            outline.dedup();
+            let close = outline.len() > 2 && outline.first() == outline.last();
+            if close {
+                outline.pop();
+            }
+            (outline, close)
+        })
+        .collect();
+    // Polygons are filled with the even-odd rule, so closed outlines lying within an odd number of
+    // the other closed outlines are holes.
+    let holes: Vec<bool> = outlines
+        .iter()
+        .enumerate()
+        .map(|(index, (outline, close))| {
+            let containing = outlines
+                .iter()
+                .enumerate()
+                .filter(|&(other_index, (other, other_close))| {
+                    other_index != index
+                        && *other_close
+                        && geom::point_in_polygon(outline[0], other)
+                })
+                .count();
+            *close && containing % 2 == 1
+        })
+        .collect();
+    let mut events = vec![];
+    for ((mut outline, close), hole) in outlines.into_iter().zip(holes) {
+        if close && offset != 0.0 {
+            // The outward normal lies to the right of each edge for counter-clockwise outlines,
+            // and to the left for holes.
+            let len = outline.len();
+            let area: f32 = (0..len)
+                .map(|i| outline[i].perp_dot(outline[(i + 1) % len]))
+                .sum();
+            let outward = if (area > 0.0) != hole { 1.0 } else { -1.0 };
+            let normal = |a: Vec2, b: Vec2| -(b - a).normalize_or_zero().perp() * outward;
+            outline = (0..len)
+                .map(|i| {
+                    let (prev, p, next) = (
+                        outline[(i + len - 1) % len],
+                        outline[i],
+                        outline[(i + 1) % len],
+                    );
+                    let (n1, n2) = (normal(prev, p), normal(p, next));
+                    let miter = (n1 + n2).normalize_or_zero();
+                    let cos = miter.dot(n1);
+                    if cos <= 0.0 {
+                        return p + n1 * offset;
+                    }
+                    p + miter * offset / cos.max(1.0 / MITER_LIMIT)
+                })
+                .collect();
+        }
+        let points = outline.iter().map(|p| lyon::math::point(p.x, p.y));
+        events.extend(lyon::path::iterator::FromPolyline::new(close, points));
+    }
+    events
+}
+
 impl draw::renderer::RenderPrimitive for Polygon {
     fn render_primitive(
         self,
@@ -454,6 +763,13 @@ where
         self.map_ty(|ty| ty.no_fill())
     }
 
+    /// Specify no stroke and in turn no stroke tessellation for the polygon.
+    ///
+    /// This clears any stroke color and stroke options set so far.
+    pub fn no_stroke(self) -> Self {
+        self.map_ty(|ty| ty.no_stroke())
+    }
+
     /// Specify a color to use for stroke tessellation.
     ///
     /// Stroke tessellation will only be performed if this method or one of the `SetStroke` methods
@@ -465,6 +781,22 @@ where
         self.map_ty(|ty| ty.stroke_color(color))
     }
 
+    /// Specify where the stroke lies relative to the outline of the polygon.
+    ///
+    /// By default, the stroke is centred on the outline. See **StrokeAlign** for details.
+    pub fn stroke_align(self, align: StrokeAlign) -> Self {
+        self.map_ty(|ty| ty.stroke_align(align))
+    }
+
+    /// Fill the polygon with parallel lines within the output of `Draw::to_plotter_paths`.
+    ///
+    /// Lines are produced at the given `angle` in radians and `spacing` apart in the coordinate
+    /// space of the output. Hatching is only produced for filled polygons and does not affect
+    /// rendering. See **Hatch** for details.
+    pub fn hatch(self, angle: f32, spacing: f32) -> Self {
+        self.map_ty(|ty| ty.hatch(angle, spacing))
+    }
+
     /// Specify the whole set of polygon options.
     pub fn polygon_options(self, opts: PolygonOptions) -> Self {
         self.map_ty(|ty| ty.polygon_options(opts))
@@ -497,6 +829,43 @@ impl<'a> DrawingPolygonInit<'a> {
         self.map_ty_with_context(|ty, ctxt| ty.points(ctxt, points))
     }
 
+    /// Describe a regular polygon with the given number of sides, centred on the origin.
+    ///
+    /// The first vertex lies at `radius` along the *y* axis so that the polygon points up. Use the
+    /// `SetOrientation` methods to rotate it. `sides` is clamped to a minimum of `3`.
+    pub fn regular(self, sides: usize, radius: f32) -> DrawingPolygon<'a> {
+        self.map_ty_with_context(|ty, ctxt| ty.regular(ctxt, sides, radius))
+    }
+
+    /// Describe a star with the given number of points, centred on the origin.
+    ///
+    /// Vertices alternate between `outer_radius` and `inner_radius`, starting with an outer vertex
+    /// along the *y* axis so that the star points up. `points` is clamped to a minimum of `2` and
+    /// `inner_radius` is clamped to the range `0.0..=outer_radius`.
+    pub fn star(self, points: usize, inner_radius: f32, outer_radius: f32) -> DrawingPolygon<'a> {
+        self.map_ty_with_context(|ty, ctxt| ty.star(ctxt, points, inner_radius, outer_radius))
+    }
+
+    /// Describe a gear with the given number of teeth, centred on the origin.
+    ///
+    /// The tips of the teeth lie at `outer_radius` and their roots `tooth_depth` closer to the
+    /// centre, with a tooth along the *y* axis. If `inner_radius` is greater than `0.0`, a hole of
+    /// that radius is cut from the centre of the gear.
+    ///
+    /// `teeth` is clamped to a minimum of `3`, `tooth_depth` to the range `0.0..=outer_radius` and
+    /// `inner_radius` to the range between `0.0` and the root of the teeth.
+    pub fn gear(
+        self,
+        teeth: usize,
+        inner_radius: f32,
+        outer_radius: f32,
+        tooth_depth: f32,
+    ) -> DrawingPolygon<'a> {
+        self.map_ty_with_context(|ty, ctxt| {
+            ty.gear(ctxt, teeth, inner_radius, outer_radius, tooth_depth)
+        })
+    }
+
     /// Consumes an iterator of points and converts them to an iterator yielding path events.
     pub fn points_colored<I, P, C>(self, points: I) -> DrawingPolygon<'a>
     where
@@ -522,6 +891,12 @@ impl<'a> DrawingPolygonInit<'a> {
     }
 }
 
+impl Default for StrokeAlign {
+    fn default() -> Self {
+        StrokeAlign::Center
+    }
+}
+
 impl SetPolygon for PolygonOptions {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         self
@@ -556,6 +931,10 @@ impl SetStroke for PolygonInit {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.opts.stroke)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        Some(&mut self.opts.stroke_tolerance)
+    }
 }
 
 impl SetOrientation for Polygon {
@@ -605,3 +984,95 @@ impl Into<Option<Polygon>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_no_fill_no_stroke() {
+    let draw = draw::Draw::new();
+    let outline = draw
+        .ellipse()
+        .w_h(100.0, 100.0)
+        .no_fill()
+        .stroke_weight(4.0)
+        .finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 0.0)), None);
+    assert_eq!(draw.hit_test(pt2(51.0, 0.0)), Some(outline));
+
+    let draw = draw::Draw::new();
+    let filled = draw
+        .ellipse()
+        .w_h(100.0, 100.0)
+        .stroke_weight(4.0)
+        .no_stroke()
+        .finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 0.0)), Some(filled));
+    assert_eq!(draw.hit_test(pt2(52.5, 0.0)), None);
+}
+
+#[test]
+fn test_regular() {
+    let draw = draw::Draw::new();
+    let hexagon = draw.polygon().regular(6, 50.0).finish();
+    // The hexagon points up, so its left and right edges are flat.
+    assert_eq!(draw.hit_test(pt2(0.0, 48.0)), Some(hexagon));
+    assert_eq!(draw.hit_test(pt2(40.0, 0.0)), Some(hexagon));
+    assert_eq!(draw.hit_test(pt2(45.0, 0.0)), None);
+}
+
+#[test]
+fn test_star_and_gear() {
+    let draw = draw::Draw::new();
+    let star = draw.star(5, 20.0, 50.0).finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 45.0)), Some(star));
+    // An inner vertex lies directly below the centre.
+    assert_eq!(draw.hit_test(pt2(0.0, -15.0)), Some(star));
+    assert_eq!(draw.hit_test(pt2(0.0, -25.0)), None);
+
+    let draw = draw::Draw::new();
+    let gear = draw.gear(8, 10.0, 50.0, 10.0).finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 0.0)), None);
+    assert_eq!(draw.hit_test(pt2(0.0, 20.0)), Some(gear));
+    assert_eq!(draw.hit_test(pt2(0.0, 48.0)), Some(gear));
+    // Between two teeth.
+    let gap = std::f32::consts::FRAC_PI_2 + std::f32::consts::TAU / 16.0;
+    assert_eq!(draw.hit_test(pt2(gap.cos() * 45.0, gap.sin() * 45.0)), None);
+}
+
+#[test]
+fn test_stroke_align() {
+    let draw = draw::Draw::new();
+    let outer = draw
+        .rect()
+        .w_h(100.0, 100.0)
+        .no_fill()
+        .stroke_weight(10.0)
+        .stroke_align(StrokeAlign::Outer)
+        .finish();
+    assert_eq!(draw.hit_test(pt2(55.0, 0.0)), Some(outer));
+    assert_eq!(draw.hit_test(pt2(45.0, 0.0)), None);
+
+    // Clockwise outlines are offset in the same direction.
+    let draw = draw::Draw::new();
+    let points = [(-50.0, -50.0), (-50.0, 50.0), (50.0, 50.0), (50.0, -50.0)];
+    let inner = draw
+        .polygon()
+        .no_fill()
+        .stroke_weight(10.0)
+        .stroke_align(StrokeAlign::Inner)
+        .points(points.iter().cloned())
+        .finish();
+    assert_eq!(draw.hit_test(pt2(45.0, 0.0)), Some(inner));
+    assert_eq!(draw.hit_test(pt2(55.0, 0.0)), None);
+
+    // The inner stroke of a hole lies outside of the hole.
+    let draw = draw::Draw::new();
+    let gear = draw
+        .polygon()
+        .no_fill()
+        .stroke_weight(4.0)
+        .stroke_align(StrokeAlign::Inner)
+        .gear(8, 10.0, 50.0, 10.0)
+        .finish();
+    assert_eq!(draw.hit_test(pt2(0.0, 12.0)), Some(gear));
+    assert_eq!(draw.hit_test(pt2(0.0, 8.0)), None);
+    assert_eq!(draw.hit_test(pt2(0.0, 48.0)), Some(gear));
+}
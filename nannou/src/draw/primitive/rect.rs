@@ -121,6 +121,10 @@ impl SetStroke for Rect {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.polygon)
+    }
 }
 
 impl SetPolygon for Rect {
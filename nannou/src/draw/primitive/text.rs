@@ -24,12 +24,19 @@ pub struct Style {
     pub color: Option<LinSrgba>,
     pub glyph_colors: Vec<LinSrgba>, // Overrides `color` if non-empty.
     pub layout: text::layout::Builder,
+    /// If `Some`, a rect of this color is drawn behind the glyphs.
+    pub background_color: Option<LinSrgba>,
+    /// The distance by which the background extends beyond the glyphs on each side.
+    pub padding: Scalar,
 }
 
 /// The drawing context for the **Text** primitive.
 pub type DrawingText<'a> = Drawing<'a, Text>;
 
 impl Text {
+    /// The width and height of the rect in which text is laid out when unspecified.
+    pub const DEFAULT_W_H: Scalar = 200.0;
+
     /// Begin drawing some text.
     pub fn new(ctxt: DrawingContext, text: &str) -> Self {
         let start = ctxt.text_buffer.len();
@@ -156,6 +163,49 @@ impl Text {
         self.style.glyph_colors = colors;
         self
     }
+
+    /// Draw a rect of the given color behind the glyphs.
+    ///
+    /// The rect covers the bounds of the laid out text, extended by the `padding`.
+    pub fn background_color(mut self, color: LinSrgba) -> Self {
+        self.style.background_color = Some(color);
+        self
+    }
+
+    /// The distance by which the background extends beyond the glyphs on each side.
+    pub fn padding(mut self, padding: Scalar) -> Self {
+        self.style.padding = padding;
+        self
+    }
+
+    /// The bounds of the laid out text extended by the `padding`, relative to the text's position.
+    ///
+    /// The given `text_buffer` must be the buffer of the **Draw** instance used to create the
+    /// **Text**. See `DrawingText::bounds` for a more convenient alternative.
+    pub fn bounds(&self, text_buffer: &str) -> geom::Rect {
+        self.layout_text(text_buffer)
+            .bounding_rect()
+            .pad(-self.style.padding)
+    }
+
+    // Lay out the text within the rect described by the spatial dimensions.
+    fn layout_text<'b>(&self, text_buffer: &'b str) -> text::Text<'b> {
+        let (maybe_x, maybe_y, maybe_z) = (
+            self.spatial.dimensions.x,
+            self.spatial.dimensions.y,
+            self.spatial.dimensions.z,
+        );
+        assert!(
+            maybe_z.is_none(),
+            "z dimension support for text is unimplemented"
+        );
+        let w = maybe_x.unwrap_or(Self::DEFAULT_W_H);
+        let h = maybe_y.unwrap_or(Self::DEFAULT_W_H);
+        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
+        let layout = self.style.layout.clone().build();
+        let text_str = &text_buffer[self.text.clone()];
+        text::text(text_str).layout(&layout).build(rect)
+    }
 }
 
 impl<'a> DrawingText<'a> {
@@ -257,6 +307,32 @@ impl<'a> DrawingText<'a> {
 
         self.map_ty(|ty| ty.glyph_colors(glyph_colors))
     }
+
+    /// Draw a rect of the given color behind the glyphs.
+    ///
+    /// The rect covers the bounds of the laid out text, extended by the `padding`.
+    pub fn background_color<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let color = color.into_lin_srgba();
+        self.map_ty(|ty| ty.background_color(color))
+    }
+
+    /// The distance by which the background extends beyond the glyphs on each side.
+    pub fn padding(self, padding: Scalar) -> Self {
+        self.map_ty(|ty| ty.padding(padding))
+    }
+
+    /// The bounds of the laid out text extended by the `padding`.
+    ///
+    /// The returned rect is relative to the text's position, i.e. it is centred on the origin for
+    /// center-justified, middle-aligned text. This is useful for positioning other graphics around
+    /// the text.
+    pub fn bounds(&self) -> geom::Rect {
+        self.with_ty_and_text_buffer(|text, text_buffer| text.bounds(text_buffer))
+            .unwrap_or_else(|| geom::Rect::from_w_h(0.0, 0.0))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Text {
@@ -265,34 +341,17 @@ impl draw::renderer::RenderPrimitive for Text {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Text {
-            spatial,
-            style,
-            text,
-        } = self;
+        let text = self.layout_text(ctxt.text_buffer);
+        let Text { spatial, style, .. } = self;
         let Style {
             color,
             glyph_colors,
-            layout,
+            background_color,
+            padding,
+            ..
         } = style;
-        let layout = layout.build();
-        let (maybe_x, maybe_y, maybe_z) = (
-            spatial.dimensions.x,
-            spatial.dimensions.y,
-            spatial.dimensions.z,
-        );
-        assert!(
-            maybe_z.is_none(),
-            "z dimension support for text is unimplemented"
-        );
-        let w = maybe_x.unwrap_or(200.0);
-        let h = maybe_y.unwrap_or(200.0);
-        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
         let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
 
-        let text_str = &ctxt.text_buffer[text.clone()];
-        let text = text::text(text_str).layout(&layout).build(rect);
-
         // Queue the glyphs to be cached
         let font_id = text::font::id(text.font());
         let positioned_glyphs: Vec<_> = text
@@ -368,6 +427,26 @@ impl draw::renderer::RenderPrimitive for Text {
             // Repeat `color` if more glyphs than glyph_colors
             .chain(std::iter::repeat(&color));
 
+        // Insert the background first so that it is drawn behind the glyphs.
+        let mut render = draw::renderer::PrimitiveRender::text();
+        if let Some(bg_color) = background_color {
+            let rect = text.bounding_rect().pad(-padding);
+            let v = |p: Point2| -> draw::mesh::Vertex {
+                let p = transform.transform_point3([p.x, p.y, 0.0].into());
+                let tex_coords = draw::mesh::vertex::default_tex_coords();
+                draw::mesh::vertex::new(p, bg_color, tex_coords)
+            };
+            let start_ix = mesh.points().len() as u32;
+            mesh.push_vertex(v(rect.top_left()));
+            mesh.push_vertex(v(rect.bottom_left()));
+            mesh.push_vertex(v(rect.bottom_right()));
+            mesh.push_vertex(v(rect.top_right()));
+            for &ix in &[0, 1, 2, 0, 2, 3] {
+                mesh.push_index(start_ix + ix);
+            }
+            render.color_vertex_count = 4;
+        }
+
         // Extend the mesh with a rect for each displayed glyph.
         for (g, g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
             if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), &g)
@@ -412,7 +491,7 @@ impl draw::renderer::RenderPrimitive for Text {
             }
         }
 
-        draw::renderer::PrimitiveRender::text()
+        render
     }
 }
 
@@ -456,3 +535,40 @@ impl Into<Option<Text>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_text_background() {
+    use crate::color::RED;
+    let draw = draw::Draw::new();
+    let bounds = draw.text("hello").padding(10.0).bounds();
+    assert!(bounds.w() > 20.0 && bounds.h() > 20.0);
+    assert!(bounds.contains(geom::pt2(0.0, 0.0)));
+
+    // Without a background, points within the padding are not covered.
+    let inside_padding = geom::pt2(0.0, bounds.top() - 5.0);
+    draw.text("hello").padding(10.0);
+    assert_eq!(draw.hit_test(inside_padding), None);
+    let bg = draw
+        .text("hello")
+        .background_color(RED)
+        .padding(10.0)
+        .finish();
+    assert_eq!(draw.hit_test(inside_padding), Some(bg));
+    assert_eq!(draw.hit_test(geom::pt2(0.0, bounds.top() + 5.0)), None);
+}
+
+#[test]
+fn test_measure() {
+    let draw = draw::Draw::new();
+    let s = "the quick brown fox jumps over the lazy dog";
+    let default = text::measure(s, &Layout::default(), Text::DEFAULT_W_H);
+    assert_eq!(default, draw.text(s).bounds().wh());
+
+    // The layout and wrap width are respected.
+    let layout = text::layout::Builder::default().font_size(24).build();
+    let wrapped = text::measure(s, &layout, 100.0);
+    assert_eq!(wrapped, draw.text(s).layout(&layout).w(100.0).bounds().wh());
+    assert!(wrapped.x <= 100.0);
+    let unwrapped = text::measure(s, &layout, 1000.0);
+    assert!(unwrapped.x > wrapped.x && unwrapped.y < wrapped.y);
+}
@@ -13,6 +13,20 @@ pub struct Texture {
     texture_view: wgpu::TextureView,
     spatial: spatial::Properties,
     area: geom::Rect,
+    filter: Option<wgpu::FilterMode>,
+    address_mode: Option<wgpu::AddressMode>,
+    nine_slice: Option<Insets>,
+}
+
+/// The distance from each edge of a texture to the inner region of a nine-slice, in pixels.
+///
+/// See `nine_slice`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Insets {
+    pub left: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub top: f32,
 }
 
 /// The drawing context for a Rect.
@@ -40,10 +54,30 @@ impl Texture {
             texture_view,
             spatial,
             area,
+            filter: None,
+            address_mode: None,
+            nine_slice: None,
         }
     }
 }
 
+impl Insets {
+    /// Insets with the given distance from each edge.
+    pub fn new(left: f32, right: f32, bottom: f32, top: f32) -> Self {
+        Insets {
+            left,
+            right,
+            bottom,
+            top,
+        }
+    }
+
+    /// Insets with the same distance from all edges.
+    pub fn uniform(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
 impl Texture {
     /// Specify the area of the texture to draw.
     ///
@@ -58,6 +92,39 @@ impl Texture {
         self.area = rect;
         self
     }
+
+    /// Specify the filter used when magnifying or minifying the texture.
+    ///
+    /// Use `wgpu::FilterMode::Nearest` for crisp pixel art. By default, the filter of the **Draw**
+    /// context's sampler is used.
+    pub fn filter(mut self, filter: wgpu::FilterMode) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Specify how texture coordinates outside of the range `0.0..=1.0` are handled.
+    ///
+    /// Use `wgpu::AddressMode::Repeat` along with an `area` larger than the texture to tile it. By
+    /// default, the address mode of the **Draw** context's sampler is used.
+    pub fn address_mode(mut self, mode: wgpu::AddressMode) -> Self {
+        self.address_mode = Some(mode);
+        self
+    }
+
+    /// Draw the texture as a nine-slice (aka nine-patch).
+    ///
+    /// The texture is divided into a grid of nine regions by the given insets, specified in pixels
+    /// of the source texture. The corners are drawn at their original size, the edges are
+    /// stretched along one axis and the center is stretched along both to fill the dimensions of
+    /// the drawing. This is useful for scaling bordered panels and buttons without distorting the
+    /// border.
+    ///
+    /// If the drawing is smaller than the sum of two opposing insets, the corners are scaled down
+    /// to fit. The insets apply within the specified `area` of the texture.
+    pub fn nine_slice(mut self, insets: Insets) -> Self {
+        self.nine_slice = Some(insets);
+        self
+    }
 }
 
 impl<'a> DrawingTexture<'a> {
@@ -73,6 +140,36 @@ impl<'a> DrawingTexture<'a> {
     pub fn area(self, rect: geom::Rect) -> Self {
         self.map_ty(|ty| ty.area(rect))
     }
+
+    /// Specify the filter used when magnifying or minifying the texture.
+    ///
+    /// Use `wgpu::FilterMode::Nearest` for crisp pixel art. By default, the filter of the **Draw**
+    /// context's sampler is used.
+    pub fn filter(self, filter: wgpu::FilterMode) -> Self {
+        self.map_ty(|ty| ty.filter(filter))
+    }
+
+    /// Specify how texture coordinates outside of the range `0.0..=1.0` are handled.
+    ///
+    /// Use `wgpu::AddressMode::Repeat` along with an `area` larger than the texture to tile it. By
+    /// default, the address mode of the **Draw** context's sampler is used.
+    pub fn address_mode(self, mode: wgpu::AddressMode) -> Self {
+        self.map_ty(|ty| ty.address_mode(mode))
+    }
+
+    /// Draw the texture as a nine-slice (aka nine-patch).
+    ///
+    /// The texture is divided into a grid of nine regions by the given insets, specified in pixels
+    /// of the source texture. The corners are drawn at their original size, the edges are
+    /// stretched along one axis and the center is stretched along both to fill the dimensions of
+    /// the drawing. This is useful for scaling bordered panels and buttons without distorting the
+    /// border.
+    ///
+    /// If the drawing is smaller than the sum of two opposing insets, the corners are scaled down
+    /// to fit. The insets apply within the specified `area` of the texture.
+    pub fn nine_slice(self, insets: Insets) -> Self {
+        self.map_ty(|ty| ty.nine_slice(insets))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Texture {
@@ -85,6 +182,9 @@ impl draw::renderer::RenderPrimitive for Texture {
             texture_view,
             spatial,
             area,
+            filter,
+            address_mode,
+            nine_slice,
         } = self;
         let spatial::Properties {
             dimensions,
@@ -107,27 +207,98 @@ impl draw::renderer::RenderPrimitive for Texture {
         let local_transform = position.transform() * orientation.transform();
         let transform = global_transform * local_transform;
 
-        // Create an iterator yielding texture points.
-        let points_textured = rect
-            .corners()
-            .vertices()
-            .map(Vec2::from)
-            .zip(area.invert_y().corners().vertices().map(Vec2::from));
-
-        path::render_path_points_textured(
-            points_textured,
-            true,
-            transform,
-            path::Options::Fill(Default::default()),
-            &mut ctxt.fill_tessellator,
-            &mut ctxt.stroke_tessellator,
-            mesh,
-        );
+        // Pair each region of the drawing with its region of the texture.
+        let area = area.invert_y();
+        let regions: Vec<(geom::Rect, geom::Rect)> = match nine_slice {
+            None => vec![(rect, area)],
+            Some(insets) => {
+                let [tex_w, tex_h] = texture_view.size();
+                let (tex_w, tex_h) = (tex_w as f32, tex_h as f32);
+                let xs = slice_edges(rect.x, insets.left, insets.right);
+                let ys = slice_edges(rect.y, insets.bottom, insets.top);
+                let (tex_l, tex_r) = (insets.left / tex_w, insets.right / tex_w);
+                let (tex_b, tex_t) = (insets.bottom / tex_h, insets.top / tex_h);
+                let tex_xs = slice_edges(area.x, tex_l, tex_r);
+                let tex_ys = slice_edges(area.y, tex_b, tex_t);
+                let mut regions = Vec::with_capacity(9);
+                for j in 0..3 {
+                    for i in 0..3 {
+                        let range =
+                            |edges: [f32; 4], i: usize| geom::Range::new(edges[i], edges[i + 1]);
+                        let region = geom::Rect {
+                            x: range(xs, i),
+                            y: range(ys, j),
+                        };
+                        let tex_region = geom::Rect {
+                            x: range(tex_xs, i),
+                            y: range(tex_ys, j),
+                        };
+                        regions.push((region, tex_region));
+                    }
+                }
+                regions
+            }
+        };
+
+        for (region, tex_region) in regions {
+            // Create an iterator yielding texture points.
+            let points_textured = region
+                .corners()
+                .vertices()
+                .map(Vec2::from)
+                .zip(tex_region.corners().vertices().map(Vec2::from));
+
+            path::render_path_points_textured(
+                points_textured,
+                true,
+                transform,
+                path::Options::Fill(Default::default()),
+                &mut ctxt.fill_tessellator,
+                &mut ctxt.stroke_tessellator,
+                mesh,
+            );
+        }
 
-        draw::renderer::PrimitiveRender::texture(texture_view)
+        // Override the context's sampler if necessary.
+        let mut render = draw::renderer::PrimitiveRender::texture(texture_view);
+        if filter.is_some() || address_mode.is_some() {
+            let mut sampler = ctxt.sampler.clone();
+            if let Some(filter) = filter {
+                sampler.mag_filter = filter;
+                sampler.min_filter = filter;
+                sampler.mipmap_filter = filter;
+            }
+            if let Some(mode) = address_mode {
+                sampler.address_mode_u = mode;
+                sampler.address_mode_v = mode;
+                sampler.address_mode_w = mode;
+            }
+            render.sampler = Some(sampler);
+        }
+        render
     }
 }
 
+// Divide the range into three by insetting `start` and `end` by the given distances.
+//
+// The insets are scaled down to fit if they would overlap. Empty sub-ranges are returned as-is.
+fn slice_edges(range: geom::Range, start: f32, end: f32) -> [f32; 4] {
+    let len = range.len();
+    let sum = start + end;
+    let scale = if sum > len && sum > 0.0 {
+        len / sum
+    } else {
+        1.0
+    };
+    let dir = if range.end < range.start { -1.0 } else { 1.0 };
+    [
+        range.start,
+        range.start + start * scale * dir,
+        range.end - end * scale * dir,
+        range.end,
+    ]
+}
+
 impl SetOrientation for Texture {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.spatial)
@@ -162,3 +333,12 @@ impl Into<Option<Texture>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_slice_edges() {
+    let range = geom::Range::new(0.0, 100.0);
+    assert_eq!(slice_edges(range, 10.0, 20.0), [0.0, 10.0, 80.0, 100.0]);
+    assert_eq!(slice_edges(range, 100.0, 100.0), [0.0, 50.0, 50.0, 100.0]);
+    let inverted = geom::Range::new(1.0, 0.0);
+    assert_eq!(slice_edges(inverted, 0.25, 0.5), [1.0, 0.75, 0.5, 0.0]);
+}
@@ -11,6 +11,9 @@ use crate::glam::vec2;
 use lyon::tessellation::StrokeOptions;
 
 /// Properties related to drawing a **Tri**.
+///
+/// A **Tri** is a 2D primitive. Its vertices lie on the *xy* plane and only the *x* and *y*
+/// dimensions may be specified. Setting a *z* dimension will cause a panic when rendered.
 #[derive(Clone, Debug)]
 pub struct Tri {
     tri: geom::Tri<Point2>,
@@ -65,6 +68,31 @@ impl<'a> DrawingTri<'a> {
     }
 }
 
+// Scale the triangle about its centroid so that its bounding rect matches the given dimensions.
+//
+// Triangles are flat, so a z dimension cannot be honoured and is rejected rather than silently
+// ignored.
+fn scale_to_dimensions(
+    tri: geom::Tri<Point2>,
+    dimensions: &dimension::Properties,
+) -> geom::Tri<Point2> {
+    let (maybe_x, maybe_y, maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
+    assert!(
+        maybe_z.is_none(),
+        "z dimension support for tri is unimplemented"
+    );
+    if maybe_x.is_none() && maybe_y.is_none() {
+        return tri;
+    }
+    let cuboid = tri.bounding_rect();
+    let centroid = tri.centroid();
+    let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
+    let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
+    let scale = vec2(x_scale, y_scale);
+    let translate = |v: Point2| centroid + ((v - centroid) * scale);
+    tri.map_vertices(translate)
+}
+
 // Trait implementations.
 
 impl draw::renderer::RenderPrimitive for Tri {
@@ -74,25 +102,11 @@ impl draw::renderer::RenderPrimitive for Tri {
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
         let Tri {
-            mut tri,
+            tri,
             dimensions,
             polygon,
         } = self;
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        // If dimensions were specified, scale the points to those dimensions.
-        if maybe_x.is_some() || maybe_y.is_some() {
-            let cuboid = tri.bounding_rect();
-            let centroid = tri.centroid();
-            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
-            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
-            let scale = vec2(x_scale, y_scale);
-            let (a, b, c) = tri.into();
-            let translate = |v: Point2| centroid + ((v - centroid) * scale);
-            let new_a = translate(a);
-            let new_b = translate(b);
-            let new_c = translate(c);
-            tri = geom::Tri([new_a, new_b, new_c]);
-        }
+        let tri = scale_to_dimensions(tri, &dimensions);
         let points = tri.vertices();
         polygon::render_points_themed(
             polygon.opts,
@@ -158,6 +172,10 @@ impl SetStroke for Tri {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
     }
+
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        SetStroke::stroke_tolerance_mut(&mut self.polygon)
+    }
 }
 
 impl SetPolygon for Tri {
@@ -182,3 +200,29 @@ impl Into<Option<Tri>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_scale_to_dimensions() {
+    let tri = Tri::default().tri;
+    let dimensions = dimension::Properties {
+        x: Some(200.0),
+        y: None,
+        z: None,
+    };
+    let scaled = scale_to_dimensions(tri, &dimensions);
+    assert!((scaled.bounding_rect().w() - 200.0).abs() < 1e-3);
+    assert!((scaled.bounding_rect().h() - tri.bounding_rect().h()).abs() < 1e-3);
+    assert!(scaled.centroid().abs_diff_eq(tri.centroid(), 1e-3));
+}
+
+#[test]
+#[should_panic]
+fn test_scale_to_dimensions_rejects_z() {
+    let tri = Tri::default().tri;
+    let dimensions = dimension::Properties {
+        x: None,
+        y: None,
+        z: Some(10.0),
+    };
+    scale_to_dimensions(tri, &dimensions);
+}
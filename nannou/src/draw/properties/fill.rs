@@ -8,15 +8,29 @@ pub trait SetFill: Sized {
     /// Provide a mutable reference to the `FillOptions` field.
     fn fill_options_mut(&mut self) -> &mut FillOptions;
 
+    /// Provide a mutable reference to the tolerance explicitly specified for the node, if tracked.
+    ///
+    /// An explicit tolerance takes precedence over that of the **Draw** (see `Draw::tolerance`).
+    /// By default, explicit tolerances are not tracked and the **Draw**'s tolerance is used.
+    fn fill_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        None
+    }
+
     /// Specify the whole set of fill tessellation options.
     fn fill_opts(mut self, opts: FillOptions) -> Self {
         *self.fill_options_mut() = opts;
+        if let Some(tolerance) = self.fill_tolerance_mut() {
+            *tolerance = Some(opts.tolerance);
+        }
         self
     }
 
     /// Maximum allowed distance to the path when building an approximation.
     fn fill_tolerance(mut self, tolerance: f32) -> Self {
         self.fill_options_mut().tolerance = tolerance;
+        if let Some(explicit) = self.fill_tolerance_mut() {
+            *explicit = Some(tolerance);
+        }
         self
     }
 
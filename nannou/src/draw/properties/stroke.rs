@@ -8,9 +8,20 @@ pub trait SetStroke: Sized {
     /// Provide a mutable reference to the `StrokeOptions` field.
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions;
 
+    /// Provide a mutable reference to the tolerance explicitly specified for the node, if tracked.
+    ///
+    /// An explicit tolerance takes precedence over that of the **Draw** (see `Draw::tolerance`).
+    /// By default, explicit tolerances are not tracked and the **Draw**'s tolerance is used.
+    fn stroke_tolerance_mut(&mut self) -> Option<&mut Option<f32>> {
+        None
+    }
+
     /// Specify the whole set of stroke tessellation options.
     fn stroke_opts(mut self, opts: StrokeOptions) -> Self {
         *self.stroke_options_mut() = opts;
+        if let Some(tolerance) = self.stroke_tolerance_mut() {
+            *tolerance = Some(opts.tolerance);
+        }
         self
     }
 
@@ -147,6 +158,9 @@ pub trait SetStroke: Sized {
     /// Maximum allowed distance to the path when building an approximation.
     fn stroke_tolerance(mut self, tolerance: f32) -> Self {
         self.stroke_options_mut().tolerance = tolerance;
+        if let Some(explicit) = self.stroke_tolerance_mut() {
+            *explicit = Some(tolerance);
+        }
         self
     }
 }
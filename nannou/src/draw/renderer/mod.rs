@@ -15,6 +15,12 @@ use std::ops::{Deref, DerefMut};
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 
 /// Draw API primitives that may be rendered via the **Renderer** type.
+///
+/// Types outside of nannou may implement this trait in order to be drawn as custom primitives via
+/// `Draw::custom`. Rather than allocating their own buffers for path events, points or text, such
+/// primitives may write to the **DrawingContext** buffers within `Draw::custom_with_context` and
+/// store the resulting index ranges, reading them back from the **RenderContext** buffers (e.g.
+/// `&ctxt.path_event_buffer[range]`) during rendering. These buffers are reused between frames.
 pub trait RenderPrimitive {
     /// Render self into the given mesh.
     fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender;
@@ -30,15 +36,31 @@ pub struct PrimitiveRender {
     pub texture_view: Option<wgpu::TextureView>,
     /// The way in which vertices should be coloured in the fragment shader.
     pub vertex_mode: VertexMode,
+    /// The sampler with which the texture should be sampled.
+    ///
+    /// If `None`, the sampler of the **Draw** context is used.
+    pub sampler: Option<wgpu::SamplerDescriptor<'static>>,
+    /// The number of vertices at the start of those submitted by the primitive that should use
+    /// `VertexMode::Color` rather than `vertex_mode`.
+    ///
+    /// This allows for submitting solid geometry alongside text or textures, e.g. the background
+    /// of a **Text** primitive.
+    pub color_vertex_count: usize,
 }
 
 /// The context provided to primitives to assist with the rendering process.
 pub struct RenderContext<'a> {
     pub transform: &'a Mat4,
+    pub sampler: &'a wgpu::SamplerDescriptor<'static>,
+    pub tolerance: Option<f32>,
+    /// If `Some`, path-based primitives append their flattened outlines. See
+    /// `Draw::to_plotter_paths`.
+    pub outlines: Option<&'a mut Vec<Vec<Vec2>>>,
     pub intermediary_mesh: &'a draw::Mesh,
     pub path_event_buffer: &'a [PathEvent],
     pub path_points_colored_buffer: &'a [(Point2, Color)],
     pub path_points_textured_buffer: &'a [(Point2, TexCoords)],
+    pub path_points_weighted_buffer: &'a [(Point2, f32)],
     pub text_buffer: &'a str,
     pub theme: &'a draw::Theme,
     pub glyph_cache: &'a mut GlyphCache,
@@ -89,7 +111,7 @@ pub struct Renderer {
     text_bind_group_layout: wgpu::BindGroupLayout,
     text_bind_group: wgpu::BindGroup,
     texture_samplers: HashMap<SamplerId, wgpu::Sampler>,
-    texture_bind_group_layouts: HashMap<wgpu::TextureSampleType, wgpu::BindGroupLayout>,
+    texture_bind_group_layouts: HashMap<(wgpu::TextureSampleType, bool), wgpu::BindGroupLayout>,
     texture_bind_groups: HashMap<BindGroupId, wgpu::BindGroup>,
     output_color_format: wgpu::TextureFormat,
     sample_count: u32,
@@ -98,6 +120,30 @@ pub struct Renderer {
     mesh: draw::Mesh,
     vertex_mode_buffer: Vec<VertexMode>,
     uniform_buffer: wgpu::Buffer,
+    baked: Option<Baked>,
+}
+
+/// The render commands and vertex data produced for the baseline of a **Draw**.
+///
+/// See `Draw::checkpoint`.
+#[derive(Debug)]
+struct Baked {
+    key: BakeKey,
+    render_commands: Vec<RenderCommand>,
+    mesh: draw::Mesh,
+    vertex_mode_buffer: Vec<VertexMode>,
+    pipeline_ids: HashMap<PipelineId, (wgpu::BlendComponent, wgpu::BlendComponent)>,
+    tex_views: HashMap<wgpu::TextureViewId, wgpu::TextureView>,
+    tex_sampler_combos: HashMap<BindGroupId, PipelineId>,
+    samplers: HashMap<SamplerId, wgpu::SamplerDescriptor<'static>>,
+}
+
+/// Baked vertex data may only be reused for the same baseline and output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BakeKey {
+    checkpoint_id: u64,
+    scale_factor: f32,
+    output_attachment_size: [u32; 2],
 }
 
 /// A type aimed at simplifying construction of a `draw::Renderer`.
@@ -110,7 +156,7 @@ pub struct Builder {
 }
 
 /// Commands that map to wgpu encodable commands.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum RenderCommand {
     /// Change pipeline for the new blend mode and topology.
     SetPipeline(PipelineId),
@@ -163,6 +209,8 @@ struct PipelineId {
     alpha_id: AlphaId,
     topology: wgpu::PrimitiveTopology,
     texture_sample_type: wgpu::TextureSampleType,
+    sampler_filtering: bool,
+    depth_test: bool,
 }
 
 impl Default for PrimitiveRender {
@@ -179,7 +227,10 @@ impl RenderPrimitive for draw::Primitive {
             draw::Primitive::Path(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Polygon(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Tri(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Circle(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Custom(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Ellipse(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Grid(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Quad(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
@@ -206,6 +257,8 @@ impl PrimitiveRender {
         PrimitiveRender {
             texture_view: None,
             vertex_mode,
+            sampler: None,
+            color_vertex_count: 0,
         }
     }
 
@@ -217,6 +270,8 @@ impl PrimitiveRender {
         PrimitiveRender {
             vertex_mode: VertexMode::Texture,
             texture_view: Some(texture_view),
+            sampler: None,
+            color_vertex_count: 0,
         }
     }
 
@@ -324,7 +379,7 @@ impl Builder {
 }
 
 impl GlyphCache {
-    fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
+    pub(crate) fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
         let [w, h] = size;
         let cache = text::GlyphCache::builder()
             .dimensions(w, h)
@@ -454,6 +509,7 @@ impl Renderer {
         let render_commands = vec![];
         let mesh = Default::default();
         let vertex_mode_buffer = vec![];
+        let baked = None;
 
         Self {
             vs_mod,
@@ -479,6 +535,7 @@ impl Renderer {
             mesh,
             vertex_mode_buffer,
             uniform_buffer,
+            baked,
         }
     }
 
@@ -492,7 +549,9 @@ impl Renderer {
     /// Generate a list of `RenderCommand`s from the given **Draw** instance and prepare any
     /// necessary vertex data.
     ///
-    /// Note that the given **Draw** instance will be *drained* of its commands.
+    /// Note that the given **Draw** instance will be *drained* of its commands, other than those of
+    /// any baseline marked via `Draw::checkpoint`. The vertex data produced for the baseline is
+    /// reused by following calls for as long as the baseline and output remain unchanged.
     pub fn fill(
         &mut self,
         device: &wgpu::Device,
@@ -547,19 +606,91 @@ impl Renderer {
         let mut curr_start_index = 0;
         let mut new_tex_views = HashMap::new();
         let mut new_tex_sampler_combos = HashMap::new();
+        let mut new_samplers = HashMap::new();
         // Track whether new commands are required.
         let mut curr_pipeline_id = None;
         let mut curr_scissor = None;
         let mut curr_tex_sampler_id = None;
 
-        // Collect all draw commands to avoid borrow errors.
-        let draw_cmds: Vec<_> = draw.drain_commands().collect();
+        // Reuse the vertex data of the draw's baseline if it was already baked for this output.
+        let bake_key = draw.checkpoint_id().map(|checkpoint_id| BakeKey {
+            checkpoint_id,
+            scale_factor,
+            output_attachment_size,
+        });
+        let reuse_baked = match (&self.baked, bake_key) {
+            (Some(baked), Some(key)) => baked.key == key,
+            _ => false,
+        };
+        let baseline_cmds = match self.baked {
+            Some(ref baked) if reuse_baked => {
+                self.render_commands
+                    .extend(baked.render_commands.iter().cloned());
+                self.mesh.extend_from_slices(
+                    baked.mesh.points(),
+                    baked.mesh.indices(),
+                    baked.mesh.colors(),
+                    baked.mesh.tex_coords(),
+                );
+                self.vertex_mode_buffer
+                    .extend_from_slice(&baked.vertex_mode_buffer);
+                new_pipeline_ids.extend(baked.pipeline_ids.clone());
+                new_tex_views.extend(baked.tex_views.clone());
+                new_tex_sampler_combos.extend(baked.tex_sampler_combos.clone());
+                new_samplers.extend(baked.samplers.clone());
+                curr_start_index = self.mesh.indices().len() as u32;
+                vec![]
+            }
+            _ => draw.baseline_commands(),
+        };
+        if bake_key.is_none() {
+            self.baked = None;
+        }
+
+        // Collect all draw commands to avoid borrow errors. `None` marks the end of a baseline
+        // that requires baking.
+        let bake_marker = if reuse_baked {
+            None
+        } else {
+            bake_key.map(|_| None)
+        };
+        let draw_cmds: Vec<_> = baseline_cmds
+            .into_iter()
+            .map(Some)
+            .chain(bake_marker)
+            .chain(draw.take_dynamic_commands().into_iter().map(Some))
+            .collect();
         let draw_state = draw.state.borrow_mut();
         let intermediary_state = draw_state.intermediary_state.borrow();
         for cmd in draw_cmds {
             match cmd {
-                draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt,
-                draw::DrawCommand::Primitive(prim) => {
+                None => {
+                    // Flush the baseline so that it may be reused independently of later commands.
+                    push_draw_cmd(
+                        &mut curr_start_index,
+                        self.mesh.indices().len() as u32,
+                        &mut self.render_commands,
+                    );
+                    curr_ctxt = draw::Context::default();
+                    curr_pipeline_id = None;
+                    curr_scissor = None;
+                    curr_tex_sampler_id = None;
+                    // The location of glyphs within the glyph cache may change as other text is
+                    // cached, so baselines containing text are tessellated each frame instead.
+                    let has_text = self.vertex_mode_buffer.contains(&VertexMode::Text);
+                    self.baked = bake_key.filter(|_| !has_text).map(|key| Baked {
+                        key,
+                        render_commands: self.render_commands.clone(),
+                        mesh: self.mesh.clone(),
+                        vertex_mode_buffer: self.vertex_mode_buffer.clone(),
+                        pipeline_ids: new_pipeline_ids.clone(),
+                        tex_views: new_tex_views.clone(),
+                        tex_sampler_combos: new_tex_sampler_combos.clone(),
+                        samplers: new_samplers.clone(),
+                    });
+                }
+                Some(draw::DrawCommand::Context(ctxt)) => curr_ctxt = ctxt,
+                Some(draw::DrawCommand::Primitive(prim)) => {
                     // Track the prev index and vertex counts.
                     let prev_index_count = self.mesh.indices().len() as u32;
                     let prev_vert_count = self.mesh.vertex_count();
@@ -571,9 +702,14 @@ impl Renderer {
                         path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
                         path_points_textured_buffer: &intermediary_state
                             .path_points_textured_buffer,
+                        path_points_weighted_buffer: &intermediary_state
+                            .path_points_weighted_buffer,
                         text_buffer: &intermediary_state.text_buffer,
                         theme: &draw_state.theme,
                         transform: &curr_ctxt.transform,
+                        sampler: &curr_ctxt.sampler,
+                        tolerance: curr_ctxt.tolerance,
+                        outlines: None,
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
                         glyph_cache: &mut self.glyph_cache,
@@ -605,23 +741,32 @@ impl Renderer {
                     let texture_sample_type = tex_view.sample_type();
                     new_tex_views.insert(tex_view_id, tex_view);
 
+                    // Primitives may override the sampler of the context.
+                    let sampler = match render.sampler {
+                        Some(sampler) => sampler,
+                        None => curr_ctxt.sampler.clone(),
+                    };
+                    let sampler_id = sampler_descriptor_hash(&sampler);
+                    let sampler_filtering = wgpu::sampler_filtering(&sampler);
+                    new_samplers.insert(sampler_id, sampler);
+
                     // Determine the new current bind group layout ID, pipeline ID, bind group ID
                     // and scissor required for drawing this primitive.
                     let new_pipeline_id = {
                         let color_id = blend_component_hash(&curr_ctxt.blend.color);
                         let alpha_id = blend_component_hash(&curr_ctxt.blend.alpha);
                         let topology = curr_ctxt.topology;
+                        let depth_test = curr_ctxt.depth_test;
                         PipelineId {
                             color_id,
                             alpha_id,
                             topology,
                             texture_sample_type,
+                            sampler_filtering,
+                            depth_test,
                         }
                     };
-                    let new_bind_group_id = {
-                        let sampler_id = sampler_descriptor_hash(&curr_ctxt.sampler);
-                        (sampler_id, tex_view_id)
-                    };
+                    let new_bind_group_id = (sampler_id, tex_view_id);
                     let new_scissor = curr_ctxt.scissor;
 
                     // Determine which have changed and in turn which require submitting new
@@ -646,11 +791,7 @@ impl Renderer {
                         curr_pipeline_id = Some(new_pipeline_id);
                         let color_blend = curr_ctxt.blend.color.clone();
                         let alpha_blend = curr_ctxt.blend.alpha.clone();
-                        let sampler_filtering = wgpu::sampler_filtering(&curr_ctxt.sampler);
-                        new_pipeline_ids.insert(
-                            new_pipeline_id,
-                            (color_blend, alpha_blend, sampler_filtering),
-                        );
+                        new_pipeline_ids.insert(new_pipeline_id, (color_blend, alpha_blend));
                         let cmd = RenderCommand::SetPipeline(new_pipeline_id);
                         self.render_commands.push(cmd);
                     }
@@ -689,7 +830,15 @@ impl Renderer {
                     // Extend the vertex mode channel.
                     let mode = render.vertex_mode;
                     let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
-                    self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+                    let color_vs = render.color_vertex_count.min(new_vs);
+                    let modes = (0..new_vs).map(|i| {
+                        if i < color_vs {
+                            VertexMode::Color
+                        } else {
+                            mode
+                        }
+                    });
+                    self.vertex_mode_buffer.extend(modes);
                 }
             }
         }
@@ -707,14 +856,14 @@ impl Renderer {
         // Clear new combos that we already have.
         new_pipeline_ids.retain(|id, _| !self.pipelines.contains_key(id));
         // Create new render pipelines as necessary.
-        for (new_id, (color_blend, alpha_blend, sampler_filtering)) in new_pipeline_ids {
+        for (new_id, (color_blend, alpha_blend)) in new_pipeline_ids {
             let bind_group_layout = self
                 .texture_bind_group_layouts
-                .entry(new_id.texture_sample_type)
+                .entry((new_id.texture_sample_type, new_id.sampler_filtering))
                 .or_insert_with(|| {
                     create_texture_bind_group_layout(
                         device,
-                        sampler_filtering,
+                        new_id.sampler_filtering,
                         new_id.texture_sample_type,
                     )
                 });
@@ -731,6 +880,7 @@ impl Renderer {
                 color_blend,
                 alpha_blend,
                 new_id.topology,
+                new_id.depth_test,
             );
             self.pipelines.insert(new_id, new_pipeline);
         }
@@ -750,12 +900,15 @@ impl Renderer {
             let sampler = self
                 .texture_samplers
                 .entry(new_sampler_id)
-                .or_insert_with(|| device.create_sampler(&curr_ctxt.sampler));
+                .or_insert_with(|| device.create_sampler(&new_samplers[&new_sampler_id]));
             // Retrieve the texture view.
             let texture_view = &new_tex_views[&new_tex_view_id];
             // Retrieve the associated bind group layout.
-            let bind_group_layout =
-                &self.texture_bind_group_layouts[&pipeline_id.texture_sample_type];
+            let layout_id = (
+                pipeline_id.texture_sample_type,
+                pipeline_id.sampler_filtering,
+            );
+            let bind_group_layout = &self.texture_bind_group_layouts[&layout_id];
             // Create the bind group.
             let bind_group =
                 create_texture_bind_group(device, bind_group_layout, sampler, texture_view);
@@ -1122,7 +1275,13 @@ fn create_render_pipeline(
     color_blend: wgpu::BlendComponent,
     alpha_blend: wgpu::BlendComponent,
     topology: wgpu::PrimitiveTopology,
+    depth_test: bool,
 ) -> wgpu::RenderPipeline {
+    let depth_compare = if depth_test {
+        wgpu::RenderPipelineBuilder::DEFAULT_DEPTH_COMPARE
+    } else {
+        wgpu::CompareFunction::Always
+    };
     let bind_group_layouts = &[uniform_layout, text_layout, texture_layout];
     wgpu::RenderPipelineBuilder::from_layout_descriptor(&bind_group_layouts[..], vs_mod)
         .fragment_shader(fs_mod)
@@ -1134,6 +1293,8 @@ fn create_render_pipeline(
         )
         .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
         .depth_format(depth_format)
+        .depth_write_enabled(depth_test)
+        .depth_compare(depth_compare)
         .sample_count(sample_count)
         .color_blend(color_blend)
         .alpha_blend(alpha_blend)
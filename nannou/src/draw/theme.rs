@@ -26,6 +26,7 @@ pub enum Primitive {
     Arrow,
     Cuboid,
     Ellipse,
+    Grid,
     Line,
     Mesh,
     Path,
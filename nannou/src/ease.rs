@@ -1,4 +1,158 @@
 //! A suite of common interpolation functions often referred to as "easing" and "tweening"
 //! functions. This API is provided by the [pennereq crate](https://docs.rs/pennereq).
+//!
+//! The `pennereq` modules (e.g. `ease::quad::ease_in(t, b, c, d)`) use Robert Penner's original
+//! signatures. For the common case of easing a normalised value, this module also provides
+//! functions such as `quad_in_out(t)` that map `t` within `0.0..=1.0` to an eased value that
+//! begins at `0.0` and ends at `1.0`, along with a small **Tween** type for animating between two
+//! values.
+
+use std::ops::{Add, Mul, Sub};
 
 pub use pennereq::*;
+
+// Produce normalised `in`, `out` and `in_out` functions for the given `pennereq` module.
+macro_rules! normalised {
+    ($module:ident, $name:literal, $ease_in:ident, $ease_out:ident, $ease_in_out:ident) => {
+        #[doc = concat!("The normalised ", $name, " ease in function.")]
+        pub fn $ease_in(t: f32) -> f32 {
+            $module::ease_in(t, 0.0, 1.0, 1.0)
+        }
+
+        #[doc = concat!("The normalised ", $name, " ease out function.")]
+        pub fn $ease_out(t: f32) -> f32 {
+            $module::ease_out(t, 0.0, 1.0, 1.0)
+        }
+
+        #[doc = concat!("The normalised ", $name, " ease in and out function.")]
+        pub fn $ease_in_out(t: f32) -> f32 {
+            $module::ease_in_out(t, 0.0, 1.0, 1.0)
+        }
+    };
+}
+
+normalised!(back, "back", back_in, back_out, back_in_out);
+normalised!(bounce, "bounce", bounce_in, bounce_out, bounce_in_out);
+normalised!(circ, "circular", circ_in, circ_out, circ_in_out);
+normalised!(cubic, "cubic", cubic_in, cubic_out, cubic_in_out);
+normalised!(elastic, "elastic", elastic_in, elastic_out, elastic_in_out);
+normalised!(expo, "exponential", expo_in, expo_out, expo_in_out);
+normalised!(quad, "quadratic", quad_in, quad_out, quad_in_out);
+normalised!(quart, "quartic", quart_in, quart_out, quart_in_out);
+normalised!(quint, "quintic", quint_in, quint_out, quint_in_out);
+normalised!(sine, "sinusoidal", sine_in, sine_out, sine_in_out);
+
+/// The identity easing function, i.e. no easing.
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+/// An animation between two values over some duration with the given easing function.
+///
+/// The duration is in the same unit as the times passed to `value_at`, e.g. seconds when used
+/// with `app.time`. **Tween** works with any type that may be linearly interpolated, including
+/// `f32`, `Vec2` and `Vec3`.
+///
+/// ```
+/// use nannou::ease::{self, Tween};
+///
+/// let tween = Tween::new(0.0, 100.0, 2.0).easing(ease::quad_in_out);
+/// assert_eq!(tween.value_at(0.0), 0.0);
+/// assert_eq!(tween.value_at(1.0), 50.0);
+/// assert_eq!(tween.value_at(3.0), 100.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Tween<T> {
+    /// The value at the start of the tween.
+    pub from: T,
+    /// The value at the end of the tween.
+    pub to: T,
+    /// The duration of the tween.
+    pub duration: f32,
+    /// Maps the linear progress of the tween within `0.0..=1.0` to the eased progress.
+    pub easing: fn(f32) -> f32,
+}
+
+impl<T> Tween<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    /// A linear tween from one value to another over the given duration.
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            easing: linear,
+        }
+    }
+
+    /// Specify the easing function, e.g. `ease::cubic_out`.
+    pub fn easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The progress of the tween at the given time, clamped to `0.0..=1.0` before easing.
+    pub fn progress_at(&self, time: f32) -> f32 {
+        let linear = if self.duration > 0.0 {
+            (time / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        (self.easing)(linear)
+    }
+
+    /// The value of the tween at the given time since it began.
+    ///
+    /// Times before the start and after the end produce `from` and `to` respectively.
+    pub fn value_at(&self, time: f32) -> T {
+        self.from + (self.to - self.from) * self.progress_at(time)
+    }
+}
+
+#[test]
+fn test_normalised_boundaries() {
+    let fns: &[fn(f32) -> f32] = &[
+        back_in,
+        back_out,
+        back_in_out,
+        bounce_in,
+        bounce_out,
+        bounce_in_out,
+        circ_in,
+        circ_out,
+        circ_in_out,
+        cubic_in,
+        cubic_out,
+        cubic_in_out,
+        elastic_in,
+        elastic_out,
+        elastic_in_out,
+        expo_in,
+        expo_out,
+        expo_in_out,
+        quad_in,
+        quad_out,
+        quad_in_out,
+        quart_in,
+        quart_out,
+        quart_in_out,
+        quint_in,
+        quint_out,
+        quint_in_out,
+        sine_in,
+        sine_out,
+        sine_in_out,
+        linear,
+    ];
+    for (i, f) in fns.iter().enumerate() {
+        assert!(f(0.0).abs() < 1e-3, "function {} at 0.0: {}", i, f(0.0));
+        assert!(
+            (f(1.0) - 1.0).abs() < 1e-3,
+            "function {} at 1.0: {}",
+            i,
+            f(1.0)
+        );
+    }
+}
@@ -170,6 +170,14 @@ pub enum WindowEvent {
     /// The window lost focus.
     Unfocused,
 
+    /// The window became entirely hidden from view, e.g. minimized or covered by other windows.
+    ///
+    /// Not all platforms report occlusion.
+    Occluded,
+
+    /// The window is no longer entirely hidden from view.
+    Unoccluded,
+
     /// The window was closed and is no longer stored in the `App`.
     Closed,
 }
@@ -238,6 +246,14 @@ impl WindowEvent {
                 }
             }
 
+            winit::event::WindowEvent::Occluded(b) => {
+                if *b {
+                    Occluded
+                } else {
+                    Unoccluded
+                }
+            }
+
             winit::event::WindowEvent::CursorMoved { position, .. } => {
                 let (x, y) = position.to_logical::<f64>(scale_factor).into();
                 let x = tx(x);
@@ -311,8 +327,7 @@ impl WindowEvent {
             winit::event::WindowEvent::Ime(_)
             | winit::event::WindowEvent::TouchpadMagnify { .. }
             | winit::event::WindowEvent::SmartMagnify { .. }
-            | winit::event::WindowEvent::TouchpadRotate { .. }
-            | winit::event::WindowEvent::Occluded(_) => return None,
+            | winit::event::WindowEvent::TouchpadRotate { .. } => return None,
         };
 
         Some(event)
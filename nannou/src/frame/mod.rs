@@ -8,6 +8,7 @@ use std::sync::Mutex;
 use std::time::Duration;
 
 pub mod raw;
+pub(crate) mod recording;
 
 pub use self::raw::RawFrame;
 
@@ -33,6 +34,8 @@ pub struct RenderData {
     size: [u32; 2],
     // For writing the intermediary linear sRGBA texture to the swap chain texture.
     texture_reshaper: wgpu::TextureReshaper,
+    // The WGSL source of the fragment shader used by the reshaper, if not the default.
+    post_process: Option<String>,
 }
 
 /// Data related to the capturing of a frame.
@@ -40,6 +43,8 @@ pub struct RenderData {
 pub(crate) struct CaptureData {
     // If `Some`, indicates a path to which the current frame should be written.
     pub(crate) next_frame_path: Mutex<Option<PathBuf>>,
+    // If `Some`, every frame is written to the active recording.
+    pub(crate) recording: Mutex<Option<recording::Recording>>,
     // The `TextureCapturer` used to capture the frame.
     pub(crate) texture_capturer: wgpu::TextureCapturer,
 }
@@ -120,6 +125,21 @@ impl<'swap_chain> Frame<'swap_chain> {
             }
         }
 
+        // Check to see if the frame should be written to an active recording.
+        let mut recording_capture = None;
+        if let Ok(mut guard) = capture_data.recording.lock() {
+            if let Some(recording) = guard.as_mut() {
+                let device = raw_frame.device_queue_pair().device();
+                let mut encoder = raw_frame.command_encoder();
+                let snapshot = capture_data.texture_capturer.capture(
+                    device,
+                    &mut *encoder,
+                    &render_data.intermediary_lin_srgba.texture,
+                );
+                recording_capture = Some((recording.next_frame(), snapshot));
+            }
+        }
+
         // Convert the linear sRGBA image to the swapchain image.
         //
         // To do so, we sample the linear sRGBA image and draw it to the swapchain image using
@@ -156,6 +176,34 @@ impl<'swap_chain> Frame<'swap_chain> {
                 eprintln!("timed out while waiting for a worker thread to capture the frame");
             }
         }
+
+        // If the frame is being recorded, submit the asynchronous read.
+        if let Some((writer, snapshot)) = recording_capture {
+            // Keep a handle so that the frame may be skipped if no worker becomes available.
+            let writer = std::sync::Arc::new(Mutex::new(Some(writer)));
+            let callback_writer = writer.clone();
+            let result = snapshot.read(move |result| {
+                let writer = match callback_writer.lock().ok().and_then(|mut w| w.take()) {
+                    Some(writer) => writer,
+                    None => return,
+                };
+                match result {
+                    Err(e) => {
+                        // TODO: Log errors, don't print to stderr.
+                        eprintln!("failed to async read recorded frame: {:?}", e);
+                        writer.skip();
+                    }
+                    Ok(image) => writer.write(image.to_owned()),
+                }
+            });
+            if let Err(wgpu::TextureCapturerAwaitWorkerTimeout(_)) = result {
+                // TODO: Log errors, don't print to stderr.
+                eprintln!("timed out while waiting for a worker thread to record the frame");
+                if let Some(writer) = writer.lock().ok().and_then(|mut w| w.take()) {
+                    writer.skip();
+                }
+            }
+        }
     }
 
     /// The texture to which all graphics should be drawn this frame.
@@ -272,6 +320,7 @@ impl CaptureData {
     pub(crate) fn new(max_jobs: u32, timeout: Option<Duration>) -> Self {
         CaptureData {
             next_frame_path: Default::default(),
+            recording: Default::default(),
             texture_capturer: wgpu::TextureCapturer::new(Some(max_jobs), timeout),
         }
     }
@@ -284,32 +333,58 @@ impl RenderData {
     ///
     /// If `msaa_samples` is greater than 1 a `multisampled` texture will also be created. Otherwise the
     /// a regular non-multisampled image will be created.
+    ///
+    /// If `post_process` is `Some`, the given WGSL fragment shader is used to write the
+    /// intermediary image to the swap chain texture. See `window::Builder::post_process`.
     pub(crate) fn new(
         device: &wgpu::Device,
         swap_chain_dims: [u32; 2],
         swap_chain_format: wgpu::TextureFormat,
         msaa_samples: u32,
+        post_process: Option<&str>,
     ) -> Self {
         let intermediary_lin_srgba =
             create_intermediary_lin_srgba(device, swap_chain_dims, msaa_samples);
+        let src_texture = &intermediary_lin_srgba.texture_view;
         let src_sample_count = 1;
+        let src_sample_type = src_texture.sample_type();
         let swap_chain_sample_count = 1;
-        let texture_reshaper = wgpu::TextureReshaper::new(
-            device,
-            &intermediary_lin_srgba.texture_view,
-            src_sample_count,
-            intermediary_lin_srgba.texture_view.sample_type(),
-            swap_chain_sample_count,
-            swap_chain_format,
-        );
+        let texture_reshaper = match post_process {
+            None => wgpu::TextureReshaper::new(
+                device,
+                src_texture,
+                src_sample_count,
+                src_sample_type,
+                swap_chain_sample_count,
+                swap_chain_format,
+            ),
+            Some(source) => wgpu::TextureReshaper::with_fragment_shader(
+                device,
+                src_texture,
+                src_sample_count,
+                src_sample_type,
+                swap_chain_sample_count,
+                swap_chain_format,
+                wgpu::ShaderModuleDescriptor {
+                    label: Some("nannou_post_process"),
+                    source: wgpu::ShaderSource::Wgsl(source.into()),
+                },
+            ),
+        };
         RenderData {
             intermediary_lin_srgba,
             texture_reshaper,
             size: swap_chain_dims,
             msaa_samples,
+            post_process: post_process.map(str::to_string),
         }
     }
 
+    // The WGSL source of the post-processing fragment shader, if any.
+    pub(crate) fn post_process(&self) -> Option<&str> {
+        self.post_process.as_deref()
+    }
+
     /// A full view into the associated texture.
     ///
     /// See `texture` for details.
@@ -0,0 +1,164 @@
+//! Items related to recording a window's frames to a video file.
+//!
+//! Frames are encoded by an `ffmpeg` child process which must be available on the `PATH`.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+/// An active recording of a window's frames.
+#[derive(Debug)]
+pub(crate) struct Recording {
+    encoder: Arc<Mutex<Encoder>>,
+    next_frame: u64,
+}
+
+/// A handle to the encoder used to write a single captured frame.
+pub(crate) struct FrameWriter {
+    encoder: Arc<Mutex<Encoder>>,
+    index: u64,
+}
+
+// Writes frames to the `ffmpeg` process in order.
+//
+// Frames are read back from the GPU on a pool of worker threads and may complete out of order, so
+// frames are buffered until all of those before them have been written.
+#[derive(Debug)]
+struct Encoder {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    size: [u32; 2],
+    next_frame: u64,
+    pending: BTreeMap<u64, Option<Vec<u8>>>,
+}
+
+impl Recording {
+    /// Spawn an `ffmpeg` process that encodes frames of the given size in pixels to `path`.
+    ///
+    /// The output format is inferred from the extension of the path, e.g. `.mp4` or `.gif`.
+    pub(crate) fn start(path: &Path, fps: f64, [w, h]: [u32; 2]) -> io::Result<Self> {
+        let mut command = Command::new("ffmpeg");
+        command
+            .args(["-y", "-loglevel", "error"])
+            .args(["-f", "rawvideo", "-pixel_format", "rgba"])
+            .arg("-video_size")
+            .arg(format!("{}x{}", w, h))
+            .arg("-framerate")
+            .arg(fps.to_string())
+            .args(["-i", "-"]);
+        let is_gif = path
+            .extension()
+            .map(|ext| ext.eq_ignore_ascii_case("gif"))
+            .unwrap_or(false);
+        if !is_gif {
+            // Most players only support 4:2:0 chroma subsampling, which requires even dimensions.
+            command.args([
+                "-pix_fmt",
+                "yuv420p",
+                "-vf",
+                "pad=ceil(iw/2)*2:ceil(ih/2)*2",
+            ]);
+        }
+        let mut child = command
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()?;
+        let stdin = child.stdin.take();
+        let encoder = Encoder {
+            child,
+            stdin,
+            size: [w, h],
+            next_frame: 0,
+            pending: Default::default(),
+        };
+        Ok(Recording {
+            encoder: Arc::new(Mutex::new(encoder)),
+            next_frame: 0,
+        })
+    }
+
+    /// Reserve the next frame of the recording.
+    pub(crate) fn next_frame(&mut self) -> FrameWriter {
+        let index = self.next_frame;
+        self.next_frame += 1;
+        FrameWriter {
+            encoder: self.encoder.clone(),
+            index,
+        }
+    }
+
+    /// Close the encoder's input and wait for it to finish writing the file.
+    ///
+    /// All frames should have been written or skipped before calling this.
+    pub(crate) fn finish(self) -> io::Result<()> {
+        let mut encoder = self
+            .encoder
+            .lock()
+            .expect("failed to lock recording encoder");
+        encoder.stdin.take();
+        let status = encoder.child.wait()?;
+        if !status.success() {
+            let msg = format!("ffmpeg exited with {}", status);
+            return Err(io::Error::other(msg));
+        }
+        Ok(())
+    }
+}
+
+impl FrameWriter {
+    /// Write the given non-linear RGBA8 image to the recording.
+    pub(crate) fn write(self, image: image::RgbaImage) {
+        let [w, h] = self.size();
+        let data = if image.dimensions() != (w, h) {
+            eprintln!(
+                "skipping recorded frame of size {:?} as the recording size is {:?}",
+                image.dimensions(),
+                (w, h),
+            );
+            None
+        } else {
+            Some(image.into_raw())
+        };
+        self.submit(data);
+    }
+
+    /// Skip this frame, e.g. if it could not be read from the GPU.
+    pub(crate) fn skip(self) {
+        self.submit(None);
+    }
+
+    fn size(&self) -> [u32; 2] {
+        self.encoder
+            .lock()
+            .expect("failed to lock recording encoder")
+            .size
+    }
+
+    fn submit(self, data: Option<Vec<u8>>) {
+        let mut encoder = self
+            .encoder
+            .lock()
+            .expect("failed to lock recording encoder");
+        encoder.pending.insert(self.index, data);
+        if let Err(err) = encoder.flush() {
+            // TODO: Log errors, don't print to stderr.
+            eprintln!("failed to write frame to recording: {}", err);
+        }
+    }
+}
+
+impl Encoder {
+    // Write all pending frames that are next in order.
+    fn flush(&mut self) -> io::Result<()> {
+        while let Some(data) = self.pending.remove(&self.next_frame) {
+            self.next_frame += 1;
+            if let (Some(stdin), Some(data)) = (self.stdin.as_mut(), data) {
+                stdin.write_all(&data)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@ pub use find_folder;
 pub use lyon;
 pub use winit;
 
-pub use self::app::{App, LoopMode};
+pub use self::app::{App, DrawReset, LoopMode};
 pub use self::draw::Draw;
 pub use self::event::Event;
 pub use self::frame::Frame;
@@ -2,3 +2,58 @@
 //! [noise-rs crate](https://docs.rs/noise).
 
 pub use noise::*;
+
+use std::f64::consts::PI;
+
+/// Sample 2D noise that tiles seamlessly every `w` units along *x* and every `h` units along *y*.
+///
+/// The plane is wrapped onto a torus embedded within 4D noise space, so the given noise function
+/// must support 4D points. The radii of the torus are chosen so that the scale of the noise
+/// roughly matches that of sampling `[x, y]` directly.
+///
+/// This is useful for producing repeating textures.
+pub fn tileable_2d<N>(noise: &N, x: f64, y: f64, w: f64, h: f64) -> f64
+where
+    N: NoiseFn<[f64; 4]>,
+{
+    let [x, y] = [circle(x, w), circle(y, h)];
+    noise.get([x[0], x[1], y[0], y[1]])
+}
+
+/// Sample 1D noise that loops seamlessly every `period` units of `t`.
+///
+/// `t` is mapped onto a circle embedded within 2D noise space, so the given noise function must
+/// support 2D points. The radius of the circle is chosen so that the scale of the noise roughly
+/// matches that of sampling `t` directly.
+///
+/// This is useful for animations that must loop, e.g. GIFs.
+pub fn looping_1d<N>(noise: &N, t: f64, period: f64) -> f64
+where
+    N: NoiseFn<[f64; 2]>,
+{
+    noise.get(circle(t, period))
+}
+
+// Map `v` onto a circle with a circumference of `period`.
+fn circle(v: f64, period: f64) -> [f64; 2] {
+    let radius = period / (2.0 * PI);
+    let angle = v / radius;
+    [radius * angle.cos(), radius * angle.sin()]
+}
+
+#[test]
+fn test_seamless_noise() {
+    let noise = OpenSimplex::new();
+    let (w, h) = (3.5, 2.25);
+    for &(x, y) in &[(1.1, 0.6), (0.3, 1.7), (2.9, 0.4)] {
+        let a = tileable_2d(&noise, x, y, w, h);
+        let b = tileable_2d(&noise, x + w, y - h, w, h);
+        assert!((a - b).abs() < 1e-6);
+    }
+    let period = 4.0;
+    for &t in &[0.7, 1.3, 3.9] {
+        let a = looping_1d(&noise, t, period);
+        let b = looping_1d(&noise, t + period, period);
+        assert!((a - b).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,6 @@
 //! A collection of commonly used items that we recommend importing for ease of use.
 
-pub use crate::app::{self, App, LoopMode};
+pub use crate::app::{self, App, DrawReset, LoopMode};
 pub use crate::draw::Draw;
 pub use crate::event::WindowEvent::*;
 pub use crate::event::{
@@ -13,8 +13,10 @@ pub use crate::text::{self, text};
 pub use crate::time::DurationF64;
 pub use crate::wgpu;
 pub use crate::wgpu::blend::{
-    ADD as BLEND_ADD, DARKEST as BLEND_DARKEST, LIGHTEST as BLEND_LIGHTEST, NORMAL as BLEND_NORMAL,
-    REVERSE_SUBTRACT as BLEND_REVERSE_SUBTRACT, SUBTRACT as BLEND_SUBTRACT,
+    ADD as BLEND_ADD, ADDITIVE as BLEND_ADDITIVE, DARKEST as BLEND_DARKEST,
+    LIGHTEST as BLEND_LIGHTEST, MULTIPLY as BLEND_MULTIPLY, NORMAL as BLEND_NORMAL,
+    REVERSE_SUBTRACT as BLEND_REVERSE_SUBTRACT, SCREEN as BLEND_SCREEN, SUBTRACT as BLEND_SUBTRACT,
+    SUBTRACTIVE as BLEND_SUBTRACTIVE,
 };
 pub use crate::wgpu::util::{BufferInitDescriptor, DeviceExt};
 pub use crate::window::{self, Id as WindowId};
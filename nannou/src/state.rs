@@ -3,7 +3,7 @@
 
 pub use self::keys::Keys;
 pub use self::mouse::Mouse;
-pub use self::time::Time;
+pub use self::time::{Clock, Time};
 pub use self::window::Window;
 
 /// Tracked state related to the focused window.
@@ -65,7 +65,7 @@ pub mod keys {
 
 /// Tracked state related to the mouse.
 pub mod mouse {
-    use crate::geom::Point2;
+    use crate::geom::{Point2, Vec2};
     use crate::window;
     use std::collections::HashMap;
 
@@ -77,12 +77,19 @@ pub mod mouse {
     pub struct Mouse {
         /// The ID of the last window currently in focus.
         pub window: Option<window::Id>,
-        /// *x* position relative to the middle of `window`.
+        /// *x* position in logical points relative to the middle of `window`.
         pub x: f32,
-        /// *y* position relative to the middle of `window`.
+        /// *y* position in logical points relative to the middle of `window`.
         pub y: f32,
         /// A map describing the state of each mouse button.
         pub buttons: ButtonMap,
+        /// The distance the mouse has moved since the previous update.
+        pub delta: Vec2,
+        /// The distance scrolled since the previous update in logical points.
+        ///
+        /// Scrolling by lines (e.g. via a notched mouse wheel) is converted to points using
+        /// `Mouse::POINTS_PER_SCROLL_LINE`.
+        pub scroll_delta: Vec2,
     }
 
     /// Whether the button is up or down.
@@ -103,6 +110,9 @@ pub mod mouse {
     }
 
     impl Mouse {
+        /// The number of points scrolled per line when the mouse wheel scrolls by lines.
+        pub const POINTS_PER_SCROLL_LINE: f32 = 20.0;
+
         /// Construct a new default `Mouse`.
         pub fn new() -> Self {
             Mouse {
@@ -110,10 +120,13 @@ pub mod mouse {
                 buttons: ButtonMap::new(),
                 x: 0.0,
                 y: 0.0,
+                delta: Vec2::ZERO,
+                scroll_delta: Vec2::ZERO,
             }
         }
 
-        /// The position of the mouse relative to the middle of the window in focus..
+        /// The position of the mouse in logical points relative to the middle of the window in
+        /// focus.
         pub fn position(&self) -> Point2 {
             [self.x, self.y].into()
         }
@@ -250,4 +263,126 @@ pub mod time {
             1000.0 / millis
         }
     }
+
+    /// A virtual clock that may be paused, scaled or set to an arbitrary time.
+    ///
+    /// This is the clock read by `app.time`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub struct Clock {
+        /// The elapsed virtual time in seconds.
+        secs: f64,
+        /// The rate at which virtual time advances relative to real time.
+        scale: f64,
+        paused: bool,
+        /// The length of the loop described by `loop_progress`, if any.
+        loop_duration: Option<std::time::Duration>,
+    }
+
+    impl Clock {
+        /// Advance the clock by the given duration of real time, applying the time scale.
+        ///
+        /// Does nothing while the clock is paused.
+        pub fn advance(&mut self, real: std::time::Duration) {
+            if !self.paused {
+                self.secs += real.as_secs_f64() * self.scale;
+            }
+        }
+
+        /// The elapsed virtual time in seconds.
+        pub fn secs(&self) -> f64 {
+            self.secs
+        }
+
+        /// Set the elapsed virtual time in seconds.
+        pub fn set_secs(&mut self, secs: f64) {
+            self.secs = secs;
+        }
+
+        /// The rate at which virtual time advances relative to real time.
+        pub fn scale(&self) -> f64 {
+            self.scale
+        }
+
+        /// Set the rate at which virtual time advances relative to real time.
+        ///
+        /// E.g. `0.5` for half speed, `2.0` for double speed or a negative value to run backwards.
+        pub fn set_scale(&mut self, scale: f64) {
+            self.scale = scale;
+        }
+
+        /// Whether or not the clock is paused.
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Pause or resume the clock.
+        pub fn set_paused(&mut self, paused: bool) {
+            self.paused = paused;
+        }
+
+        /// The length of the loop described by `loop_progress`, if any.
+        pub fn loop_duration(&self) -> Option<std::time::Duration> {
+            self.loop_duration
+        }
+
+        /// Specify the length of the loop described by `loop_progress`.
+        pub fn set_loop_duration(&mut self, duration: Option<std::time::Duration>) {
+            self.loop_duration = duration;
+        }
+
+        /// The progress through the current loop in the range `0.0..1.0`.
+        ///
+        /// Returns `0.0` if no loop duration is set or if it is zero.
+        pub fn loop_progress(&self) -> f64 {
+            match self.loop_duration {
+                Some(duration) if duration.as_secs_f64() > 0.0 => {
+                    let loop_secs = duration.as_secs_f64();
+                    // Guard against rounding up to exactly `1.0`.
+                    let progress = self.secs.rem_euclid(loop_secs) / loop_secs;
+                    if progress < 1.0 {
+                        progress
+                    } else {
+                        0.0
+                    }
+                }
+                _ => 0.0,
+            }
+        }
+    }
+
+    impl Default for Clock {
+        fn default() -> Self {
+            Clock {
+                secs: 0.0,
+                scale: 1.0,
+                paused: false,
+                loop_duration: None,
+            }
+        }
+    }
+}
+
+#[test]
+fn test_clock() {
+    use std::time::Duration;
+
+    let mut clock = Clock::default();
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.secs(), 2.0);
+    clock.set_scale(0.5);
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.secs(), 3.0);
+    clock.set_paused(true);
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.secs(), 3.0);
+    clock.set_paused(false);
+    clock.set_secs(10.0);
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.secs(), 11.0);
+
+    assert_eq!(clock.loop_progress(), 0.0);
+    clock.set_loop_duration(Some(Duration::from_secs(4)));
+    assert_eq!(clock.loop_progress(), 0.75);
+    clock.set_secs(-1.0);
+    assert_eq!(clock.loop_progress(), 0.75);
 }
@@ -677,3 +677,14 @@ pub fn pt_to_scale(font_size_in_points: FontSize) -> Scale {
 pub fn text(s: &str) -> Builder {
     Builder::from(s)
 }
+
+/// Measure the size of the given text when laid out with the given layout, wrapping lines (if the
+/// layout wraps) at `max_width`.
+///
+/// This is the size of the bounds returned by `draw.text(s).layout(layout).w(max_width)` without
+/// any padding. No draw commands are emitted. The default layout and a `max_width` of
+/// `draw::primitive::Text::DEFAULT_W_H` match the defaults of `draw.text`.
+pub fn measure(s: &str, layout: &Layout, max_width: Scalar) -> Vec2 {
+    let rect = geom::Rect::from_w_h(max_width, max_width);
+    text(s).layout(layout).build(rect).bounding_rect().wh()
+}
@@ -53,6 +53,7 @@ pub struct Builder<'app> {
     max_capture_frame_jobs: u32,
     capture_frame_timeout: Option<Duration>,
     clear_color: Option<wgpu::Color>,
+    post_process: Option<String>,
 }
 
 /// For storing all user functions within the window.
@@ -79,6 +80,8 @@ pub(crate) struct UserFunctions {
     pub(crate) dropped_file: Option<DroppedFileFnAny>,
     pub(crate) focused: Option<FocusedFnAny>,
     pub(crate) unfocused: Option<UnfocusedFnAny>,
+    pub(crate) occluded: Option<OccludedFnAny>,
+    pub(crate) unoccluded: Option<UnoccludedFnAny>,
     pub(crate) closed: Option<ClosedFnAny>,
 }
 
@@ -164,6 +167,12 @@ pub type FocusedFn<Model> = fn(&App, &mut Model);
 /// A function for processing window unfocused events.
 pub type UnfocusedFn<Model> = fn(&App, &mut Model);
 
+/// A function for processing window occluded events.
+pub type OccludedFn<Model> = fn(&App, &mut Model);
+
+/// A function for processing window unoccluded events.
+pub type UnoccludedFn<Model> = fn(&App, &mut Model);
+
 /// A function for processing window closed events.
 pub type ClosedFn<Model> = fn(&App, &mut Model);
 
@@ -233,6 +242,8 @@ fn_any!(HoveredFileCancelledFn<M>, HoveredFileCancelledFnAny);
 fn_any!(DroppedFileFn<M>, DroppedFileFnAny);
 fn_any!(FocusedFn<M>, FocusedFnAny);
 fn_any!(UnfocusedFn<M>, UnfocusedFnAny);
+fn_any!(OccludedFn<M>, OccludedFnAny);
+fn_any!(UnoccludedFn<M>, UnoccludedFnAny);
 fn_any!(ClosedFn<M>, ClosedFnAny);
 
 /// A nannou window.
@@ -244,6 +255,7 @@ pub struct Window {
     pub(crate) window: winit::window::Window,
     pub(crate) surface: wgpu::Surface,
     pub(crate) surface_conf: wgpu::SurfaceConfiguration,
+    adapter: Arc<wgpu::ActiveAdapter>,
     pub(crate) device_queue_pair: Arc<wgpu::DeviceQueuePair>,
     msaa_samples: u32,
     pub(crate) frame_data: Option<FrameData>,
@@ -272,6 +284,10 @@ pub(crate) struct TrackedState {
     pub(crate) scale_factor: f64,
     // Updated on `Resized`.
     pub(crate) physical_size: winit::dpi::PhysicalSize<u32>,
+    // Updated on `Focused`.
+    pub(crate) focused: bool,
+    // Updated on `Occluded`.
+    pub(crate) occluded: bool,
 }
 
 /// Surface configuration for which nannou will provide a default if unspecified.
@@ -282,6 +298,7 @@ pub struct SurfaceConfigurationBuilder {
     pub usage: Option<wgpu::TextureUsages>,
     pub format: Option<wgpu::TextureFormat>,
     pub present_mode: Option<wgpu::PresentMode>,
+    pub alpha_mode: Option<CompositeAlphaMode>,
 }
 
 impl SurfaceConfigurationBuilder {
@@ -289,6 +306,17 @@ impl SurfaceConfigurationBuilder {
     pub const DEFAULT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8UnormSrgb;
     pub const DEFAULT_PRESENT_MODE: wgpu::PresentMode = wgpu::PresentMode::Fifo;
     pub const DEFAULT_USAGE: wgpu::TextureUsages = wgpu::TextureUsages::RENDER_ATTACHMENT;
+    pub const DEFAULT_ALPHA_MODE: CompositeAlphaMode = CompositeAlphaMode::Auto;
+    /// The alpha modes that allow for transparent windows, in order of preference.
+    ///
+    /// `PostMultiplied` is preferred as nannou's frames are not premultiplied by alpha. With
+    /// `PreMultiplied`, the window's clear color is premultiplied, though colors drawn to the frame
+    /// are not.
+    pub const TRANSPARENT_ALPHA_MODES: [CompositeAlphaMode; 3] = [
+        CompositeAlphaMode::PostMultiplied,
+        CompositeAlphaMode::PreMultiplied,
+        CompositeAlphaMode::Inherit,
+    ];
 
     /// A new empty **SurfaceConfigurationBuilder** with all parameters set to `None`.
     pub fn new() -> Self {
@@ -301,6 +329,7 @@ impl SurfaceConfigurationBuilder {
             .usage(conf.usage)
             .format(conf.format)
             .present_mode(conf.present_mode)
+            .alpha_mode(conf.alpha_mode)
     }
 
     /// Specify the texture usages for the surface.
@@ -319,37 +348,81 @@ impl SurfaceConfigurationBuilder {
     ///
     /// By default, nannou will attempt to select the ideal present mode depending on the current
     /// app `LoopMode`.
+    ///
+    /// If the given mode is not supported by the surface, a warning is emitted and
+    /// `DEFAULT_PRESENT_MODE` is used instead.
     pub fn present_mode(mut self, present_mode: wgpu::PresentMode) -> Self {
         self.present_mode = Some(present_mode);
         self
     }
 
+    /// The way in which the alpha channel of the surface's frames is composited with the desktop.
+    ///
+    /// By default, nannou selects the first supported mode within `TRANSPARENT_ALPHA_MODES` for
+    /// transparent windows and `DEFAULT_ALPHA_MODE` otherwise.
+    ///
+    /// If the given mode is not supported by the surface, a warning is emitted and
+    /// `DEFAULT_ALPHA_MODE` is used instead.
+    pub fn alpha_mode(mut self, alpha_mode: CompositeAlphaMode) -> Self {
+        self.alpha_mode = Some(alpha_mode);
+        self
+    }
+
     /// Build the surface configuration.
     pub(crate) fn build(
         self,
         surface: &wgpu::Surface,
         adapter: &wgpu::Adapter,
         [width_px, height_px]: [u32; 2],
+        transparent: bool,
     ) -> wgpu::SurfaceConfiguration {
         let usage = self.usage.unwrap_or(Self::DEFAULT_USAGE);
+        let capabilities = surface.get_capabilities(&adapter);
         let format = self
             .format
-            .or_else(|| {
-                surface
-                    .get_capabilities(&adapter)
-                    .formats
-                    .get(0)
-                    .map(|x| x.clone())
-            })
+            .or_else(|| capabilities.formats.get(0).map(|x| x.clone()))
             .unwrap_or(Self::DEFAULT_FORMAT);
-        let present_mode = self.present_mode.unwrap_or(Self::DEFAULT_PRESENT_MODE);
+        let present_mode = match self.present_mode {
+            Some(mode) if !capabilities.present_modes.contains(&mode) => {
+                eprintln!(
+                    "present mode {:?} is not supported by the surface, falling back to {:?}",
+                    mode,
+                    Self::DEFAULT_PRESENT_MODE,
+                );
+                Self::DEFAULT_PRESENT_MODE
+            }
+            mode => mode.unwrap_or(Self::DEFAULT_PRESENT_MODE),
+        };
+        let alpha_mode = match self.alpha_mode {
+            Some(mode) if !capabilities.alpha_modes.contains(&mode) => {
+                eprintln!(
+                    "alpha mode {:?} is not supported by the surface, falling back to {:?}",
+                    mode,
+                    Self::DEFAULT_ALPHA_MODE,
+                );
+                Self::DEFAULT_ALPHA_MODE
+            }
+            Some(mode) => mode,
+            None if transparent => Self::TRANSPARENT_ALPHA_MODES
+                .iter()
+                .cloned()
+                .find(|mode| capabilities.alpha_modes.contains(mode))
+                .unwrap_or_else(|| {
+                    eprintln!(
+                        "transparent windows are not supported by the surface, falling back to \
+                         an opaque window"
+                    );
+                    Self::DEFAULT_ALPHA_MODE
+                }),
+            None => Self::DEFAULT_ALPHA_MODE,
+        };
         wgpu::SurfaceConfiguration {
             usage,
             format,
             width: width_px,
             height: height_px,
             present_mode,
-            alpha_mode: CompositeAlphaMode::Auto,
+            alpha_mode,
             view_formats: Vec::new(),
         }
     }
@@ -376,6 +449,7 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs: Default::default(),
             capture_frame_timeout: Default::default(),
             clear_color: None,
+            post_process: None,
         }
     }
 
@@ -394,6 +468,18 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// Specify the way in which the window's frames are presented to the display.
+    ///
+    /// E.g. `Fifo` waits for vertical sync, `Mailbox` replaces pending frames to reduce latency
+    /// without tearing and `Immediate` presents frames as soon as possible, possibly with tearing.
+    ///
+    /// This is short-hand for setting the `present_mode` of the `surface_conf_builder`. If the
+    /// mode is not supported by the window's surface, a warning is emitted and `Fifo` is used.
+    pub fn present_mode(mut self, present_mode: wgpu::PresentMode) -> Self {
+        self.surface_conf_builder = self.surface_conf_builder.present_mode(present_mode);
+        self
+    }
+
     /// Specify the power preference desired for the WGPU adapter.
     ///
     /// By default, this is `wgpu::PowerPreference::HighPerformance`.
@@ -421,7 +507,8 @@ impl<'app> Builder<'app> {
     /// Specify the number of samples per pixel for the multisample anti-aliasing render pass.
     ///
     /// If `msaa_samples` is unspecified, the first default value that nannou will attempt to use
-    /// can be found via the `Frame::DEFAULT_MSAA_SAMPLES` constant.
+    /// can be found via the `Frame::DEFAULT_MSAA_SAMPLES` constant, unless smoothing was disabled
+    /// via the app's `smooth` builder method in which case MSAA is disabled.
     ///
     /// **Note:** This parameter has no meaning if the window uses a **raw_view** function for
     /// rendering graphics to the window rather than a **view** function. This is because the
@@ -443,6 +530,30 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// Specify a WGSL fragment shader with which to post-process each **Frame**.
+    ///
+    /// By default, the image drawn to each **Frame** is written to the surface texture as is. The
+    /// given shader is run for every pixel of the surface texture instead, allowing it to sample
+    /// the frame's image in order to apply effects like bloom, vignettes or chromatic aberration.
+    /// The shader's `main` entry point receives the texture coordinates at `@location(0)` and may
+    /// sample the frame's resolved, linear sRGBA image via the following bindings:
+    ///
+    /// ```wgsl
+    /// @group(0) @binding(0)
+    /// var tex: texture_2d<f32>;
+    /// @group(0) @binding(1)
+    /// var tex_sampler: sampler;
+    /// ```
+    ///
+    /// Captured frames are taken before post-processing is applied.
+    ///
+    /// **Note:** Like `msaa_samples`, this has no effect for windows that use a **raw_view**
+    /// function.
+    pub fn post_process(mut self, wgsl: &str) -> Self {
+        self.post_process = Some(wgsl.to_string());
+        self
+    }
+
     /// Provide a simple function for drawing to the window.
     ///
     /// This is similar to `view` but does not provide access to user data via a Model type. This
@@ -694,6 +805,27 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// A function for processing the occluded event associated with this window.
+    ///
+    /// The window is occluded when it is entirely hidden from view, e.g. when minimized or covered
+    /// by other windows. Not all platforms report occlusion.
+    pub fn occluded<M>(mut self, f: OccludedFn<M>) -> Self
+    where
+        M: 'static,
+    {
+        self.user_functions.occluded = Some(OccludedFnAny::from_fn_ptr(f));
+        self
+    }
+
+    /// A function for processing the unoccluded event associated with this window.
+    pub fn unoccluded<M>(mut self, f: UnoccludedFn<M>) -> Self
+    where
+        M: 'static,
+    {
+        self.user_functions.unoccluded = Some(UnoccludedFnAny::from_fn_ptr(f));
+        self
+    }
+
     /// A function for processing the window closed event associated with this window.
     pub fn closed<M>(mut self, f: ClosedFn<M>) -> Self
     where
@@ -760,6 +892,7 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
         } = self;
 
         // If the title was not set, default to the "nannou - <exe_name>".
@@ -878,13 +1011,10 @@ impl<'app> Builder<'app> {
         // Background must be initially cleared
         let is_invalidated = true;
 
-        let clear_color = clear_color.unwrap_or_else(|| {
+        let transparent = window.window_attributes().transparent;
+        let mut clear_color = clear_color.unwrap_or_else(|| {
             let mut color: wgpu::Color = Default::default();
-            color.a = if window.window_attributes().transparent {
-                0.0
-            } else {
-                1.0
-            };
+            color.a = if transparent { 0.0 } else { 1.0 };
             color
         });
 
@@ -940,14 +1070,20 @@ impl<'app> Builder<'app> {
         let win_physical_size = window.inner_size();
         let win_dims_px: [u32; 2] = win_physical_size.into();
         let device = device_queue_pair.device();
-        let surface_conf = surface_conf_builder.build(&surface, &*adapter, win_dims_px);
+        let surface_conf =
+            surface_conf_builder.build(&surface, &*adapter, win_dims_px, transparent);
         surface.configure(&device, &surface_conf);
+        if surface_conf.alpha_mode == CompositeAlphaMode::PreMultiplied {
+            clear_color.r *= clear_color.a;
+            clear_color.g *= clear_color.a;
+            clear_color.b *= clear_color.a;
+        }
 
         // If we're using an intermediary image for rendering frames to surface textures, create
         // the necessary render data.
         let (frame_data, msaa_samples) = match user_functions.view {
             Some(View::WithModel(_)) | Some(View::Sketch(_)) | None => {
-                let msaa_samples = msaa_samples.unwrap_or(Frame::DEFAULT_MSAA_SAMPLES);
+                let msaa_samples = msaa_samples.unwrap_or_else(|| app.default_msaa_samples());
                 // TODO: Verity that requested sample count is valid for surface?
                 let surface_dims = [surface_conf.width, surface_conf.height];
                 let render = frame::RenderData::new(
@@ -955,6 +1091,7 @@ impl<'app> Builder<'app> {
                     surface_dims,
                     surface_conf.format,
                     msaa_samples,
+                    post_process.as_deref(),
                 );
                 let capture =
                     frame::CaptureData::new(max_capture_frame_jobs, capture_frame_timeout);
@@ -970,12 +1107,15 @@ impl<'app> Builder<'app> {
         let tracked_state = TrackedState {
             scale_factor: window.scale_factor(),
             physical_size: win_physical_size,
+            focused: window.has_focus(),
+            occluded: false,
         };
 
         let window = Window {
             window,
             surface,
             surface_conf,
+            adapter,
             device_queue_pair,
             msaa_samples,
             frame_data,
@@ -1012,6 +1152,7 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
         } = self;
         let window = map(window);
         Builder {
@@ -1027,6 +1168,7 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
         }
     }
 
@@ -1100,6 +1242,11 @@ impl<'app> Builder<'app> {
     }
 
     /// Sets whether the background of the window should be transparent.
+    ///
+    /// When transparent, the window is cleared with a fully transparent color by default and the
+    /// alpha of the frame (e.g. that of `draw.background()`) is composited with the desktop. If
+    /// the surface does not support transparency, a warning is emitted and the window is opaque.
+    /// Transparency also depends on the platform's window manager.
     pub fn transparent(self, transparent: bool) -> Self {
         self.map_window(|w| w.with_transparent(transparent))
     }
@@ -1472,6 +1619,13 @@ impl Window {
         &self.surface_conf
     }
 
+    /// The wgpu adapter representing the physical device on which the window's surface is running.
+    ///
+    /// This may be used to query the features, limits and info of the GPU prior to requesting them.
+    pub fn adapter(&self) -> &wgpu::Adapter {
+        &self.adapter
+    }
+
     /// The wgpu logical device on which the window's wgpu surface is running.
     pub fn device(&self) -> &wgpu::Device {
         self.device_queue_pair.device()
@@ -1511,14 +1665,15 @@ impl Window {
         self.surface_conf.width = self.tracked_state.physical_size.width;
         self.surface_conf.height = self.tracked_state.physical_size.height;
         self.surface.configure(self.device(), &self.surface_conf);
-        if self.frame_data.is_some() {
+        if let Some(frame_data) = self.frame_data.as_mut() {
             let render_data = frame::RenderData::new(
-                self.device(),
+                self.device_queue_pair.device(),
                 self.tracked_state.physical_size.into(),
                 self.surface_conf.format,
                 self.msaa_samples,
+                frame_data.render.post_process(),
             );
-            self.frame_data.as_mut().unwrap().render = render_data;
+            frame_data.render = render_data;
         }
 
         // May contain uninitialized or previous contents, so must be cleared
@@ -1530,6 +1685,19 @@ impl Window {
         self.fullscreen().is_some()
     }
 
+    /// Whether or not the window currently has input focus.
+    pub fn is_focused(&self) -> bool {
+        self.tracked_state.focused
+    }
+
+    /// Whether or not the window is currently entirely hidden from view, e.g. minimized or covered
+    /// by other windows.
+    ///
+    /// Not all platforms report occlusion, in which case this is always `false`.
+    pub fn is_occluded(&self) -> bool {
+        self.tracked_state.occluded
+    }
+
     /// The number of times `view` has been called with a `Frame` for this window.
     pub fn elapsed_frames(&self) -> u64 {
         self.frame_count
@@ -1543,6 +1711,8 @@ impl Window {
     /// The dimensions will be equal to the result of `inner_size_points`. This represents the area
     /// of the that we can draw to in a DPI-agnostic manner, typically useful for drawing and UI
     /// positioning.
+    ///
+    /// This is the same as `app.window_rect()` when this window is focused.
     pub fn rect(&self) -> geom::Rect {
         let (w, h) = self.inner_size_points();
         geom::Rect::from_w_h(w, h)
@@ -1560,6 +1730,71 @@ impl Window {
         self.capture_frame_inner(path.as_ref());
     }
 
+    /// Begin recording every frame drawn to this window to a video file at the given path.
+    ///
+    /// Frames are encoded by an `ffmpeg` process, which must be installed and available on the
+    /// `PATH`. The output format is inferred from the extension of the path (e.g. `.mp4`, `.gif`).
+    ///
+    /// Every frame that is drawn is written to the video, and the video plays back at the given
+    /// `fps`. For a video that plays back in real time, `fps` should match the rate at which the
+    /// window is drawn. Frames are read back from the GPU on worker threads so as not to block the
+    /// main loop. Frames drawn while the window's size differs from its size when recording began
+    /// are skipped.
+    ///
+    /// Any active recording is stopped first. Returns an error if `ffmpeg` could not be started.
+    pub fn start_recording<P>(&self, path: P, fps: f64) -> std::io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.stop_recording()?;
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            if !dir.as_os_str().is_empty() && !dir.exists() {
+                std::fs::create_dir_all(&dir)?;
+            }
+        }
+        let (w, h) = self.inner_size_pixels();
+        let recording = frame::recording::Recording::start(path, fps, [w, h])?;
+        *self.recording().lock().expect("failed to lock `recording`") = Some(recording);
+        Ok(())
+    }
+
+    /// Stop the active recording, if any.
+    ///
+    /// Blocks until all pending frames have been written and the video file is complete.
+    pub fn stop_recording(&self) -> std::io::Result<()> {
+        let recording = match self.recording().lock() {
+            Ok(mut guard) => guard.take(),
+            Err(_) => None,
+        };
+        if let Some(recording) = recording {
+            if self.await_capture_frame_jobs().is_err() {
+                // TODO: Replace eprintlns with proper logging.
+                eprintln!("timed out while waiting for recorded frames to complete");
+            }
+            recording.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Whether or not the window's frames are currently being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.frame_data
+            .as_ref()
+            .and_then(|data| data.capture.recording.lock().ok())
+            .map(|guard| guard.is_some())
+            .unwrap_or(false)
+    }
+
+    fn recording(&self) -> &std::sync::Mutex<Option<frame::recording::Recording>> {
+        &self
+            .frame_data
+            .as_ref()
+            .expect("window recording requires that `view` draws to a `Frame` (not a `RawFrame`)")
+            .capture
+            .recording
+    }
+
     /// Produces a reference to the inner winit window.
     ///
     /// This is sometimes useful for integration with other winit-aware libraries (e.g. UI).
@@ -1605,6 +1840,12 @@ impl Window {
 
 impl Drop for Window {
     fn drop(&mut self) {
+        if self.frame_data.is_some() {
+            if let Err(err) = self.stop_recording() {
+                // TODO: Replace eprintlns with proper logging.
+                eprintln!("failed to complete recording: {}", err);
+            }
+        }
         if self.await_capture_frame_jobs().is_err() {
             // TODO: Replace eprintlns with proper logging.
             eprintln!("timed out while waiting for capture jobs to complete");
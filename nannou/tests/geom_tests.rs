@@ -13,3 +13,59 @@ fn angle_test() {
     let vector = vec2(70.7, -60.8);
     assert_eq!(vector.angle(), -0.7102547457375739);
 }
+
+#[test]
+fn polygon_area_centroid_and_containment_test() {
+    let square = [pt2(0.0, 0.0), pt2(2.0, 0.0), pt2(2.0, 2.0), pt2(0.0, 2.0)];
+    assert_eq!(geom::polygon_area(&square), 4.0);
+    let clockwise: Vec<_> = square.iter().rev().cloned().collect();
+    assert_eq!(geom::polygon_area(&clockwise), -4.0);
+    assert_eq!(geom::polygon_centroid(&square), pt2(1.0, 1.0));
+
+    // A concave "L" shape.
+    let l = [
+        pt2(0.0, 0.0),
+        pt2(2.0, 0.0),
+        pt2(2.0, 1.0),
+        pt2(1.0, 1.0),
+        pt2(1.0, 2.0),
+        pt2(0.0, 2.0),
+    ];
+    assert!(geom::point_in_polygon(pt2(0.5, 1.5), &l));
+    assert!(!geom::point_in_polygon(pt2(1.5, 1.5), &l));
+    assert!(!geom::point_in_polygon(pt2(3.0, 0.5), &l));
+
+    // Degenerate cases.
+    let line = [pt2(0.0, 0.0), pt2(1.0, 1.0), pt2(2.0, 2.0)];
+    assert_eq!(geom::polygon_area(&line), 0.0);
+    assert_eq!(geom::polygon_centroid(&line), pt2(1.0, 1.0));
+    assert_eq!(geom::polygon_area(&[]), 0.0);
+    assert_eq!(geom::polygon_centroid(&[]), Vec2::ZERO);
+    assert!(!geom::point_in_polygon(pt2(0.0, 0.0), &[]));
+}
+
+#[test]
+fn rect_layout_test() {
+    let rect = Rect::from_w_h(100.0, 50.0);
+
+    let cells: Vec<_> = rect.subdivide(4, 2).collect();
+    assert_eq!(cells.len(), 8);
+    assert_eq!(
+        cells[0],
+        Rect::from_corners(pt2(-50.0, -25.0), pt2(-25.0, 0.0))
+    );
+    assert_eq!(
+        cells[7],
+        Rect::from_corners(pt2(25.0, 0.0), pt2(50.0, 25.0))
+    );
+    assert_eq!(rect.subdivide(0, 3).count(), 0);
+
+    let (left, right) = rect.split_x(0.25);
+    assert_eq!((left.w(), right.w()), (25.0, 75.0));
+    assert_eq!(left.right(), right.left());
+    let (bottom, top) = rect.split_y(0.5);
+    assert_eq!((bottom.top(), top.bottom()), (0.0, 0.0));
+
+    let small = Rect::from_w_h(10.0, 10.0).align_to(geom::Align::Start, geom::Align::End, rect);
+    assert_eq!(small.top_left(), rect.top_left());
+}
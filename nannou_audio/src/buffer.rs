@@ -25,6 +25,18 @@ pub struct FramesMut<'a, S: 'a> {
     chunks: std::slice::ChunksMut<'a, S>,
 }
 
+/// An iterator yielding each sample of a single channel in some Buffer.
+#[derive(Clone, Debug)]
+pub struct Channel<'a, S: 'a> {
+    samples: std::iter::StepBy<std::slice::Iter<'a, S>>,
+}
+
+/// An iterator yielding mutable references to each sample of a single channel in some Buffer.
+#[derive(Debug)]
+pub struct ChannelMut<'a, S: 'a> {
+    samples: std::iter::StepBy<std::slice::IterMut<'a, S>>,
+}
+
 impl<S> Buffer<S> {
     /// The default number of frames per buffer.
     ///
@@ -57,6 +69,71 @@ impl<S> Buffer<S> {
         let chunks = self.interleaved_samples.chunks_mut(self.channels);
         FramesMut { chunks }
     }
+
+    /// Produce an iterator yielding each sample of the channel at the given index in order.
+    ///
+    /// **Panics** if `channel` is not less than the number of channels.
+    pub fn channel(&self, channel: usize) -> Channel<S> {
+        assert!(channel < self.channels, "channel index out of range");
+        // The buffer may be empty, in which case there are no samples to yield.
+        let samples = self
+            .interleaved_samples
+            .get(channel..)
+            .unwrap_or(&[])
+            .iter()
+            .step_by(self.channels);
+        Channel { samples }
+    }
+
+    /// Produce an iterator yielding a mutable reference to each sample of the channel at the given
+    /// index in order.
+    ///
+    /// **Panics** if `channel` is not less than the number of channels.
+    pub fn channel_mut(&mut self, channel: usize) -> ChannelMut<S> {
+        assert!(channel < self.channels, "channel index out of range");
+        let samples = self
+            .interleaved_samples
+            .get_mut(channel..)
+            .unwrap_or_default()
+            .iter_mut()
+            .step_by(self.channels);
+        ChannelMut { samples }
+    }
+
+    /// Copy the samples of each channel into their own buffer.
+    ///
+    /// The result contains one `Vec` per channel, each with a length of `len_frames`.
+    pub fn deinterleaved(&self) -> Vec<Vec<S>>
+    where
+        S: Copy,
+    {
+        (0..self.channels)
+            .map(|channel| self.channel(channel).cloned().collect())
+            .collect()
+    }
+
+    /// Overwrite the samples of each channel with those of the given planar (non-interleaved)
+    /// channel buffers.
+    ///
+    /// If a channel buffer is shorter than `len_frames`, the remaining samples of that channel are
+    /// left unchanged. Any samples beyond `len_frames` are ignored.
+    ///
+    /// **Panics** if the number of channel buffers does not match the number of channels.
+    pub fn fill_from_planar(&mut self, planar: &[&[S]])
+    where
+        S: Copy,
+    {
+        assert_eq!(
+            planar.len(),
+            self.channels,
+            "the number of planar buffers must match the number of channels"
+        );
+        for (channel, samples) in planar.iter().enumerate() {
+            for (out, &sample) in self.channel_mut(channel).zip(samples.iter()) {
+                *out = sample;
+            }
+        }
+    }
 }
 
 impl<S> Deref for Buffer<S> {
@@ -115,3 +192,59 @@ impl<'a, S> DoubleEndedIterator for FramesMut<'a, S> {
         self.chunks.next_back()
     }
 }
+
+impl<'a, S> Iterator for Channel<'a, S> {
+    type Item = &'a S;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.samples.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.samples.size_hint()
+    }
+}
+
+impl<'a, S> Iterator for ChannelMut<'a, S> {
+    type Item = &'a mut S;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.samples.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.samples.size_hint()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Channel<'a, S> {
+    fn len(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for ChannelMut<'a, S> {
+    fn len(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+#[test]
+fn test_channel() {
+    let mut buffer = Buffer {
+        interleaved_samples: vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0].into_boxed_slice(),
+        channels: 2,
+        sample_rate: 44_100,
+    };
+    assert_eq!(
+        buffer.channel(1).cloned().collect::<Vec<_>>(),
+        [1.0, 3.0, 5.0]
+    );
+    buffer.channel_mut(0).for_each(|s| *s = -1.0);
+    assert_eq!(buffer.deinterleaved(), [[-1.0; 3], [1.0, 3.0, 5.0]]);
+
+    // An empty buffer yields no samples rather than panicking.
+    let mut empty = Buffer::<f32> {
+        interleaved_samples: Box::new([]),
+        channels: 2,
+        sample_rate: 44_100,
+    };
+    assert_eq!(empty.channel(1).len(), 0);
+    assert_eq!(empty.channel_mut(1).len(), 0);
+}
@@ -0,0 +1,353 @@
+use crate::{mixer, Buffer, Resampler};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as DecodeError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::{MediaSource, MediaSourceStream};
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use thiserror::Error;
+
+/// Plays back an audio file that has been decoded into memory.
+///
+/// WAV, FLAC and MP3 files are supported. The file is resampled to the rate of the buffer passed
+/// to `render`, and is up or down-mixed to the buffer's channel count following the same policy
+/// as the [**Mixer**](../mixer/struct.Mixer.html).
+///
+/// The `FilePlayer` may be used as the model of an output stream with `FilePlayer::render` as the
+/// render function, or called from within another render function or `Mixer` source.
+#[derive(Clone, Debug)]
+pub struct FilePlayer {
+    // The decoded, interleaved samples of the file.
+    samples: Arc<[f32]>,
+    channels: usize,
+    sample_rate: u32,
+    // The index of the next frame to be read from `samples`.
+    position: usize,
+    playing: bool,
+    looping: bool,
+    resampler: Resampler,
+    // Whether the frame held back by the resampler has been flushed at the end of the file.
+    flushed: bool,
+    // Frames that have been resampled but not yet written to an output buffer.
+    pending: Vec<f32>,
+}
+
+/// Errors that might occur when opening an audio file.
+#[derive(Debug, Error)]
+pub enum FileError {
+    #[error("failed to open the file: {err}")]
+    Io { err: std::io::Error },
+    #[error("failed to decode the file: {err}")]
+    Decode { err: DecodeError },
+    #[error("the file contains no audio track")]
+    NoTrack,
+    #[error("the file does not specify its sample rate or channel count")]
+    UnknownFormat,
+}
+
+impl FilePlayer {
+    // The number of frames read from the file per call to the resampler.
+    const CHUNK_LEN_FRAMES: usize = 256;
+
+    /// Open and decode the audio file at the given path.
+    ///
+    /// The format is detected from the contents of the file, using the extension as a hint. The
+    /// player is initially paused and does not loop.
+    pub fn open<P>(path: P) -> Result<Self, FileError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let mut hint = Hint::new();
+        if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+            hint.with_extension(ext);
+        }
+        Self::decode(Box::new(file), &hint)
+    }
+
+    // Decode the audio within the given source.
+    fn decode(source: Box<dyn MediaSource>, hint: &Hint) -> Result<Self, FileError> {
+        let stream = MediaSourceStream::new(source, Default::default());
+        let probed = symphonia::default::get_probe().format(
+            hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?;
+        let mut format = probed.format;
+        let track = format.default_track().ok_or(FileError::NoTrack)?;
+        let track_id = track.id;
+        let params = &track.codec_params;
+        let sample_rate = params.sample_rate.ok_or(FileError::UnknownFormat)?;
+        let channels = params
+            .channels
+            .map(|channels| channels.count())
+            .filter(|&channels| channels > 0)
+            .ok_or(FileError::UnknownFormat)?;
+        let mut decoder =
+            symphonia::default::get_codecs().make(params, &DecoderOptions::default())?;
+
+        // Decode all packets of the track.
+        let mut samples = vec![];
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                Err(DecodeError::IoError(ref err))
+                    if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+                {
+                    break
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if packet.track_id() != track_id {
+                continue;
+            }
+            let decoded = match decoder.decode(&packet) {
+                Ok(decoded) => decoded,
+                // Skip corrupt packets rather than failing the whole file.
+                Err(DecodeError::DecodeError(_)) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+            buffer.copy_interleaved_ref(decoded);
+            samples.extend_from_slice(buffer.samples());
+        }
+
+        Ok(FilePlayer {
+            samples: samples.into(),
+            channels,
+            sample_rate,
+            position: 0,
+            playing: false,
+            looping: false,
+            resampler: Resampler::new(channels),
+            flushed: false,
+            pending: vec![],
+        })
+    }
+
+    /// Specify whether or not playback should restart from the beginning upon reaching the end.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.set_looping(looping);
+        self
+    }
+
+    /// Set whether or not playback should restart from the beginning upon reaching the end.
+    pub fn set_looping(&mut self, looping: bool) {
+        self.looping = looping;
+    }
+
+    /// Whether or not playback restarts from the beginning upon reaching the end.
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Begin or resume playback.
+    ///
+    /// If playback previously reached the end of the file, it restarts from the beginning.
+    pub fn play(&mut self) {
+        if self.position >= self.len_frames() && self.pending.is_empty() {
+            self.seek(Duration::from_secs(0));
+        }
+        self.playing = true;
+    }
+
+    /// Pause playback at the current position.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Whether or not the file is currently playing.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Move the playback position to the given offset from the start of the file.
+    ///
+    /// Offsets beyond the end of the file are clamped to the end.
+    pub fn seek(&mut self, offset: Duration) {
+        let frame = (offset.as_secs_f64() * self.sample_rate as f64) as usize;
+        self.position = frame.min(self.len_frames());
+        self.pending.clear();
+        self.resampler.reset();
+        self.flushed = false;
+    }
+
+    /// The current playback position as an offset from the start of the file.
+    pub fn position(&self) -> Duration {
+        Duration::from_secs_f64(self.position as f64 / self.sample_rate as f64)
+    }
+
+    /// The duration of the file.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.len_frames() as f64 / self.sample_rate as f64)
+    }
+
+    /// The number of channels within the file.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// The sample rate of the file.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Render the file into the given buffer.
+    ///
+    /// The samples are summed with the existing contents of the buffer, allowing for multiple
+    /// files to be rendered into the same buffer. Nothing is rendered while paused.
+    pub fn render(&mut self, buffer: &mut Buffer) {
+        if !self.playing {
+            return;
+        }
+
+        // Resample enough frames to fill the buffer.
+        let channels = self.channels;
+        let needed = buffer.len_frames() * channels;
+        let (in_rate, out_rate) = (self.sample_rate, buffer.sample_rate());
+        while self.pending.len() < needed {
+            if self.position >= self.len_frames() {
+                if !self.looping || self.len_frames() == 0 {
+                    // Output the final frame held back by the resampler.
+                    if !self.flushed {
+                        self.resampler
+                            .flush_into(in_rate, out_rate, &mut self.pending);
+                        self.flushed = true;
+                        continue;
+                    }
+                    break;
+                }
+                self.position = 0;
+            }
+            let end = (self.position + Self::CHUNK_LEN_FRAMES).min(self.len_frames());
+            let chunk = &self.samples[self.position * channels..end * channels];
+            self.position = end;
+            self.resampler
+                .process_into(chunk, in_rate, out_rate, &mut self.pending);
+        }
+
+        // Write the pending frames to the buffer.
+        let written = needed.min(self.pending.len());
+        let frames = buffer
+            .frames_mut()
+            .zip(self.pending[..written].chunks(channels));
+        for (out_frame, frame) in frames {
+            mixer::mix_frame(out_frame, frame, 1.0);
+        }
+        self.pending.drain(..written);
+
+        // Stop once all frames have been written.
+        if self.position >= self.len_frames()
+            && self.flushed
+            && self.pending.is_empty()
+            && !self.looping
+        {
+            self.playing = false;
+        }
+    }
+
+    fn len_frames(&self) -> usize {
+        self.samples.len() / self.channels
+    }
+}
+
+impl From<std::io::Error> for FileError {
+    fn from(err: std::io::Error) -> Self {
+        FileError::Io { err }
+    }
+}
+
+impl From<DecodeError> for FileError {
+    fn from(err: DecodeError) -> Self {
+        FileError::Decode { err }
+    }
+}
+
+#[cfg(test)]
+fn test_player(samples: &[i16], sample_rate: u32) -> FilePlayer {
+    // Encode the samples as a mono 16-bit PCM WAV file in memory.
+    let data_len = samples.len() as u32 * 2;
+    let mut wav = vec![];
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+    let mut hint = Hint::new();
+    hint.with_extension("wav");
+    FilePlayer::decode(Box::new(std::io::Cursor::new(wav)), &hint).unwrap()
+}
+
+#[cfg(test)]
+fn test_render(player: &mut FilePlayer, len_frames: usize) -> Vec<f32> {
+    let mut buffer = Buffer {
+        interleaved_samples: vec![0.0; len_frames].into_boxed_slice(),
+        channels: 1,
+        sample_rate: player.sample_rate(),
+    };
+    player.render(&mut buffer);
+    buffer.to_vec()
+}
+
+#[test]
+fn test_end_of_file() {
+    let samples: Vec<i16> = (1..=10).map(|i| i * 1_000).collect();
+    let mut player = test_player(&samples, 8_000);
+    assert_eq!(player.channels(), 1);
+    assert_eq!(player.duration(), Duration::from_secs_f64(10.0 / 8_000.0));
+
+    // Nothing is rendered until playing.
+    assert_eq!(test_render(&mut player, 4), [0.0; 4]);
+    player.play();
+    let mut output = vec![];
+    for _ in 0..4 {
+        output.extend(test_render(&mut player, 4));
+    }
+    assert!(!player.is_playing());
+
+    // The resampler lags by a frame, after which the whole file plays once followed by silence.
+    let expected: Vec<f32> = std::iter::once(0.0)
+        .chain(samples.iter().map(|&s| s as f32 / 32_768.0))
+        .chain(std::iter::repeat(0.0))
+        .take(output.len())
+        .collect();
+    assert_eq!(output, expected);
+
+    // Playing again restarts from the beginning.
+    player.play();
+    assert_eq!(test_render(&mut player, 2)[1], expected[1]);
+}
+
+#[test]
+fn test_looping() {
+    let samples: Vec<i16> = (1..=10).map(|i| i * 1_000).collect();
+    let mut player = test_player(&samples, 8_000).looping(true);
+    player.play();
+    let mut output = vec![];
+    for _ in 0..8 {
+        output.extend(test_render(&mut player, 4));
+    }
+    assert!(player.is_playing());
+
+    // The file repeats without a gap at the loop point.
+    assert_eq!(output[0], 0.0);
+    for (i, &sample) in output.iter().enumerate().skip(1) {
+        assert_eq!(sample, samples[(i - 1) % samples.len()] as f32 / 32_768.0);
+    }
+}
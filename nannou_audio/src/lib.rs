@@ -11,6 +11,14 @@
 //! - [**Receiver**](./receiver/struct.Receiver.html) and
 //!   [**Requester**](./requester/struct.Requester.html) for buffering input and output streams that
 //!   may deliver buffers of inconsistent sizes into a stream of consistently sized buffers.
+//! - [**FilePlayer**](./file_player/struct.FilePlayer.html) - for playing back WAV, FLAC and MP3
+//!   files.
+//! - [**Mixer**](./mixer/struct.Mixer.html) - for mixing any number of sources into a single
+//!   output stream.
+//! - [**Resampler**](./resampler/struct.Resampler.html) - for converting audio between sample
+//!   rates, e.g. when an algorithm requires a fixed rate that differs from the device's.
+//! - [**Spectrum**](./spectrum/struct.Spectrum.html) - for analysing the frequency content of a
+//!   signal, e.g. to drive audio-reactive visuals from an input stream.
 
 use cpal::traits::HostTrait;
 use std::marker::PhantomData;
@@ -18,24 +26,32 @@ use std::sync::Arc;
 
 pub use self::buffer::Buffer;
 pub use self::device::{Device, Devices};
+pub use self::file_player::FilePlayer;
+pub use self::mixer::Mixer;
 pub use self::receiver::Receiver;
 pub use self::requester::Requester;
+pub use self::resampler::Resampler;
+pub use self::spectrum::Spectrum;
 pub use self::stream::Stream;
 pub use cpal;
 #[doc(inline)]
 pub use cpal::{
     BackendSpecificError, BufferSize, BuildStreamError, DefaultStreamConfigError, DeviceNameError,
     DevicesError, HostId, HostUnavailable, InputCallbackInfo, InputStreamTimestamp,
-    OutputCallbackInfo, OutputStreamTimestamp, PauseStreamError, PlayStreamError, StreamError,
-    SupportedBufferSize, SupportedInputConfigs, SupportedOutputConfigs, SupportedStreamConfig,
-    SupportedStreamConfigsError,
+    OutputCallbackInfo, OutputStreamTimestamp, PauseStreamError, PlayStreamError, SampleFormat,
+    StreamError, SupportedBufferSize, SupportedInputConfigs, SupportedOutputConfigs,
+    SupportedStreamConfig, SupportedStreamConfigsError,
 };
 pub use dasp_sample;
 
 pub mod buffer;
 pub mod device;
+pub mod file_player;
+pub mod mixer;
 pub mod receiver;
 pub mod requester;
+pub mod resampler;
+pub mod spectrum;
 pub mod stream;
 
 /// The top-level audio API, for enumerating devices and spawning input/output streams.
@@ -104,6 +120,10 @@ impl Host {
 
     /// Begin building a new input audio stream.
     ///
+    /// The sample type `S` of the `Buffer` passed to the capture function may be any of `f32`,
+    /// `f64`, `i16` or `u16`. Samples are converted from the device's format where necessary. See
+    /// `stream::input::Builder::sample_format`.
+    ///
     /// If this is the first time a stream has been created, this method will spawn the
     /// `cpal::EventLoop::run` method on its own thread, ready to run built streams.
     pub fn new_input_stream<M, S>(&self, model: M) -> stream::input::BuilderInit<M, S> {
@@ -116,6 +136,10 @@ impl Host {
 
     /// Begin building a new output audio stream.
     ///
+    /// The sample type `S` of the `Buffer` passed to the render function may be any of `f32`,
+    /// `f64`, `i16` or `u16`. Samples are converted to the device's format where necessary. See
+    /// `stream::output::Builder::sample_format`.
+    ///
     /// If this is the first time a stream has been created, this method will spawn the
     /// `cpal::EventLoop::run` method on its own thread, ready to run built streams.
     pub fn new_output_stream<M, S>(&self, model: M) -> stream::output::BuilderInit<M, S> {
@@ -136,10 +160,13 @@ impl Host {
             model,
             sample_rate: None,
             channels: None,
+            allow_fewer_channels: false,
             frames_per_buffer: None,
             device_buffer_size: None,
             device: None,
             sample_format: PhantomData,
+            device_sample_format: None,
+            underrun: None,
         }
     }
 }
@@ -0,0 +1,230 @@
+use crate::Buffer;
+
+/// Mixes any number of audio sources into the buffer of a single output stream.
+///
+/// The `Mixer` is intended to be used as the model of an output stream, with `Mixer::render` as
+/// the stream's render function:
+///
+/// ```ignore
+/// let mut mixer = audio::Mixer::new();
+/// let synth = mixer.add_source(1, 0.5, move |buffer: &mut audio::Buffer| { /* ... */ });
+/// let stream = host
+///     .new_output_stream(mixer)
+///     .render(audio::Mixer::render)
+///     .build()?;
+/// // Sources may be adjusted while the stream is running.
+/// stream.send(move |mixer| mixer.set_gain(synth, 0.25))?;
+/// ```
+///
+/// ## Mixing policy
+///
+/// Each source renders into its own buffer with its own channel count. Its samples are multiplied
+/// by the source's gain and summed into the output as follows:
+///
+/// - If the source has fewer channels than the output, output channel `c` receives source channel
+///   `c % source_channels`. E.g. a mono source is copied to every output channel.
+/// - If the source has at least as many channels as the output, output channel `c` receives the
+///   average of all source channels `i` where `i % output_channels == c`. E.g. a stereo source is
+///   averaged down to a mono output.
+///
+/// Finally, the summed output is clamped to the range `-1.0..=1.0` to avoid wrapping or clipping
+/// artifacts in the device. Reduce source gains to avoid reaching this limit.
+#[derive(Default)]
+pub struct Mixer {
+    sources: Vec<Source>,
+    next_id: u64,
+}
+
+/// A unique identifier for a source added to a `Mixer`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceId(u64);
+
+// A single source of audio within the mixer.
+struct Source {
+    id: SourceId,
+    gain: f32,
+    render: Box<dyn FnMut(&mut Buffer) + Send>,
+    buffer: Buffer,
+}
+
+impl Mixer {
+    /// Create a new `Mixer` with no sources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a source that renders `channels` channels of audio via the given function.
+    ///
+    /// The buffer passed to `render` has the same length in frames and sample rate as that of the
+    /// output stream. Its samples are zeroed before each call.
+    ///
+    /// **Panics** if `channels` is `0`.
+    pub fn add_source<F>(&mut self, channels: usize, gain: f32, render: F) -> SourceId
+    where
+        F: 'static + FnMut(&mut Buffer) + Send,
+    {
+        assert!(channels > 0);
+        let id = SourceId(self.next_id);
+        self.next_id += 1;
+        let buffer = Buffer {
+            interleaved_samples: Vec::new().into_boxed_slice(),
+            channels,
+            sample_rate: 0,
+        };
+        self.sources.push(Source {
+            id,
+            gain,
+            render: Box::new(render),
+            buffer,
+        });
+        id
+    }
+
+    /// Remove the source with the given ID.
+    ///
+    /// Returns `false` if there was no source with the given ID.
+    pub fn remove_source(&mut self, id: SourceId) -> bool {
+        let len = self.sources.len();
+        self.sources.retain(|source| source.id != id);
+        self.sources.len() != len
+    }
+
+    /// The gain of the source with the given ID.
+    pub fn gain(&self, id: SourceId) -> Option<f32> {
+        self.sources.iter().find(|s| s.id == id).map(|s| s.gain)
+    }
+
+    /// Set the gain of the source with the given ID.
+    ///
+    /// Returns `false` if there was no source with the given ID.
+    pub fn set_gain(&mut self, id: SourceId, gain: f32) -> bool {
+        match self.sources.iter_mut().find(|s| s.id == id) {
+            Some(source) => {
+                source.gain = gain;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The number of sources within the mixer.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Whether or not the mixer contains no sources.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Render all sources and mix them into the given output buffer.
+    ///
+    /// This has the signature of an output stream render function, where the `Mixer` is the
+    /// stream's model.
+    pub fn render(&mut self, output: &mut Buffer) {
+        for sample in output.iter_mut() {
+            *sample = 0.0;
+        }
+
+        let len_frames = output.len_frames();
+        let sample_rate = output.sample_rate();
+        for source in &mut self.sources {
+            // Prepare the source's buffer. This only allocates when the output length changes.
+            let src_channels = source.buffer.channels;
+            let len_samples = len_frames * src_channels;
+            if source.buffer.interleaved_samples.len() != len_samples {
+                source.buffer.interleaved_samples = vec![0.0; len_samples].into_boxed_slice();
+            } else {
+                for sample in source.buffer.iter_mut() {
+                    *sample = 0.0;
+                }
+            }
+            source.buffer.sample_rate = sample_rate;
+            (source.render)(&mut source.buffer);
+
+            // Sum the source into the output.
+            let gain = source.gain;
+            let frames = output.frames_mut().zip(source.buffer.frames());
+            for (out_frame, src_frame) in frames {
+                mix_frame(out_frame, src_frame, gain);
+            }
+        }
+
+        for sample in output.iter_mut() {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+    }
+}
+
+// Sum the source frame into the output frame, up or down-mixing as described in the `Mixer` docs.
+pub(crate) fn mix_frame(out_frame: &mut [f32], src_frame: &[f32], gain: f32) {
+    let (out_channels, src_channels) = (out_frame.len(), src_frame.len());
+    if src_channels < out_channels {
+        for (c, out) in out_frame.iter_mut().enumerate() {
+            *out += src_frame[c % src_channels] * gain;
+        }
+    } else {
+        for (c, out) in out_frame.iter_mut().enumerate() {
+            let contributing = src_frame.iter().skip(c).step_by(out_channels);
+            let count = (src_channels - c).div_ceil(out_channels);
+            let sum: f32 = contributing.sum();
+            *out += sum / count as f32 * gain;
+        }
+    }
+}
+
+impl std::fmt::Debug for Mixer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let sources: Vec<_> = self
+            .sources
+            .iter()
+            .map(|s| (s.id, s.buffer.channels, s.gain))
+            .collect();
+        f.debug_struct("Mixer").field("sources", &sources).finish()
+    }
+}
+
+#[test]
+fn test_mix_frame() {
+    // Up-mixing copies each source channel to the output channels that share its index.
+    let mut out = [0.0; 4];
+    mix_frame(&mut out, &[0.5], 2.0);
+    assert_eq!(out, [1.0; 4]);
+    let mut out = [0.0; 4];
+    mix_frame(&mut out, &[0.25, 0.5], 1.0);
+    assert_eq!(out, [0.25, 0.5, 0.25, 0.5]);
+
+    // Down-mixing averages the source channels that share an output channel.
+    let mut out = [0.0];
+    mix_frame(&mut out, &[0.25, 0.75], 1.0);
+    assert_eq!(out, [0.5]);
+    let mut out = [0.0; 2];
+    mix_frame(&mut out, &[0.2, 0.4, 0.6], 0.5);
+    assert_eq!(out, [0.2, 0.2]);
+
+    // Sources are summed into the existing output.
+    mix_frame(&mut out, &[0.1, 0.1], 1.0);
+    assert_eq!(out, [0.3, 0.3]);
+}
+
+#[test]
+fn test_render_clamps() {
+    let mut mixer = Mixer::new();
+    for &value in &[0.75, 0.75] {
+        mixer.add_source(1, 1.0, move |buffer: &mut Buffer| {
+            buffer.iter_mut().for_each(|s| *s = value);
+        });
+    }
+    mixer.add_source(2, 1.0, |buffer: &mut Buffer| {
+        for frame in buffer.frames_mut() {
+            frame[1] = -2.0;
+        }
+    });
+    let mut output = Buffer {
+        interleaved_samples: vec![0.0; 8].into_boxed_slice(),
+        channels: 2,
+        sample_rate: 44_100,
+    };
+    mixer.render(&mut output);
+    assert_eq!(&output[..], &[1.0, -0.5, 1.0, -0.5, 1.0, -0.5, 1.0, -0.5]);
+}
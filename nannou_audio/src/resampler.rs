@@ -0,0 +1,179 @@
+/// A linear interpolating sample rate converter for interleaved `f32` audio.
+///
+/// The `Resampler` is useful for bridging the gap between algorithms that require a fixed sample
+/// rate (e.g. HRTF processing) and the rate at which the audio device is actually running.
+///
+/// The interpolation position and the last frame of each buffer are retained between calls to
+/// `process` so that consecutive buffers join without discontinuities. As a result, the output
+/// lags the input by a single frame.
+#[derive(Clone, Debug)]
+pub struct Resampler {
+    channels: usize,
+    // The last frame of the previous input buffer.
+    prev_frame: Vec<f32>,
+    // The read position in frames relative to `prev_frame`.
+    position: f64,
+}
+
+impl Resampler {
+    /// Construct a new `Resampler` for audio with the given number of channels.
+    ///
+    /// **Panics** if `channels` is `0`.
+    pub fn new(channels: usize) -> Self {
+        assert!(channels > 0);
+        Resampler {
+            channels,
+            prev_frame: vec![0.0; channels],
+            position: 0.0,
+        }
+    }
+
+    /// The number of interleaved channels expected by the resampler.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Clear the retained state, e.g. before processing an unrelated signal.
+    pub fn reset(&mut self) {
+        for sample in &mut self.prev_frame {
+            *sample = 0.0;
+        }
+        self.position = 0.0;
+    }
+
+    /// Convert the given interleaved `input` at `in_rate` to a new buffer at `out_rate`.
+    ///
+    /// Note that this allocates a new `Vec` for the output. Prefer `process_into` within audio
+    /// callbacks.
+    pub fn process(&mut self, input: &[f32], in_rate: u32, out_rate: u32) -> Vec<f32> {
+        let mut output = Vec::new();
+        self.process_into(input, in_rate, out_rate, &mut output);
+        output
+    }
+
+    /// Convert the given interleaved `input` at `in_rate` to `out_rate`, appending the result to
+    /// `output`.
+    ///
+    /// The number of frames produced may differ between calls by one frame as the fractional
+    /// position is carried over to the next buffer.
+    ///
+    /// **Panics** if either rate is `0` or if the length of `input` is not a multiple of the
+    /// number of channels.
+    pub fn process_into(
+        &mut self,
+        input: &[f32],
+        in_rate: u32,
+        out_rate: u32,
+        output: &mut Vec<f32>,
+    ) {
+        assert!(in_rate > 0 && out_rate > 0);
+        assert_eq!(input.len() % self.channels, 0);
+        let Resampler {
+            channels,
+            ref mut prev_frame,
+            ref mut position,
+        } = *self;
+        let len_frames = input.len() / channels;
+        if len_frames == 0 {
+            return;
+        }
+
+        // Index `0` refers to the last frame of the previous buffer, `1` to the first of `input`.
+        let frame = |index: usize| -> &[f32] {
+            match index {
+                0 => &prev_frame[..],
+                i => &input[(i - 1) * channels..i * channels],
+            }
+        };
+
+        let step = in_rate as f64 / out_rate as f64;
+        let estimated_frames = ((len_frames as f64 - *position) / step).ceil().max(0.0);
+        output.reserve(estimated_frames as usize * channels);
+        while *position < len_frames as f64 {
+            let index = *position as usize;
+            let fract = (*position - index as f64) as f32;
+            let (a, b) = (frame(index), frame(index + 1));
+            output.extend(a.iter().zip(b).map(|(&a, &b)| a + (b - a) * fract));
+            *position += step;
+        }
+
+        *position -= len_frames as f64;
+        prev_frame.copy_from_slice(&input[(len_frames - 1) * channels..]);
+    }
+
+    /// Flush the frame held back from the last call to `process_into`, appending it to `output`.
+    ///
+    /// The held frame is extended for the duration of a single input frame. This is useful at the
+    /// end of a signal, where there is no following input to interpolate towards.
+    ///
+    /// **Panics** if either rate is `0`.
+    pub fn flush_into(&mut self, in_rate: u32, out_rate: u32, output: &mut Vec<f32>) {
+        assert!(in_rate > 0 && out_rate > 0);
+        let step = in_rate as f64 / out_rate as f64;
+        while self.position < 1.0 {
+            output.extend_from_slice(&self.prev_frame);
+            self.position += step;
+        }
+        self.position -= 1.0;
+    }
+}
+
+#[test]
+fn test_continuity() {
+    // Resample a ramp in buffers of varying length and compare it to the ramp at the new rate.
+    let (in_rate, out_rate) = (48_000, 44_100);
+    let ramp: Vec<f32> = (0..1_000).map(|i| i as f32).collect();
+    let mut resampler = Resampler::new(1);
+    let mut chunked = vec![];
+    for chunk in ramp.chunks(37) {
+        resampler.process_into(chunk, in_rate, out_rate, &mut chunked);
+    }
+    let step = in_rate as f64 / out_rate as f64;
+    for (i, &sample) in chunked.iter().enumerate() {
+        // The output lags the input by a single frame.
+        let expected = (i as f64 * step - 1.0).max(0.0) as f32;
+        assert!(
+            (sample - expected).abs() < 1e-3,
+            "{}: {} != {}",
+            i,
+            sample,
+            expected
+        );
+    }
+
+    // Processing the whole signal at once produces the same output.
+    resampler.reset();
+    assert_eq!(resampler.process(&ramp, in_rate, out_rate), chunked);
+}
+
+#[test]
+fn test_flush() {
+    let input = [1.0, 2.0, 3.0];
+    let mut resampler = Resampler::new(1);
+    let mut output = resampler.process(&input, 44_100, 44_100);
+    assert_eq!(output, [0.0, 1.0, 2.0]);
+    resampler.flush_into(44_100, 44_100, &mut output);
+    assert_eq!(output, [0.0, 1.0, 2.0, 3.0]);
+
+    // At a higher output rate the held frame is extended to the same duration.
+    resampler.reset();
+    let mut output = resampler.process(&input, 22_050, 44_100);
+    resampler.flush_into(22_050, 44_100, &mut output);
+    assert_eq!(output, [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.0]);
+}
+
+#[test]
+fn test_len_ratio() {
+    let channels = 2;
+    for &(in_rate, out_rate) in &[(48_000, 44_100), (44_100, 48_000), (22_050, 44_100)] {
+        let input = vec![0.0; in_rate as usize * channels];
+        let mut resampler = Resampler::new(channels);
+        let mut output = vec![];
+        for chunk in input.chunks(64 * channels) {
+            resampler.process_into(chunk, in_rate, out_rate, &mut output);
+        }
+        assert_eq!(output.len() % channels, 0);
+        let len_frames = output.len() / channels;
+        assert!((len_frames as i64 - out_rate as i64).abs() <= 1);
+    }
+}
@@ -0,0 +1,226 @@
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+
+/// A window function applied to each block of samples prior to the FFT.
+///
+/// Windowing reduces the "leakage" of energy into neighbouring bins caused by analysing a block of
+/// samples that does not contain a whole number of periods of the signal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Window {
+    /// No windowing. Offers the narrowest peaks but the most leakage.
+    Rectangular,
+    /// A good general purpose window.
+    Hann,
+    /// Similar to `Hann`, but with lower nearby side lobes at the cost of higher distant ones.
+    Hamming,
+}
+
+/// A short-time spectrum analyser for a mono signal.
+///
+/// The `Spectrum` retains the most recent `size` samples pushed via `push_samples`. The magnitude
+/// of each frequency bin is computed from these via an FFT when `magnitudes` is called.
+pub struct Spectrum {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    // The sum of the window coefficients, used to normalise magnitudes.
+    window_sum: f32,
+    // A ring buffer of the most recent `size` samples.
+    samples: Vec<f32>,
+    // The index of the oldest sample in `samples`.
+    next: usize,
+    buffer: Vec<Complex<f32>>,
+    scratch: Vec<Complex<f32>>,
+    magnitudes: Vec<f32>,
+    // Whether or not samples have been pushed since the magnitudes were last computed.
+    dirty: bool,
+}
+
+impl Window {
+    /// The coefficient of the window at index `i` of a block of `size` samples.
+    pub fn coefficient(&self, i: usize, size: usize) -> f32 {
+        if size <= 1 {
+            return 1.0;
+        }
+        let phase = 2.0 * std::f32::consts::PI * i as f32 / (size - 1) as f32;
+        match *self {
+            Window::Rectangular => 1.0,
+            Window::Hann => 0.5 - 0.5 * phase.cos(),
+            Window::Hamming => 0.54 - 0.46 * phase.cos(),
+        }
+    }
+}
+
+impl Spectrum {
+    /// The default window function.
+    pub const DEFAULT_WINDOW: Window = Window::Hann;
+
+    /// Create a spectrum analyser that operates on blocks of `size` samples using a Hann window.
+    ///
+    /// Larger sizes yield finer frequency resolution at the cost of time resolution. Sizes that
+    /// are a power of two are the most efficient.
+    ///
+    /// **Panics** if `size` is less than `2`.
+    pub fn new(size: usize) -> Self {
+        Self::with_window(size, Self::DEFAULT_WINDOW)
+    }
+
+    /// Create a spectrum analyser that operates on blocks of `size` samples using the given window.
+    ///
+    /// **Panics** if `size` is less than `2`.
+    pub fn with_window(size: usize, window: Window) -> Self {
+        assert!(size >= 2, "spectrum size must be at least 2");
+        let fft = FftPlanner::new().plan_fft_forward(size);
+        let window: Vec<f32> = (0..size).map(|i| window.coefficient(i, size)).collect();
+        let window_sum = window.iter().sum();
+        let scratch = vec![Complex::default(); fft.get_inplace_scratch_len()];
+        Spectrum {
+            fft,
+            window,
+            window_sum,
+            samples: vec![0.0; size],
+            next: 0,
+            buffer: vec![Complex::default(); size],
+            scratch,
+            magnitudes: vec![0.0; size / 2 + 1],
+            dirty: false,
+        }
+    }
+
+    /// The number of samples analysed by each FFT.
+    pub fn size(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// The number of frequency bins, i.e. `size / 2 + 1`.
+    pub fn len_bins(&self) -> usize {
+        self.magnitudes.len()
+    }
+
+    /// The centre frequency in hz of the given bin for a signal at the given sample rate.
+    pub fn bin_frequency(&self, bin: usize, sample_rate: u32) -> f32 {
+        bin as f32 * sample_rate as f32 / self.size() as f32
+    }
+
+    /// Push the given mono samples, discarding any samples older than the last `size`.
+    ///
+    /// To analyse a multi-channel `Buffer`, consider pushing the average of each frame.
+    pub fn push_samples(&mut self, samples: &[f32]) {
+        let size = self.size();
+        // Only the most recent `size` samples are relevant.
+        let samples = &samples[samples.len().saturating_sub(size)..];
+        for &sample in samples {
+            self.samples[self.next] = sample;
+            self.next = (self.next + 1) % size;
+        }
+        self.dirty |= !samples.is_empty();
+    }
+
+    /// Clear all retained samples.
+    pub fn reset(&mut self) {
+        for sample in &mut self.samples {
+            *sample = 0.0;
+        }
+        self.next = 0;
+        self.dirty = true;
+    }
+
+    /// The magnitude of each frequency bin for the most recent `size` samples.
+    ///
+    /// Bin `i` corresponds to the frequency returned by `bin_frequency(i, sample_rate)`. The
+    /// magnitudes are normalised such that a full-scale sine wave that aligns with a bin produces
+    /// a magnitude of roughly `1.0`.
+    ///
+    /// The FFT is only performed if samples have been pushed since the last call.
+    pub fn magnitudes(&mut self) -> &[f32] {
+        if self.dirty {
+            self.process();
+            self.dirty = false;
+        }
+        &self.magnitudes
+    }
+
+    /// Average the magnitudes into `bands.len()` logarithmically spaced bands between `min_hz`
+    /// and `max_hz`.
+    ///
+    /// Logarithmic bands better match the perception of pitch, in which each octave spans twice
+    /// the frequency range of the one below. Each band is guaranteed to cover at least one bin, so
+    /// narrow low frequency bands may share bins when the frequency resolution is coarse.
+    ///
+    /// **Panics** if `min_hz` is not greater than `0.0` or if `max_hz` is not greater than
+    /// `min_hz`.
+    pub fn log_bands(&mut self, sample_rate: u32, min_hz: f32, max_hz: f32, bands: &mut [f32]) {
+        assert!(min_hz > 0.0 && max_hz > min_hz);
+        let hz_per_bin = sample_rate as f32 / self.size() as f32;
+        let num_bands = bands.len();
+        let magnitudes = self.magnitudes();
+        let last_bin = magnitudes.len() - 1;
+        let ratio = max_hz / min_hz;
+        for (i, band) in bands.iter_mut().enumerate() {
+            let lo_hz = min_hz * ratio.powf(i as f32 / num_bands as f32);
+            let hi_hz = min_hz * ratio.powf((i + 1) as f32 / num_bands as f32);
+            let start = ((lo_hz / hz_per_bin).round() as usize).min(last_bin);
+            let end = ((hi_hz / hz_per_bin).round() as usize).clamp(start + 1, last_bin + 1);
+            let bins = &magnitudes[start..end];
+            *band = bins.iter().sum::<f32>() / bins.len() as f32;
+        }
+    }
+
+    // Perform the FFT over the retained samples and update the magnitudes.
+    fn process(&mut self) {
+        let size = self.size();
+        let oldest_first = self.samples[self.next..]
+            .iter()
+            .chain(&self.samples[..self.next]);
+        for ((c, &sample), &w) in self.buffer.iter_mut().zip(oldest_first).zip(&self.window) {
+            *c = Complex::new(sample * w, 0.0);
+        }
+        self.fft
+            .process_with_scratch(&mut self.buffer, &mut self.scratch);
+        let scale = 2.0 / self.window_sum;
+        for (mag, c) in self.magnitudes.iter_mut().zip(&self.buffer[..size / 2 + 1]) {
+            *mag = c.norm() * scale;
+        }
+    }
+}
+
+impl std::fmt::Debug for Spectrum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Spectrum")
+            .field("size", &self.size())
+            .field("magnitudes", &self.magnitudes)
+            .finish()
+    }
+}
+
+#[test]
+fn test_peak_frequency() {
+    let sample_rate = 44_100;
+    let mut spectrum = Spectrum::new(1024);
+    let hz_per_bin = sample_rate as f32 / spectrum.size() as f32;
+    // Include a frequency that aligns with a bin and one that falls between bins.
+    for &hz in &[hz_per_bin * 20.0, 1_000.0, 5_000.0] {
+        let sine: Vec<f32> = (0..4_000)
+            .map(|i| (2.0 * std::f32::consts::PI * hz * i as f32 / sample_rate as f32).sin())
+            .collect();
+        // Push in chunks so that the ring buffer wraps around.
+        spectrum.reset();
+        for chunk in sine.chunks(300) {
+            spectrum.push_samples(chunk);
+        }
+        let magnitudes = spectrum.magnitudes();
+        let (peak, &peak_mag) = magnitudes
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .unwrap();
+        let peak_hz = spectrum.bin_frequency(peak, sample_rate);
+        assert!(
+            (peak_hz - hz).abs() <= hz_per_bin * 0.5,
+            "{} != {}",
+            peak_hz,
+            hz
+        );
+        assert!(peak_mag > 0.5 && peak_mag < 1.1);
+    }
+}
@@ -69,6 +69,19 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
     pub fn channels(mut self, channels: usize) -> Self {
         assert!(channels > 0);
         self.builder.channels = Some(channels);
+        self.builder.allow_fewer_channels = false;
+        self
+    }
+
+    /// Request the given number of channels, falling back to the maximum number of channels
+    /// supported by the device in the case that it supports fewer.
+    ///
+    /// The number of channels that the stream was built with can be retrieved via
+    /// `stream.cpal_config().channels`.
+    pub fn try_channels(mut self, channels: usize) -> Self {
+        assert!(channels > 0);
+        self.builder.channels = Some(channels);
+        self.builder.allow_fewer_channels = true;
         self
     }
 
@@ -88,6 +101,35 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
         self
     }
 
+    /// Request the given sample format from the device.
+    ///
+    /// By default, nannou requests the format matching the `Buffer`'s sample type `S` where
+    /// possible. This may be used to target a specific format supported by the device instead,
+    /// e.g. for devices that only expose integer formats. The `Buffer` passed to the `capture`
+    /// function remains `Buffer<S>`, with samples converted as they are read from the device.
+    ///
+    /// If the device does not support the requested format, its default format is used instead.
+    /// The format that the stream was built with can be retrieved via `stream.sample_format()`.
+    pub fn sample_format(mut self, format: cpal::SampleFormat) -> Self {
+        self.builder.device_sample_format = Some(format);
+        self
+    }
+
+    /// Specify a function to call each time an underrun is detected.
+    ///
+    /// For input streams, this occurs when the capture function fails to read a buffer within the
+    /// duration of the audio it contains, in which case captured audio may be dropped. The total
+    /// number of underruns is also available via `stream.underrun_count()`.
+    ///
+    /// **Note:** This function is called on the real-time audio thread so should return quickly.
+    pub fn on_underrun<F>(mut self, on_underrun: F) -> Self
+    where
+        F: 'static + Fn() + Send,
+    {
+        self.builder.underrun = Some(Box::new(on_underrun));
+        self
+    }
+
     pub fn build(self) -> std::result::Result<Stream<M>, super::BuildError>
     where
         S: 'static + Send + Sample + FromSample<u16> + FromSample<i16> + FromSample<f32>,
@@ -104,9 +146,12 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
                     model,
                     sample_rate,
                     channels,
+                    allow_fewer_channels,
                     frames_per_buffer,
                     device_buffer_size,
                     device,
+                    device_sample_format,
+                    underrun,
                     ..
                 },
         } = self;
@@ -119,8 +164,9 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
         };
 
         let desired = super::DesiredStreamConfig {
-            sample_format: super::cpal_sample_format::<S>(),
+            sample_format: device_sample_format.or_else(super::cpal_sample_format::<S>),
             channels,
+            allow_fewer_channels,
             sample_rate: sample_rate.map(cpal::SampleRate),
             device_buffer_size,
         };
@@ -131,8 +177,7 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
             desired,
             device.default_input_config().ok(),
             |device| device.supported_input_configs().map(|fs| fs.collect()),
-        )?
-        .expect("no matching supported audio input formats for the target device");
+        )?;
         let (update_tx, update_rx) = mpsc::channel();
         let model = Arc::new(Mutex::new(Some(model)));
         let model_render = model.clone();
@@ -156,8 +201,14 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
         // format.
         let mut samples = vec![S::EQUILIBRIUM; frames_per_buffer * num_channels];
 
+        // Counts buffers that are not processed in time.
+        let mut underruns = stream::UnderrunDetector::new(underrun);
+        let underrun_count = underruns.count();
+
         // The function used to process a buffer of samples.
-        let capture_fn = move |data: &cpal::Data, _info: &cpal::InputCallbackInfo| {
+        let capture_fn = move |data: &cpal::Data, info: &cpal::InputCallbackInfo| {
+            let started = std::time::Instant::now();
+
             // Collect and process any pending updates.
             macro_rules! process_pending_updates {
                 () => {
@@ -215,6 +266,9 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
             }
 
             process_pending_updates!();
+
+            let frames = data.len() / num_channels;
+            underruns.check(info.timestamp().callback, started, frames, sample_rate);
         };
 
         // Wrap the user's error function.
@@ -233,12 +287,14 @@ impl<M, FC, FE, S> Builder<M, FC, FE, S> {
             stream,
             model,
             is_paused: AtomicBool::new(false),
+            underruns: underrun_count,
         });
 
         let stream = Stream {
             shared,
             update_tx,
             cpal_config: stream_config,
+            sample_format,
         };
         Ok(stream)
     }
@@ -3,8 +3,9 @@ use cpal::traits::StreamTrait;
 use std;
 use std::any::{Any, TypeId};
 use std::marker::PhantomData;
-use std::sync::atomic::{self, AtomicBool};
+use std::sync::atomic::{self, AtomicBool, AtomicU64};
 use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Items related to input audio streams.
@@ -23,6 +24,8 @@ pub trait ErrorFn<M>: Fn(&mut M, cpal::StreamError) {}
 pub type UpdateFn<M> = dyn FnOnce(&mut M) + Send + 'static;
 /// The default stream error function type used when unspecified.
 pub type DefaultErrorFn<M> = fn(&mut M, err: cpal::StreamError);
+/// The type of function called on the audio thread each time an underrun is detected.
+pub type UnderrunFn = dyn Fn() + Send + 'static;
 
 /// A clone-able handle around an audio stream.
 pub struct Stream<M> {
@@ -32,6 +35,8 @@ pub struct Stream<M> {
     shared: Arc<Shared<M>>,
     /// The stream config with which the stream was created.
     cpal_config: cpal::StreamConfig,
+    /// The sample format with which the stream was created.
+    sample_format: cpal::SampleFormat,
 }
 
 // Data shared between each `Stream` handle to a single stream.
@@ -42,6 +47,8 @@ struct Shared<M> {
     model: Arc<Mutex<Option<M>>>,
     // Whether or not the stream is currently paused.
     is_paused: AtomicBool,
+    // The number of underruns detected on the audio thread.
+    underruns: Arc<AtomicU64>,
 }
 
 /// Stream building parameters that are common between input and output streams.
@@ -50,10 +57,30 @@ pub struct Builder<M, S = f32> {
     pub model: M,
     pub sample_rate: Option<u32>,
     pub channels: Option<usize>,
+    /// Whether or not to fall back to the device's maximum channel count in the case that it
+    /// supports fewer than `channels`.
+    pub(crate) allow_fewer_channels: bool,
     pub frames_per_buffer: Option<usize>,
     pub device_buffer_size: Option<cpal::BufferSize>,
     pub device: Option<Device>,
     pub(crate) sample_format: PhantomData<S>,
+    /// The sample format requested of the device, if it should differ from `S`.
+    pub(crate) device_sample_format: Option<cpal::SampleFormat>,
+    /// Called on the audio thread each time an underrun is detected.
+    pub(crate) underrun: Option<Box<UnderrunFn>>,
+}
+
+// Detects buffers that were not processed in time.
+//
+// A buffer is considered late if the callback took longer to process it than the duration of the
+// audio it contained, or if the callback was invoked later than the previous buffer ran out.
+pub(crate) struct UnderrunDetector {
+    count: Arc<AtomicU64>,
+    on_underrun: Option<Box<UnderrunFn>>,
+    // The instant at which the previous buffer's callback was invoked.
+    last_callback: Option<cpal::StreamInstant>,
+    // The duration of the previous buffer.
+    last_duration: Option<Duration>,
 }
 
 /// Errors that might occur when attempting to build a stream.
@@ -67,6 +94,14 @@ pub enum BuildError {
     },
     #[error("failed to build stream: {err}")]
     BuildStream { err: cpal::BuildStreamError },
+    #[error("the audio device is no longer available")]
+    DeviceUnavailable,
+    #[error("{requested} channels were requested but the device supports at most {max}")]
+    UnsupportedChannelCount { requested: usize, max: usize },
+    #[error("the device does not support the requested sample rate of {requested}hz")]
+    UnsupportedSampleRate { requested: u32 },
+    #[error("no supported stream config matches the requested parameters")]
+    NoMatchingConfig,
 }
 
 #[derive(Debug)]
@@ -75,6 +110,8 @@ struct DesiredStreamConfig {
     sample_format: Option<cpal::SampleFormat>,
     /// Channel count specified by the user.
     channels: Option<usize>,
+    /// Whether or not the channel count may be reduced to the maximum supported by the device.
+    allow_fewer_channels: bool,
     /// The user's if specified, otherwise is `DEFAULT_SAMPLE_RATE`.
     sample_rate: Option<cpal::SampleRate>,
     /// Desired device buffer size specified by the user.
@@ -165,6 +202,20 @@ impl<M> Stream<M> {
         Ok(())
     }
 
+    /// The number of underruns detected since the stream was created.
+    ///
+    /// An underrun (or "xrun") occurs when the audio callback fails to process a buffer within
+    /// the duration of the audio it contains, e.g. because the render function was too slow. This
+    /// usually results in an audible glitch. For input streams, this indicates that captured audio
+    /// was not read in time. If this count increases steadily, consider reducing the work done per
+    /// buffer or increasing the `device_buffer_size`.
+    ///
+    /// Detection is based on the timing of the callback, so underruns caused elsewhere within the
+    /// audio backend may not be counted.
+    pub fn underrun_count(&self) -> u64 {
+        self.shared.underruns.load(atomic::Ordering::Relaxed)
+    }
+
     /// The config with which the inner CPAL stream was created.
     ///
     /// This **should** match the actual stream config that is running. If not, there may be a bug
@@ -174,6 +225,13 @@ impl<M> Stream<M> {
     pub fn cpal_config(&self) -> &cpal::StreamConfig {
         &self.cpal_config
     }
+
+    /// The sample format with which the inner CPAL stream was created.
+    ///
+    /// If this differs from the `Buffer`'s sample type, samples are converted on the audio thread.
+    pub fn sample_format(&self) -> cpal::SampleFormat {
+        self.sample_format
+    }
 }
 
 impl<M> Shared<M> {
@@ -198,6 +256,56 @@ impl<M> Shared<M> {
     }
 }
 
+impl UnderrunDetector {
+    pub(crate) fn new(on_underrun: Option<Box<UnderrunFn>>) -> Self {
+        UnderrunDetector {
+            count: Arc::new(AtomicU64::new(0)),
+            on_underrun,
+            last_callback: None,
+            last_duration: None,
+        }
+    }
+
+    // A handle to the underrun count to be shared with the `Stream`.
+    pub(crate) fn count(&self) -> Arc<AtomicU64> {
+        self.count.clone()
+    }
+
+    // Check the timing of a callback that was invoked at `callback` and began processing at
+    // `started`, counting an underrun if the buffer was late.
+    pub(crate) fn check(
+        &mut self,
+        callback: cpal::StreamInstant,
+        started: Instant,
+        frames: usize,
+        sample_rate: u32,
+    ) {
+        let duration = Duration::from_secs_f64(frames as f64 / sample_rate as f64);
+        let interval = self
+            .last_callback
+            .and_then(|last_callback| callback.duration_since(&last_callback));
+        self.last_callback = Some(callback);
+        self.check_timing(started.elapsed(), interval, duration);
+    }
+
+    // Count an underrun if processing the buffer of the given duration took longer than its
+    // duration, or if the interval since the previous callback exceeded the duration of both the
+    // previous buffer and this one.
+    fn check_timing(&mut self, elapsed: Duration, interval: Option<Duration>, duration: Duration) {
+        let mut late = elapsed > duration;
+        if let (Some(interval), Some(last_duration)) = (interval, self.last_duration) {
+            late |= interval > last_duration + duration;
+        }
+        self.last_duration = Some(duration);
+        if late {
+            self.count.fetch_add(1, atomic::Ordering::Relaxed);
+            if let Some(ref on_underrun) = self.on_underrun {
+                on_underrun();
+            }
+        }
+    }
+}
+
 impl<M, F> ErrorFn<M> for F where F: Fn(&mut M, cpal::StreamError) {}
 
 impl<M> Clone for Stream<M> {
@@ -205,10 +313,12 @@ impl<M> Clone for Stream<M> {
         let update_tx = self.update_tx.clone();
         let shared = self.shared.clone();
         let cpal_config = self.cpal_config.clone();
+        let sample_format = self.sample_format;
         Stream {
             update_tx,
             shared,
             cpal_config,
+            sample_format,
         }
     }
 }
@@ -223,13 +333,19 @@ impl<M> Drop for Shared<M> {
 
 impl From<cpal::BuildStreamError> for BuildError {
     fn from(err: cpal::BuildStreamError) -> Self {
-        BuildError::BuildStream { err }
+        match err {
+            cpal::BuildStreamError::DeviceNotAvailable => BuildError::DeviceUnavailable,
+            err => BuildError::BuildStream { err },
+        }
     }
 }
 
 impl From<cpal::SupportedStreamConfigsError> for BuildError {
     fn from(err: cpal::SupportedStreamConfigsError) -> Self {
-        BuildError::SupportedStreamConfigs { err }
+        match err {
+            cpal::SupportedStreamConfigsError::DeviceNotAvailable => BuildError::DeviceUnavailable,
+            err => BuildError::SupportedStreamConfigs { err },
+        }
     }
 }
 
@@ -363,35 +479,50 @@ fn desired_config_matches_default(
             default.buffer_size(),
         )
     {
-        let mut config = default.config();
-        config.buffer_size = desired
-            .device_buffer_size
-            .clone()
-            .unwrap_or(cpal::BufferSize::Default);
-        let sample_format = desired.sample_format.unwrap_or(default.sample_format());
-        let matching = MatchingConfig {
-            config,
-            sample_format,
-        };
-        Some(matching)
+        Some(default_matching_config(desired, default))
     } else {
         None
     }
 }
 
+// The default stream config with the desired buffer size.
+fn default_matching_config(
+    desired: &DesiredStreamConfig,
+    default: &cpal::SupportedStreamConfig,
+) -> MatchingConfig {
+    let mut config = default.config();
+    config.buffer_size = desired
+        .device_buffer_size
+        .clone()
+        .unwrap_or(cpal::BufferSize::Default);
+    let sample_format = desired.sample_format.unwrap_or(default.sample_format());
+    MatchingConfig {
+        config,
+        sample_format,
+    }
+}
+
 // Given some audio device find the supported stream config that best matches the given optional
 // config parameters (specified by the user).
+//
+// The device's supported configs are only queried if the default config does not match. In the
+// case that the query fails, the default config is used as a fallback.
+//
+// In the case that no config matches, the returned error describes the parameter that could not
+// be satisfied.
 fn find_best_matching_config<F>(
     device: &cpal::Device,
     mut desired: DesiredStreamConfig,
     default: Option<cpal::SupportedStreamConfig>,
     supported_configs: F,
-) -> Result<Option<MatchingConfig>, cpal::SupportedStreamConfigsError>
+) -> Result<MatchingConfig, BuildError>
 where
     F: Fn(
         &cpal::Device,
     ) -> Result<Vec<cpal::SupportedStreamConfigRange>, cpal::SupportedStreamConfigsError>,
 {
+    let requested_sample_rate = desired.sample_rate;
+
     // In the case that the user has not specified a sample rate, we want to try specifying a
     // reasonable default ourselves, otherwise CPAL can give back some extremely high frequency
     // ones by default that are generally less practical.
@@ -401,24 +532,55 @@ where
         trying_default_sample_rate = true;
     }
 
+    // First, see if the default config satisfies the request without querying the device.
+    if let Some(ref default) = default {
+        if let Some(conf) = desired_config_matches_default(&desired, default) {
+            return Ok(conf);
+        }
+    }
+
+    // Otherwise query the supported configs, falling back to the default config on failure.
+    let queried = match supported_configs(device) {
+        Ok(supported) => Some(supported),
+        Err(_) if default.is_some() => None,
+        Err(err) => return Err(err.into()),
+    };
+    let supported = queried.as_deref().unwrap_or(&[]);
+
+    // Check that the device can provide the requested number of channels, reducing the request
+    // to the maximum supported if the user allows it.
+    let max_channels = supported
+        .iter()
+        .map(|config| config.channels() as usize)
+        .chain(default.as_ref().map(|config| config.channels() as usize))
+        .max();
+    if let (Some(requested), Some(max)) = (desired.channels, max_channels) {
+        if requested > max {
+            if !desired.allow_fewer_channels {
+                return Err(BuildError::UnsupportedChannelCount { requested, max });
+            }
+            desired.channels = Some(max);
+        }
+    }
+
     loop {
         {
             // First, see if the default config satisfies the request.
             if let Some(ref default) = default {
                 if let Some(conf) = desired_config_matches_default(&desired, default) {
-                    return Ok(Some(conf));
+                    return Ok(conf);
                 }
             }
 
             // Otherwise search through all supported configs for compatible configs.
-            let stream_configs = supported_configs(device)?
-                .into_iter()
-                .filter_map(|config| matching_supported_config(&desired, &config));
+            let stream_configs = supported
+                .iter()
+                .filter_map(|config| matching_supported_config(&desired, config));
 
             // Find the best supported config. In the case that no desired channel count was
             // specified, this will be the maximum number of channels available.
             if let Some(matching) = stream_configs.max_by_key(|matching| matching.config.channels) {
-                return Ok(Some(matching));
+                return Ok(matching);
             }
         }
 
@@ -439,8 +601,22 @@ where
             continue;
         }
 
+        // If the supported configs could not be queried, fall back to the default config.
+        if let (None, Some(ref default)) = (&queried, &default) {
+            return Ok(default_matching_config(&desired, default));
+        }
+
         // Otherwise, there are no matches for the request.
-        return Ok(None);
+        if let Some(sample_rate) = requested_sample_rate {
+            let supports_sample_rate = supported.iter().any(|config| {
+                config.min_sample_rate() <= sample_rate && sample_rate <= config.max_sample_rate()
+            });
+            if !supports_sample_rate {
+                let requested = sample_rate.0;
+                return Err(BuildError::UnsupportedSampleRate { requested });
+            }
+        }
+        return Err(BuildError::NoMatchingConfig);
     }
 }
 
@@ -448,3 +624,37 @@ where
 pub(crate) fn default_error_fn<M>(_: &mut M, err: cpal::StreamError) {
     eprintln!("A `StreamError` occurred: {}", err);
 }
+
+#[test]
+fn test_underrun_detector() {
+    let called = Arc::new(AtomicU64::new(0));
+    let on_underrun = {
+        let called = called.clone();
+        move || {
+            called.fetch_add(1, atomic::Ordering::Relaxed);
+        }
+    };
+    let mut detector = UnderrunDetector::new(Some(Box::new(on_underrun)));
+    let count = detector.count();
+    let duration = Duration::from_secs_f64(64.0 / 44_100.0);
+
+    // On time: each buffer is processed within its duration and callbacks arrive regularly.
+    detector.check_timing(duration / 2, None, duration);
+    detector.check_timing(duration / 2, Some(duration), duration);
+    detector.check_timing(duration / 4, Some(duration * 3 / 2), duration);
+    assert_eq!(count.load(atomic::Ordering::Relaxed), 0);
+
+    // Slow: processing the buffer took longer than its duration.
+    detector.check_timing(duration * 2, Some(duration), duration);
+    assert_eq!(count.load(atomic::Ordering::Relaxed), 1);
+
+    // Late: the callback arrived after the previous buffer and this one would have played out.
+    detector.check_timing(duration / 2, Some(duration * 3), duration);
+    assert_eq!(count.load(atomic::Ordering::Relaxed), 2);
+    assert_eq!(called.load(atomic::Ordering::Relaxed), 2);
+
+    // The interval accounts for the length of the previous buffer.
+    detector.check_timing(duration / 2, None, duration * 4);
+    detector.check_timing(duration / 2, Some(duration * 4), duration);
+    assert_eq!(count.load(atomic::Ordering::Relaxed), 2);
+}
@@ -68,6 +68,19 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
     pub fn channels(mut self, channels: usize) -> Self {
         assert!(channels > 0);
         self.builder.channels = Some(channels);
+        self.builder.allow_fewer_channels = false;
+        self
+    }
+
+    /// Request the given number of channels, falling back to the maximum number of channels
+    /// supported by the device in the case that it supports fewer.
+    ///
+    /// The number of channels that the stream was built with can be retrieved via
+    /// `stream.cpal_config().channels`.
+    pub fn try_channels(mut self, channels: usize) -> Self {
+        assert!(channels > 0);
+        self.builder.channels = Some(channels);
+        self.builder.allow_fewer_channels = true;
         self
     }
 
@@ -87,6 +100,35 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
         self
     }
 
+    /// Request the given sample format from the device.
+    ///
+    /// By default, nannou requests the format matching the `Buffer`'s sample type `S` where
+    /// possible. This may be used to target a specific format supported by the device instead,
+    /// e.g. for devices that only expose integer formats. The `Buffer` passed to the `render`
+    /// function remains `Buffer<S>`, with samples converted as they are written to the device.
+    ///
+    /// If the device does not support the requested format, its default format is used instead.
+    /// The format that the stream was built with can be retrieved via `stream.sample_format()`.
+    pub fn sample_format(mut self, format: cpal::SampleFormat) -> Self {
+        self.builder.device_sample_format = Some(format);
+        self
+    }
+
+    /// Specify a function to call each time an underrun is detected.
+    ///
+    /// An underrun occurs when the render function fails to fill a buffer within the duration of
+    /// the audio it contains, usually resulting in an audible glitch. The total number of
+    /// underruns is also available via `stream.underrun_count()`.
+    ///
+    /// **Note:** This function is called on the real-time audio thread so should return quickly.
+    pub fn on_underrun<F>(mut self, on_underrun: F) -> Self
+    where
+        F: 'static + Fn() + Send,
+    {
+        self.builder.underrun = Some(Box::new(on_underrun));
+        self
+    }
+
     pub fn build(self) -> std::result::Result<Stream<M>, super::BuildError>
     where
         S: 'static + Send + Sample + ToSample<u16> + ToSample<i16> + ToSample<f32>,
@@ -103,9 +145,12 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
                     model,
                     sample_rate,
                     channels,
+                    allow_fewer_channels,
                     frames_per_buffer,
                     device_buffer_size,
                     device,
+                    device_sample_format,
+                    underrun,
                     ..
                 },
         } = self;
@@ -118,8 +163,9 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
         };
 
         let desired = super::DesiredStreamConfig {
-            sample_format: super::cpal_sample_format::<S>(),
+            sample_format: device_sample_format.or_else(super::cpal_sample_format::<S>),
             channels,
+            allow_fewer_channels,
             sample_rate: sample_rate.map(cpal::SampleRate),
             device_buffer_size,
         };
@@ -130,8 +176,7 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
             desired,
             device.default_output_config().ok(),
             |device| device.supported_output_configs().map(|fs| fs.collect()),
-        )?
-        .expect("no matching supported audio output formats for the target device");
+        )?;
         let (update_tx, update_rx) = mpsc::channel();
         let model = Arc::new(Mutex::new(Some(model)));
         let model_render = model.clone();
@@ -155,9 +200,15 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
         // format.
         let mut samples = vec![S::EQUILIBRIUM; frames_per_buffer * num_channels];
 
+        // Counts buffers that are not processed in time.
+        let mut underruns = stream::UnderrunDetector::new(underrun);
+        let underrun_count = underruns.count();
+
         // The function used to process a buffer of samples.
         // TODO: We should notify the user of `OutputCallbackInfo`.
-        let render_fn = move |data: &mut cpal::Data, _info: &cpal::OutputCallbackInfo| {
+        let render_fn = move |data: &mut cpal::Data, info: &cpal::OutputCallbackInfo| {
+            let started = std::time::Instant::now();
+
             // Collect and process any pending updates.
             macro_rules! process_pending_updates {
                 () => {
@@ -214,6 +265,9 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
                     fill_output(output, &samples);
                 }
             }
+
+            let frames = data.len() / num_channels;
+            underruns.check(info.timestamp().callback, started, frames, sample_rate);
         };
 
         // Wrap the user's error function.
@@ -232,12 +286,14 @@ impl<M, FR, FE, S> Builder<M, FR, FE, S> {
             stream,
             model,
             is_paused: AtomicBool::new(false),
+            underruns: underrun_count,
         });
 
         let stream = Stream {
             shared,
             update_tx,
             cpal_config: stream_config,
+            sample_format,
         };
         Ok(stream)
     }
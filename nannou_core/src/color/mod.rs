@@ -4,11 +4,13 @@
 //! See the [**named**](./named/index.html) module for a set of provided color constants.
 
 pub mod conv;
+pub mod palette;
 
 pub use self::conv::IntoLinSrgba;
 pub use self::named::*;
+use crate::math::num_traits::Float;
 #[doc(inline)]
-pub use palette::*;
+pub use ::palette::*;
 
 /// The default scalar value for working with color components, hues, etc.
 pub type DefaultScalar = f32;
@@ -153,6 +155,77 @@ pub fn hsva(h: f32, s: f32, v: f32, a: f32) -> Hsva {
     Hsva::new(RgbHue::from_degrees(h * 360.0), s, v, a)
 }
 
+/// Produce a linear sRGB color from the given coordinates within the perceptually uniform
+/// [Oklab](https://bottosson.github.io/posts/oklab/) color space.
+///
+/// `l` is the perceived lightness where `0.0` is black and `1.0` is white. `a` and `b` describe
+/// how green/red and blue/yellow the color is respectively, typically within `-0.4..0.4`.
+///
+/// The resulting color is not clamped and may lie outside of the sRGB gamut.
+pub fn oklab(l: f32, a: f32, b: f32) -> LinSrgb {
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+    lin_srgb(
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    )
+}
+
+/// Produce a linear sRGB color from the given lightness, chroma and hue within the Oklch color
+/// space, the cylindrical form of Oklab.
+///
+/// Evenly spaced Oklch hues appear evenly spaced to the eye, making this well suited to producing
+/// smooth gradients and palettes.
+///
+/// The given hue expects a value between `0.0` and `1.0` where `0.0` is 0 degress and `1.0` is
+/// 360 degrees (or 2 PI radians). Chroma is typically within `0.0..0.4`.
+///
+/// The resulting color is not clamped and may lie outside of the sRGB gamut.
+pub fn oklch(l: f32, c: f32, h: f32) -> LinSrgb {
+    let radians = h * core::f32::consts::PI * 2.0;
+    oklab(l, c * Float::cos(radians), c * Float::sin(radians))
+}
+
+/// The same as `oklch` but with an alpha value representing opacity.
+pub fn oklcha(l: f32, c: f32, h: f32, a: f32) -> LinSrgba {
+    let LinSrgb {
+        red, green, blue, ..
+    } = oklch(l, c, h);
+    lin_srgba(red, green, blue, a)
+}
+
+/// Convert the given linear sRGB color to `(l, a, b)` coordinates within the Oklab color space.
+///
+/// This is the inverse of `oklab`.
+pub fn to_oklab(color: LinSrgb) -> (f32, f32, f32) {
+    let LinSrgb {
+        red, green, blue, ..
+    } = color;
+    let l = 0.4122214708 * red + 0.5363325363 * green + 0.0514459929 * blue;
+    let m = 0.2119034982 * red + 0.6806995451 * green + 0.1073969566 * blue;
+    let s = 0.0883024619 * red + 0.2817188376 * green + 0.6299787005 * blue;
+    let (l_, m_, s_) = (Float::cbrt(l), Float::cbrt(m), Float::cbrt(s));
+    (
+        0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
+        1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
+        0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
+    )
+}
+
+/// Convert the given linear sRGB color to `(l, c, h)` coordinates within the Oklch color space.
+///
+/// The hue is returned within the range `0.0..1.0`. This is the inverse of `oklch`.
+pub fn to_oklch(color: LinSrgb) -> (f32, f32, f32) {
+    let (l, a, b) = to_oklab(color);
+    let c = Float::sqrt(a * a + b * b);
+    let turns = Float::atan2(b, a) / (core::f32::consts::PI * 2.0);
+    let h = if turns < 0.0 { turns + 1.0 } else { turns };
+    (l, c, h)
+}
+
 /// A short-hand constructor for `Gray::new`.
 pub fn gray<T>(g: T) -> Gray<T>
 where
@@ -165,3 +238,18 @@ where
 fn test_rgb_u32() {
     assert_eq!(rgb_u32(0xFF8000), rgb8(255, 128, 0));
 }
+
+#[test]
+fn test_oklch_round_trip() {
+    let steps = 36;
+    for i in 0..steps {
+        let h = i as f32 / steps as f32;
+        let (l2, c2, h2) = to_oklch(oklch(0.7, 0.1, h));
+        assert!((l2 - 0.7).abs() < 1e-4);
+        assert!((c2 - 0.1).abs() < 1e-4);
+        let dh = (h2 - h).abs();
+        assert!(dh.min(1.0 - dh) < 1e-4);
+    }
+    let (l, a, b) = to_oklab(lin_srgb(1.0, 1.0, 1.0));
+    assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+}
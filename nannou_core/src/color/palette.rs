@@ -0,0 +1,105 @@
+//! Helpers for generating color schemes from a base color.
+//!
+//! All schemes are produced by rotating hues within the perceptually uniform Oklch color space,
+//! preserving the lightness and chroma of the base color so that the resulting colors appear
+//! equally bright and saturated. The results are deterministic for the same inputs.
+//!
+//! Colors that fall outside of the sRGB gamut are clamped.
+
+use crate::color::{lin_srgba, oklch, to_oklch, IntoLinSrgba, LinSrgba};
+use crate::math::num_traits::Float;
+
+/// The hue distance between neighbouring colors produced by `analogous`, i.e. 30 degrees.
+pub const ANALOGOUS_HUE_STEP: f32 = 1.0 / 12.0;
+
+/// Produce `count` colors with hues neighbouring that of `base`, centred on `base`.
+///
+/// Neighbouring colors are `ANALOGOUS_HUE_STEP` apart. If `count` is odd, `base` itself is the
+/// middle color.
+pub fn analogous<C>(base: C, count: usize) -> impl Iterator<Item = LinSrgba>
+where
+    C: IntoLinSrgba<f32>,
+{
+    let base = base.into_lin_srgba();
+    let first = -(count.saturating_sub(1) as f32) * 0.5;
+    (0..count).map(move |i| rotate_hue(base, (first + i as f32) * ANALOGOUS_HUE_STEP))
+}
+
+/// Produce `base` alongside the color with the opposite hue.
+pub fn complementary<C>(base: C) -> [LinSrgba; 2]
+where
+    C: IntoLinSrgba<f32>,
+{
+    let base = base.into_lin_srgba();
+    [rotate_hue(base, 0.0), rotate_hue(base, 0.5)]
+}
+
+/// Produce `base` alongside the two colors with hues a third of the way around the color wheel.
+pub fn triadic<C>(base: C) -> [LinSrgba; 3]
+where
+    C: IntoLinSrgba<f32>,
+{
+    let base = base.into_lin_srgba();
+    [
+        rotate_hue(base, 0.0),
+        rotate_hue(base, 1.0 / 3.0),
+        rotate_hue(base, 2.0 / 3.0),
+    ]
+}
+
+/// Produce `count` opaque colors with evenly spaced hues, starting from a hue of `0.0`.
+///
+/// `chroma` and `lightness` are the Oklch coordinates shared by all colors. See the `oklch`
+/// function for their typical ranges.
+pub fn evenly_spaced_hues(
+    count: usize,
+    chroma: f32,
+    lightness: f32,
+) -> impl Iterator<Item = LinSrgba> {
+    (0..count).map(move |i| {
+        let h = i as f32 / count as f32;
+        clamped(oklch(lightness, chroma, h).into_lin_srgba())
+    })
+}
+
+// Rotate the Oklch hue of the given color by the given number of turns, preserving alpha.
+fn rotate_hue(color: LinSrgba, turns: f32) -> LinSrgba {
+    let (l, c, h) = to_oklch(color.color);
+    let h = h + turns;
+    let mut rotated = clamped(oklch(l, c, h - Float::floor(h)).into_lin_srgba());
+    rotated.alpha = color.alpha;
+    rotated
+}
+
+// Clamp the color components to the sRGB gamut.
+fn clamped(color: LinSrgba) -> LinSrgba {
+    let clamp = |x: f32| Float::min(Float::max(x, 0.0), 1.0);
+    lin_srgba(
+        clamp(color.red),
+        clamp(color.green),
+        clamp(color.blue),
+        clamp(color.alpha),
+    )
+}
+
+#[test]
+fn test_palette() {
+    let base = oklch(0.7, 0.1, 0.2);
+    let hue = |color: LinSrgba| to_oklch(color.color).2;
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+
+    let [a, b] = complementary(base);
+    assert!(close(hue(a), 0.2) && close(hue(b), 0.7));
+    let [_, b, c] = triadic(base);
+    assert!(close(hue(b), 0.2 + 1.0 / 3.0) && close(hue(c), 0.2 + 2.0 / 3.0));
+
+    // Odd counts are centred on the base color.
+    let mut colors = analogous(base, 3);
+    assert!(close(hue(colors.next().unwrap()), 0.2 - ANALOGOUS_HUE_STEP));
+    assert!(close(hue(colors.next().unwrap()), 0.2));
+    assert!(close(hue(colors.next().unwrap()), 0.2 + ANALOGOUS_HUE_STEP));
+    assert!(colors.next().is_none());
+
+    assert_eq!(evenly_spaced_hues(5, 0.1, 0.7).count(), 5);
+    assert!(evenly_spaced_hues(5, 0.1, 0.7).eq(evenly_spaced_hues(5, 0.1, 0.7)));
+}
@@ -89,3 +89,58 @@ where
 {
     <I::Item as vertex::Average>::average(vertices)
 }
+
+/// The signed area of the polygon described by the given vertices.
+///
+/// The area is positive if the vertices are wound counter-clockwise and negative if they are wound
+/// clockwise. Returns `0.0` for polygons with fewer than three vertices or whose vertices are all
+/// collinear.
+pub fn polygon_area(vertices: &[Vec2]) -> f32 {
+    polygon_area_x2(vertices) * 0.5
+}
+
+/// The centroid (centre of mass) of the area enclosed by the polygon described by the given
+/// vertices.
+///
+/// Unlike `centroid`, this is weighted by area rather than by vertex, so unevenly distributed
+/// vertices do not skew the result. If the polygon is degenerate (i.e. has no area) the average
+/// of its vertices is returned instead. Returns `Vec2::ZERO` if `vertices` is empty.
+pub fn polygon_centroid(vertices: &[Vec2]) -> Vec2 {
+    let area_x2 = polygon_area_x2(vertices);
+    if -f32::EPSILON < area_x2 && area_x2 < f32::EPSILON {
+        return centroid(vertices.iter().cloned()).unwrap_or(Vec2::ZERO);
+    }
+    let sum = polygon_edges(vertices).fold(Vec2::ZERO, |sum, (a, b)| sum + (a + b) * a.perp_dot(b));
+    sum / (3.0 * area_x2)
+}
+
+/// Whether or not the given point lies within the polygon described by the given vertices.
+///
+/// Uses the even-odd rule, so this works for both convex and concave polygons. Self-intersecting
+/// regions that overlap an even number of times are considered outside.
+pub fn point_in_polygon(point: Vec2, vertices: &[Vec2]) -> bool {
+    let mut inside = false;
+    for (a, b) in polygon_edges(vertices) {
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+// Twice the signed area of the polygon via the shoelace formula.
+fn polygon_area_x2(vertices: &[Vec2]) -> f32 {
+    if vertices.len() < 3 {
+        return 0.0;
+    }
+    polygon_edges(vertices).map(|(a, b)| a.perp_dot(b)).sum()
+}
+
+// Each edge of the polygon, including the closing edge from the last vertex back to the first.
+fn polygon_edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let next = vertices.iter().skip(1).chain(vertices.first());
+    vertices.iter().cloned().zip(next.cloned())
+}
@@ -57,6 +57,17 @@ pub struct SubdivisionRanges<S = scalar::Default> {
     pub y_b: Range<S>,
 }
 
+/// Yields the cells of a `Rect` divided into an even grid of columns and rows.
+///
+/// See `Rect::subdivide` for the order in which cells are yielded.
+#[derive(Clone, Debug)]
+pub struct Cells<S = scalar::Default> {
+    rect: Rect<S>,
+    cols: usize,
+    rows: usize,
+    index: usize,
+}
+
 /// An iterator yielding the four corners of a `Rect`.
 #[derive(Clone, Debug)]
 pub struct Corners<S = scalar::Default> {
@@ -188,6 +199,14 @@ where
         }
     }
 
+    /// Align `self` to `other` along both axes in accordance with the given `Align` variants.
+    ///
+    /// E.g. `rect.align_to(Align::Start, Align::End, other)` places `rect` within the top left
+    /// corner of `other`.
+    pub fn align_to(self, x: Align, y: Align, other: Self) -> Self {
+        self.align_x_of(x, other).align_y_of(y, other)
+    }
+
     /// Align the middle of `self` with the middle of the `other` **Rect** along the *x* axis.
     pub fn align_middle_x_of(self, other: Self) -> Self {
         Rect {
@@ -255,6 +274,52 @@ where
     pub fn subdivisions_iter(&self) -> Subdivisions<S> {
         self.subdivision_ranges().rects_iter()
     }
+
+    /// Divide the `Rect` into an even grid of `cols` columns and `rows` rows.
+    ///
+    /// Following the order of `subdivisions`, cells are yielded one row at a time from left to
+    /// right, starting with the bottom row.
+    pub fn subdivide(&self, cols: usize, rows: usize) -> Cells<S> {
+        Cells {
+            rect: *self,
+            cols,
+            rows,
+            index: 0,
+        }
+    }
+
+    /// Split the `Rect` into two along the *x* axis, returning the left and right parts.
+    ///
+    /// `t` is the fraction of the width that is allocated to the left part, e.g. `0.25` produces
+    /// a left part that is a quarter of the width of `self`.
+    pub fn split_x(&self, t: S) -> (Self, Self) {
+        let x = self.x.start + (self.x.end - self.x.start) * t;
+        let left = Rect {
+            x: Range::new(self.x.start, x),
+            y: self.y,
+        };
+        let right = Rect {
+            x: Range::new(x, self.x.end),
+            y: self.y,
+        };
+        (left, right)
+    }
+
+    /// Split the `Rect` into two along the *y* axis, returning the bottom and top parts.
+    ///
+    /// `t` is the fraction of the height that is allocated to the bottom part.
+    pub fn split_y(&self, t: S) -> (Self, Self) {
+        let y = self.y.start + (self.y.end - self.y.start) * t;
+        let bottom = Rect {
+            x: self.x,
+            y: Range::new(self.y.start, y),
+        };
+        let top = Rect {
+            x: self.x,
+            y: Range::new(y, self.y.end),
+        };
+        (bottom, top)
+    }
 }
 
 impl<S> Rect<S>
@@ -877,6 +942,48 @@ where
     }
 }
 
+impl<S> Iterator for Cells<S>
+where
+    S: Scalar + Float,
+{
+    type Item = Rect<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.cols * self.rows {
+            return None;
+        }
+        let (col, row) = (self.index % self.cols, self.index / self.cols);
+        self.index += 1;
+        let lerp = |range: Range<S>, i: usize, n: usize| {
+            let t = S::from(i).unwrap() / S::from(n).unwrap();
+            range.start + (range.end - range.start) * t
+        };
+        let x = Range::new(
+            lerp(self.rect.x, col, self.cols),
+            lerp(self.rect.x, col + 1, self.cols),
+        );
+        let y = Range::new(
+            lerp(self.rect.y, row, self.rows),
+            lerp(self.rect.y, row + 1, self.rows),
+        );
+        Some(Rect { x, y })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<S> ExactSizeIterator for Cells<S>
+where
+    S: Scalar + Float,
+{
+    fn len(&self) -> usize {
+        (self.cols * self.rows).saturating_sub(self.index)
+    }
+}
+
 impl<S> Iterator for Corners<S>
 where
     S: Scalar,
@@ -120,6 +120,23 @@ pub trait Clear: ClearIndices + ClearVertices {
     }
 }
 
+/// Meshes whose indices channel can be truncated.
+pub trait TruncateIndices {
+    /// Shorten the indices channel to the given length.
+    ///
+    /// Has no effect if the channel is already shorter than `len`.
+    fn truncate_indices(&mut self, len: usize);
+}
+
+/// Meshes whose vertices channels can be truncated.
+pub trait TruncateVertices {
+    /// Shorten all vertex channels to the given length.
+    ///
+    /// Has no effect if the channels are already shorter than `len`. Indices referring to removed
+    /// vertices should be truncated via **TruncateIndices**.
+    fn truncate_vertices(&mut self, len: usize);
+}
+
 /// Meshes that may be extended from a slice of data.
 pub trait ExtendFromSlice<'a> {
     /// The slice type expected via the mesh.
@@ -1051,6 +1068,124 @@ where
     }
 }
 
+// **TruncateIndices** implementations
+
+impl<'a, M> TruncateIndices for &'a mut M
+where
+    M: TruncateIndices,
+{
+    fn truncate_indices(&mut self, len: usize) {
+        (**self).truncate_indices(len);
+    }
+}
+
+impl<'a, M> TruncateIndices for RefMut<'a, M>
+where
+    M: TruncateIndices,
+{
+    fn truncate_indices(&mut self, len: usize) {
+        (**self).truncate_indices(len);
+    }
+}
+
+impl<M, I> TruncateIndices for WithIndices<M, Vec<I>> {
+    fn truncate_indices(&mut self, len: usize) {
+        self.indices.truncate(len);
+    }
+}
+
+impl<M, C> TruncateIndices for WithColors<M, C>
+where
+    M: TruncateIndices,
+{
+    fn truncate_indices(&mut self, len: usize) {
+        self.mesh.truncate_indices(len);
+    }
+}
+
+impl<M, T> TruncateIndices for WithTexCoords<M, T>
+where
+    M: TruncateIndices,
+{
+    fn truncate_indices(&mut self, len: usize) {
+        self.mesh.truncate_indices(len);
+    }
+}
+
+impl<M, N> TruncateIndices for WithNormals<M, N>
+where
+    M: TruncateIndices,
+{
+    fn truncate_indices(&mut self, len: usize) {
+        self.mesh.truncate_indices(len);
+    }
+}
+
+// **TruncateVertices** implementations
+
+impl<'a, M> TruncateVertices for &'a mut M
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        (**self).truncate_vertices(len)
+    }
+}
+
+impl<'a, M> TruncateVertices for RefMut<'a, M>
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        (**self).truncate_vertices(len)
+    }
+}
+
+impl<V> TruncateVertices for MeshPoints<Vec<V>> {
+    fn truncate_vertices(&mut self, len: usize) {
+        self.points.truncate(len);
+    }
+}
+
+impl<M, I> TruncateVertices for WithIndices<M, I>
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        self.mesh.truncate_vertices(len);
+    }
+}
+
+impl<M, C> TruncateVertices for WithColors<M, Vec<C>>
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        self.mesh.truncate_vertices(len);
+        self.colors.truncate(len);
+    }
+}
+
+impl<M, T> TruncateVertices for WithTexCoords<M, Vec<T>>
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        self.mesh.truncate_vertices(len);
+        self.tex_coords.truncate(len);
+    }
+}
+
+impl<M, N> TruncateVertices for WithNormals<M, Vec<N>>
+where
+    M: TruncateVertices,
+{
+    fn truncate_vertices(&mut self, len: usize) {
+        self.mesh.truncate_vertices(len);
+        self.normals.truncate(len);
+    }
+}
+
 // **ExtendFromSlice** implementations
 
 impl<'a, P> ExtendFromSlice<'a> for MeshPoints<Vec<P>>
@@ -1382,6 +1517,22 @@ where
     mesh.clear();
 }
 
+/// Shorten the indices channel of the mesh to the given length.
+pub fn truncate_indices<M>(mut mesh: M, len: usize)
+where
+    M: TruncateIndices,
+{
+    mesh.truncate_indices(len);
+}
+
+/// Shorten the vertex channels of the mesh to the given length.
+pub fn truncate_vertices<M>(mut mesh: M, len: usize)
+where
+    M: TruncateVertices,
+{
+    mesh.truncate_vertices(len);
+}
+
 // Mesh iterators.
 
 /// An iterator yielding the raw vertices (with combined channels) of a mesh.
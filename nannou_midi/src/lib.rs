@@ -0,0 +1,201 @@
+//! Tools for receiving MIDI from connected controllers. [**inputs()**](./fn.inputs.html) lists the
+//! available input ports, [**receiver(input)**](./fn.receiver.html) creates a MIDI receiver.
+
+pub use midir;
+
+pub use self::recv::Receiver;
+
+use std::fmt;
+
+pub mod recv;
+
+/// The client name with which nannou identifies itself to the system's MIDI API.
+pub const CLIENT_NAME: &str = "nannou";
+
+/// A MIDI input port available on the system, e.g. a connected controller.
+#[derive(Clone)]
+pub struct Input {
+    port: midir::MidiInputPort,
+    name: String,
+}
+
+/// A decoded MIDI channel message.
+///
+/// Channels are in the range `0..16`. All other values are in the range `0..128` unless stated
+/// otherwise.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Message {
+    /// A key was released.
+    NoteOff { channel: u8, note: u8, velocity: u8 },
+    /// A key was pressed.
+    ///
+    /// Note on messages with a velocity of `0` are decoded as `NoteOff`.
+    NoteOn { channel: u8, note: u8, velocity: u8 },
+    /// The pressure applied to a key that is already held.
+    PolyPressure { channel: u8, note: u8, pressure: u8 },
+    /// A knob, fader, pedal or other controller changed value.
+    ControlChange {
+        channel: u8,
+        controller: u8,
+        value: u8,
+    },
+    /// The selected program (or "patch") changed.
+    ProgramChange { channel: u8, program: u8 },
+    /// The pressure applied to all held keys.
+    ChannelPressure { channel: u8, pressure: u8 },
+    /// The pitch bend wheel moved.
+    ///
+    /// The value is in the range `-8192..8192` where `0` is the centre.
+    PitchBend { channel: u8, value: i16 },
+    /// Any other message in its raw form.
+    Other(Vec<u8>),
+}
+
+/// Errors that might occur while listing or connecting to MIDI inputs.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to initialise the MIDI client: {0}")]
+    Init(#[from] midir::InitError),
+    #[error("failed to retrieve MIDI port info: {0}")]
+    PortInfo(#[from] midir::PortInfoError),
+    #[error("failed to connect to the MIDI input: {0}")]
+    Connect(midir::ConnectErrorKind),
+}
+
+impl Input {
+    /// The name of the port as reported by the system.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Message {
+    /// Decode a message from the raw bytes received from a MIDI input.
+    ///
+    /// Channel messages with too few data bytes and system messages are returned as `Other`.
+    pub fn decode(bytes: &[u8]) -> Self {
+        let other = || Message::Other(bytes.to_vec());
+        let (status, data) = match bytes.split_first() {
+            Some((&status, data)) if status < 0xF0 => (status, data),
+            _ => return other(),
+        };
+        let channel = status & 0x0F;
+        let byte = |i: usize| data.get(i).map(|&b| b & 0x7F);
+        match (status & 0xF0, byte(0), byte(1)) {
+            (0x80, Some(note), Some(velocity)) | (0x90, Some(note), Some(velocity @ 0)) => {
+                Message::NoteOff {
+                    channel,
+                    note,
+                    velocity,
+                }
+            }
+            (0x90, Some(note), Some(velocity)) => Message::NoteOn {
+                channel,
+                note,
+                velocity,
+            },
+            (0xA0, Some(note), Some(pressure)) => Message::PolyPressure {
+                channel,
+                note,
+                pressure,
+            },
+            (0xB0, Some(controller), Some(value)) => Message::ControlChange {
+                channel,
+                controller,
+                value,
+            },
+            (0xC0, Some(program), _) => Message::ProgramChange { channel, program },
+            (0xD0, Some(pressure), _) => Message::ChannelPressure { channel, pressure },
+            (0xE0, Some(lsb), Some(msb)) => {
+                let value = ((msb as i16) << 7 | lsb as i16) - 8192;
+                Message::PitchBend { channel, value }
+            }
+            _ => other(),
+        }
+    }
+}
+
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Input").field("name", &self.name).finish()
+    }
+}
+
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        self.port == other.port
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// List the MIDI input ports currently available on the system.
+///
+/// Devices may be plugged in or unplugged at any time, so it may be worth calling this
+/// periodically to detect changes.
+pub fn inputs() -> Result<Vec<Input>, Error> {
+    let midi_in = midir::MidiInput::new(CLIENT_NAME)?;
+    midi_in
+        .ports()
+        .into_iter()
+        .map(|port| {
+            let name = midi_in.port_name(&port)?;
+            Ok(Input { port, name })
+        })
+        .collect()
+}
+
+/// A simple wrapper around the most commonly used `Receiver` constructor.
+pub fn receiver(input: &Input) -> Result<Receiver, Error> {
+    Receiver::connect(input)
+}
+
+#[test]
+fn test_decode_note() {
+    let on = Message::decode(&[0x91, 60, 100]);
+    assert_eq!(
+        on,
+        Message::NoteOn {
+            channel: 1,
+            note: 60,
+            velocity: 100
+        }
+    );
+    // A note on with a velocity of zero is a note off.
+    let off = Message::decode(&[0x91, 60, 0]);
+    assert_eq!(
+        off,
+        Message::NoteOff {
+            channel: 1,
+            note: 60,
+            velocity: 0
+        }
+    );
+}
+
+#[test]
+fn test_decode_pitch_bend() {
+    let bend = |lsb, msb| Message::decode(&[0xE0, lsb, msb]);
+    let value = |value| Message::PitchBend { channel: 0, value };
+    assert_eq!(bend(0x00, 0x40), value(0));
+    assert_eq!(bend(0x00, 0x00), value(-8192));
+    assert_eq!(bend(0x7F, 0x7F), value(8191));
+}
+
+#[test]
+fn test_decode_truncated() {
+    for bytes in [
+        &[][..],
+        &[0x90],
+        &[0x90, 60],
+        &[0xB0, 7],
+        &[0xE0, 0],
+        &[0xC0],
+    ] {
+        assert_eq!(Message::decode(bytes), Message::Other(bytes.to_vec()));
+    }
+}
@@ -0,0 +1,98 @@
+//! Items related to the `midi::Receiver` implementation.
+
+use super::{inputs, Error, Input, Message, CLIENT_NAME};
+use std::sync::mpsc;
+
+/// A type used for receiving MIDI messages from an input.
+///
+/// Messages are queued as they arrive on a separate thread and may be collected via `try_recv`
+/// or `try_iter`, e.g. once per frame within `update`.
+///
+/// If the input's device is unplugged, the receiver simply stops receiving messages. See
+/// `is_connected`.
+pub struct Receiver {
+    input: Input,
+    messages: mpsc::Receiver<Message>,
+    _connection: midir::MidiInputConnection<()>,
+}
+
+/// An iterator that calls `try_recv` on the inner `Receiver` and yields the results.
+///
+/// Each call to `next` will only return `Some` while there are pending messages and will return
+/// `None` otherwise.
+pub struct TryIter<'a> {
+    receiver: &'a Receiver,
+}
+
+impl Receiver {
+    /// Connect to the given input and begin queueing its messages.
+    ///
+    /// ```no_run
+    /// use nannou_midi::Receiver;
+    ///
+    /// fn main() {
+    ///     let inputs = nannou_midi::inputs().expect("Couldn't list MIDI inputs");
+    ///     let input = inputs.first().expect("No MIDI inputs available");
+    ///     let rx = Receiver::connect(input).expect("Couldn't connect to MIDI input");
+    /// }
+    /// ```
+    pub fn connect(input: &Input) -> Result<Self, Error> {
+        let mut midi_in = midir::MidiInput::new(CLIENT_NAME)?;
+        midi_in.ignore(midir::Ignore::All);
+        let (tx, messages) = mpsc::channel();
+        let callback = move |_stamp: u64, bytes: &[u8], _: &mut ()| {
+            // The receiver may have been dropped while the connection is closing.
+            let _ = tx.send(Message::decode(bytes));
+        };
+        let connection = midi_in
+            .connect(&input.port, CLIENT_NAME, callback, ())
+            .map_err(|err| Error::Connect(err.kind()))?;
+        let input = input.clone();
+        Ok(Receiver {
+            input,
+            messages,
+            _connection: connection,
+        })
+    }
+
+    /// The input from which this `Receiver` receives messages.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Whether or not the input is still available on the system.
+    ///
+    /// Once a device has been unplugged, no further messages are received. A new `Receiver` may
+    /// be connected once the device reappears within `inputs()`.
+    ///
+    /// **Note:** This creates a new MIDI client and queries every port on the system via
+    /// `inputs()` on each call, which may take some time. Avoid calling it every frame, e.g.
+    /// check periodically instead.
+    pub fn is_connected(&self) -> bool {
+        inputs()
+            .map(|inputs| inputs.contains(&self.input))
+            .unwrap_or(false)
+    }
+
+    /// Pop the next pending message from the queue, if any.
+    ///
+    /// This never blocks.
+    pub fn try_recv(&self) -> Option<Message> {
+        self.messages.try_recv().ok()
+    }
+
+    /// An iterator yielding all pending messages.
+    ///
+    /// Each call to `next` will only return `Some` while there are pending messages and will return
+    /// `None` otherwise.
+    pub fn try_iter(&self) -> TryIter<'_> {
+        TryIter { receiver: self }
+    }
+}
+
+impl<'a> Iterator for TryIter<'a> {
+    type Item = Message;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.try_recv()
+    }
+}
@@ -43,6 +43,10 @@ impl LayoutBuilder {
     }
 
     /// Add a storage buffer binding to the layout.
+    ///
+    /// Note that writable buffers (i.e. `read_only` is `false`) may only be visible to the vertex
+    /// stage if the device was created with `wgpu::Features::VERTEX_WRITABLE_STORAGE`. This is
+    /// validated by wgpu when the layout is created.
     pub fn storage_buffer(
         self,
         visibility: wgpu::ShaderStages,
@@ -117,6 +121,10 @@ impl LayoutBuilder {
     }
 
     /// Add a storage texture binding to the layout.
+    ///
+    /// Note that textures whose `access` allows writing may only be visible to the vertex stage if
+    /// the device was created with `wgpu::Features::VERTEX_WRITABLE_STORAGE`. This is validated by
+    /// wgpu when the layout is created.
     pub fn storage_texture(
         self,
         visibility: wgpu::ShaderStages,
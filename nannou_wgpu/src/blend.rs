@@ -37,3 +37,36 @@ pub const LIGHTEST: wgpu::BlendComponent = wgpu::BlendComponent {
     dst_factor: wgpu::BlendFactor::One,
     operation: wgpu::BlendOperation::Max,
 };
+
+/// Adds the source colour, weighted by its alpha, to the destination. Useful for glow and particle
+/// effects, as overlapping drawings brighten towards white.
+pub const ADDITIVE: wgpu::BlendComponent = wgpu::BlendComponent {
+    src_factor: wgpu::BlendFactor::SrcAlpha,
+    dst_factor: wgpu::BlendFactor::One,
+    operation: wgpu::BlendOperation::Add,
+};
+
+/// Subtracts the source colour, weighted by its alpha, from the destination. Overlapping drawings
+/// darken towards black.
+pub const SUBTRACTIVE: wgpu::BlendComponent = wgpu::BlendComponent {
+    src_factor: wgpu::BlendFactor::SrcAlpha,
+    dst_factor: wgpu::BlendFactor::One,
+    operation: wgpu::BlendOperation::ReverseSubtract,
+};
+
+/// Multiplies the source and destination colours. The result is always at least as dark as
+/// either colour. Note that the alpha of the source is not taken into account.
+pub const MULTIPLY: wgpu::BlendComponent = wgpu::BlendComponent {
+    src_factor: wgpu::BlendFactor::Dst,
+    dst_factor: wgpu::BlendFactor::Zero,
+    operation: wgpu::BlendOperation::Add,
+};
+
+/// Multiplies the inverse of the source and destination colours and inverts the result. The
+/// result is always at least as light as either colour. Note that the alpha of the source is not
+/// taken into account.
+pub const SCREEN: wgpu::BlendComponent = wgpu::BlendComponent {
+    src_factor: wgpu::BlendFactor::One,
+    dst_factor: wgpu::BlendFactor::OneMinusSrc,
+    operation: wgpu::BlendOperation::Add,
+};
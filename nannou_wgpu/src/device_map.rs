@@ -28,6 +28,7 @@ pub struct AdapterMapKey {
 }
 
 /// A single active adapter and its map of connected devices.
+#[derive(Debug)]
 pub struct ActiveAdapter {
     adapter: wgpu::Adapter,
     device_map: DeviceMap,
@@ -41,7 +42,7 @@ pub struct ActiveAdapter {
 ///
 /// The map contains only weak handles to active adapters and cleans up unused entries at the end
 /// of each application loop.
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct DeviceMap {
     map: Mutex<HashMap<DeviceMapKey, Weak<DeviceQueuePair>>>,
 }
@@ -27,6 +27,7 @@
 mod bind_group_builder;
 pub mod blend;
 mod device_map;
+mod ping_pong_buffer;
 mod render_pass;
 mod render_pipeline_builder;
 mod sampler_builder;
@@ -42,6 +43,7 @@ pub use self::bind_group_builder::{
 pub use self::device_map::{
     ActiveAdapter, AdapterMap, AdapterMapKey, DeviceMap, DeviceMapKey, DeviceQueuePair,
 };
+pub use self::ping_pong_buffer::PingPongBuffer;
 pub use self::render_pass::{
     Builder as RenderPassBuilder,
     ColorAttachmentDescriptorBuilder as RenderPassColorAttachmentDescriptorBuilder,
@@ -65,6 +67,7 @@ pub use self::texture::{
     format_size_bytes as texture_format_size_bytes, Builder as TextureBuilder, Texture, TextureId,
     TextureView, TextureViewId, ToTextureView,
 };
+pub use bytemuck;
 #[doc(inline)]
 pub use wgpu_upstream::{
     include_wgsl,
@@ -0,0 +1,129 @@
+use crate as wgpu;
+use crate::util::DeviceExt;
+
+/// A pair of equally sized buffers for double-buffered ("ping-pong") GPU simulation.
+///
+/// Compute shaders that step a simulation (e.g. particles) commonly read the previous state from
+/// one buffer and write the next state to another, swapping the two each frame. This type takes
+/// care of sizing both buffers for `count` instances of `T` and of enabling the `STORAGE`,
+/// `VERTEX`, `COPY_SRC` and `COPY_DST` usages so that the result may be bound to a compute pass,
+/// drawn as a vertex/instance buffer and read back or written to from the CPU.
+///
+/// `T` must implement `bytemuck::Pod` (re-exported as `wgpu::bytemuck`) so that instances may be
+/// safely viewed as the raw bytes uploaded to the GPU.
+#[derive(Debug)]
+pub struct PingPongBuffer {
+    buffers: [wgpu::Buffer; 2],
+    front: usize,
+    len: usize,
+    stride: wgpu::BufferAddress,
+}
+
+impl PingPongBuffer {
+    /// The usage flags enabled on both buffers.
+    pub const USAGE: wgpu::BufferUsages = wgpu::BufferUsages::STORAGE
+        .union(wgpu::BufferUsages::VERTEX)
+        .union(wgpu::BufferUsages::COPY_SRC)
+        .union(wgpu::BufferUsages::COPY_DST);
+
+    /// Create a pair of zeroed buffers, each large enough to store `len` instances of `T`.
+    ///
+    /// **Panics** if `T` is zero-sized.
+    pub fn new<T>(device: &wgpu::Device, label: Option<&str>, len: usize) -> Self
+    where
+        T: bytemuck::Pod,
+    {
+        let stride = stride::<T>();
+        let size = stride * len as wgpu::BufferAddress;
+        let create = || {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label,
+                size,
+                usage: Self::USAGE,
+                mapped_at_creation: false,
+            })
+        };
+        let buffers = [create(), create()];
+        PingPongBuffer {
+            buffers,
+            front: 0,
+            len,
+            stride,
+        }
+    }
+
+    /// Create a pair of buffers where both are initialised with the given data.
+    ///
+    /// **Panics** if `T` is zero-sized.
+    pub fn init<T>(device: &wgpu::Device, label: Option<&str>, data: &[T]) -> Self
+    where
+        T: bytemuck::Pod,
+    {
+        let stride = stride::<T>();
+        let contents: &[u8] = bytemuck::cast_slice(data);
+        let create = || {
+            device.create_buffer_init(&wgpu::BufferInitDescriptor {
+                label,
+                contents,
+                usage: Self::USAGE,
+            })
+        };
+        let buffers = [create(), create()];
+        PingPongBuffer {
+            buffers,
+            front: 0,
+            len: data.len(),
+            stride,
+        }
+    }
+
+    /// The buffer containing the most recently completed state.
+    ///
+    /// This is the buffer that should be read from (or drawn) this frame.
+    pub fn front(&self) -> &wgpu::Buffer {
+        &self.buffers[self.front]
+    }
+
+    /// The buffer to which the next state should be written.
+    pub fn back(&self) -> &wgpu::Buffer {
+        &self.buffers[1 - self.front]
+    }
+
+    /// Swap the front and back buffers.
+    ///
+    /// This should be called once the back buffer has been written to.
+    pub fn swap(&mut self) {
+        self.front = 1 - self.front;
+    }
+
+    /// The number of instances of `T` that each buffer can store.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether or not the buffers have a capacity of zero instances.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size of a single instance in bytes.
+    pub fn stride(&self) -> wgpu::BufferAddress {
+        self.stride
+    }
+
+    /// The size of *each* buffer in bytes.
+    ///
+    /// The total amount of memory allocated is twice this size.
+    pub fn size(&self) -> wgpu::BufferAddress {
+        self.stride * self.len as wgpu::BufferAddress
+    }
+}
+
+fn stride<T>() -> wgpu::BufferAddress {
+    let stride = std::mem::size_of::<T>();
+    assert!(
+        stride > 0,
+        "`PingPongBuffer` does not support zero-sized types"
+    );
+    stride as wgpu::BufferAddress
+}
@@ -283,6 +283,10 @@ impl<'a> RenderPipelineBuilder<'a> {
         self
     }
 
+    /// Whether or not depth values are written to the depth/stencil attachment.
+    ///
+    /// Like the other depth and stencil methods, this enables the depth/stencil state using
+    /// `DEFAULT_DEPTH_STENCIL` if it was not yet specified.
     pub fn depth_write_enabled(mut self, enabled: bool) -> Self {
         let state = self
             .depth_stencil
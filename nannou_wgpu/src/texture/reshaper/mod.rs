@@ -40,8 +40,7 @@ impl Reshaper {
         dst_sample_count: u32,
         dst_format: wgpu::TextureFormat,
     ) -> Self {
-        // Load shader modules.
-        let vs_desc = wgpu::include_wgsl!("shaders/vs.wgsl");
+        // Load the fragment shader with an unrolled resolve for the sample count where possible.
         let fs_desc = match src_sample_count {
             1 => wgpu::include_wgsl!("shaders/fs.wgsl"),
             2 => wgpu::include_wgsl!("shaders/fs_msaa2.wgsl"),
@@ -50,6 +49,43 @@ impl Reshaper {
             16 => wgpu::include_wgsl!("shaders/fs_msaa16.wgsl"),
             _ => wgpu::include_wgsl!("shaders/fs_msaa.wgsl"),
         };
+        Self::with_fragment_shader(
+            device,
+            src_texture,
+            src_sample_count,
+            src_sample_type,
+            dst_sample_count,
+            dst_format,
+            fs_desc,
+        )
+    }
+
+    /// Construct a new `Reshaper` that writes to the destination with the given fragment shader.
+    ///
+    /// This allows for applying an effect while reshaping, e.g. for post-processing. The shader's
+    /// `main` entry point receives the texture coordinates at `@location(0)` and may sample the
+    /// source texture via the following bindings:
+    ///
+    /// ```wgsl
+    /// @group(0) @binding(0)
+    /// var tex: texture_2d<f32>;
+    /// @group(0) @binding(1)
+    /// var tex_sampler: sampler;
+    /// ```
+    ///
+    /// If the source texture is multisampled, `tex` must instead be a `texture_multisampled_2d`.
+    /// For sample counts other than 1, 2, 4, 8 or 16, a uniform containing the `sample_count` as a
+    /// `u32` is also bound at `@binding(2)`.
+    pub fn with_fragment_shader(
+        device: &wgpu::Device,
+        src_texture: &wgpu::TextureViewHandle,
+        src_sample_count: u32,
+        src_sample_type: wgpu::TextureSampleType,
+        dst_sample_count: u32,
+        dst_format: wgpu::TextureFormat,
+        fs_desc: wgpu::ShaderModuleDescriptor,
+    ) -> Self {
+        let vs_desc = wgpu::include_wgsl!("shaders/vs.wgsl");
         let vs_mod = device.create_shader_module(vs_desc);
         let fs_mod = device.create_shader_module(fs_desc);
 